@@ -101,6 +101,8 @@ fn bench_map_reduce(c: &mut Criterion) {
             num_threads: None,
             chunk_size: 1_024,
             max_in_flight_chunks: 4,
+            pool: None,
+            track_chunk_output: false,
         });
 
         group.bench_with_input(
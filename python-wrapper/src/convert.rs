@@ -2,7 +2,7 @@
 
 use pyo3::exceptions::{PyIOError, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::{PyBool, PyDict, PyList};
+use pyo3::types::{PyBool, PyBytes, PyDict, PyList};
 
 use rust_data_processing::IngestionError;
 use rust_data_processing::execution::ExecutionOptions;
@@ -40,8 +40,10 @@ pub(crate) fn parse_data_type(s: &str) -> PyResult<DataType> {
         "float64" | "f64" | "double" => Ok(DataType::Float64),
         "bool" | "boolean" => Ok(DataType::Bool),
         "utf8" | "string" | "str" | "text" => Ok(DataType::Utf8),
+        "bytes" | "binary" => Ok(DataType::Bytes),
+        "timestamp" | "datetime" => Ok(DataType::Timestamp),
         _ => Err(PyValueError::new_err(format!(
-            "unknown data_type '{s}'; expected int64, float64, bool, or utf8"
+            "unknown data_type '{s}'; expected int64, float64, bool, utf8, bytes, or timestamp"
         ))),
     }
 }
@@ -59,7 +61,16 @@ pub(crate) fn schema_from_py(obj: &Bound<'_, PyAny>) -> PyResult<Schema> {
             .get_item("data_type")?
             .ok_or_else(|| PyValueError::new_err("schema field missing 'data_type'"))?
             .extract()?;
-        fields.push(Field::new(name, parse_data_type(dt.trim())?));
+        let data_type = if dt.trim().eq_ignore_ascii_case("enum") {
+            let values: Vec<String> = d
+                .get_item("values")?
+                .ok_or_else(|| PyValueError::new_err("enum field missing 'values'"))?
+                .extract()?;
+            DataType::Enum(values)
+        } else {
+            parse_data_type(dt.trim())?
+        };
+        fields.push(Field::new(name, data_type));
     }
     Ok(Schema::new(fields))
 }
@@ -69,11 +80,17 @@ pub(crate) fn schema_to_py_list(py: Python<'_>, schema: &Schema) -> PyResult<PyO
     for f in &schema.fields {
         let d = PyDict::new(py);
         d.set_item("name", &f.name)?;
-        let dt = match f.data_type {
+        let dt = match &f.data_type {
             DataType::Int64 => "int64",
             DataType::Float64 => "float64",
             DataType::Bool => "bool",
             DataType::Utf8 => "utf8",
+            DataType::Bytes => "bytes",
+            DataType::Timestamp => "timestamp",
+            DataType::Enum(values) => {
+                d.set_item("values", values)?;
+                "enum"
+            }
         };
         d.set_item("data_type", dt)?;
         list.append(d)?;
@@ -164,6 +181,14 @@ pub(crate) fn value_to_py(py: Python<'_>, v: &Value) -> PyObject {
             .expect("str converts")
             .into_any()
             .unbind(),
+        Value::Bytes(b) => PyBytes::new(py, b).into_any().unbind(),
+        // RFC 3339 UTC text; `datetime.fromisoformat` parses it on Python 3.11+.
+        Value::Timestamp(_) => v
+            .to_string()
+            .into_pyobject(py)
+            .expect("str converts")
+            .into_any()
+            .unbind(),
     }
 }
 
@@ -184,8 +209,11 @@ pub(crate) fn value_from_py(obj: &Bound<'_, PyAny>) -> PyResult<Value> {
     if let Ok(s) = obj.extract::<String>() {
         return Ok(Value::Utf8(s));
     }
+    if let Ok(b) = obj.downcast::<PyBytes>() {
+        return Ok(Value::Bytes(b.as_bytes().to_vec()));
+    }
     Err(PyValueError::new_err(
-        "value must be None, int, float, bool, or str",
+        "value must be None, int, float, bool, str, or bytes",
     ))
 }
 
@@ -208,8 +236,16 @@ pub(crate) fn dataset_from_rows_py(
             )));
         }
         let mut row = Vec::with_capacity(ncols);
-        for c in row_list.iter() {
-            row.push(value_from_py(&c)?);
+        for (c, field) in row_list.iter().zip(&schema.fields) {
+            let v = value_from_py(&c)?;
+            // Timestamps arrive as ISO 8601 strings (or epoch microseconds).
+            let v = if field.data_type == DataType::Timestamp {
+                v.coerce_to(&DataType::Timestamp)
+                    .map_err(PyValueError::new_err)?
+            } else {
+                v
+            };
+            row.push(v);
         }
         out_rows.push(row);
     }
@@ -43,6 +43,15 @@ pub enum IngestionError {
     #[error("schema mismatch: {message}")]
     SchemaMismatch { message: String },
 
+    /// The input has more rows than [`crate::ingestion::IngestionOptions::max_rows`] allows.
+    #[error("row limit exceeded: input has more than {limit} rows")]
+    RowLimitExceeded { limit: usize },
+
+    /// The input has no data rows and [`crate::ingestion::IngestionOptions::allow_empty`] is
+    /// `false`.
+    #[error("input has no data rows")]
+    EmptyInput,
+
     /// A value could not be parsed into the required [`crate::types::DataType`].
     #[error("failed to parse value at row {row} column '{column}': {message} (raw='{raw}')")]
     ParseError {
@@ -0,0 +1,278 @@
+//! In-memory grouped aggregation state used by [`super::ExecutionEngine::group_by_parallel`].
+//!
+//! A [`GroupPlan`] resolves key/aggregation columns once; each chunk builds a [`GroupPartial`]
+//! and partials are merged in chunk order, so groups keep first-appearance order.
+
+use std::collections::HashMap;
+
+use crate::error::{IngestionError, IngestionResult};
+use crate::pipeline::Agg;
+use crate::processing::ReduceOp;
+use crate::processing::multi::group_key_part;
+use crate::processing::reduce::ColumnReducer;
+use crate::types::{DataSet, DataType, Field, Schema, Value};
+
+enum AggSpec {
+    Rows,
+    NotNull(usize),
+    Reduce {
+        idx: usize,
+        data_type: DataType,
+        op: ReduceOp,
+    },
+}
+
+enum AggAcc {
+    Rows(i64),
+    NotNull(i64),
+    Reduce(ColumnReducer),
+}
+
+impl AggAcc {
+    fn merge(&mut self, other: AggAcc) {
+        match (self, other) {
+            (Self::Rows(a), Self::Rows(b)) | (Self::NotNull(a), Self::NotNull(b)) => *a += b,
+            (Self::Reduce(a), Self::Reduce(b)) => a.merge(b),
+            _ => unreachable!("partials are built from the same plan"),
+        }
+    }
+
+    /// Final value, or `None` if an Int64 sum overflows.
+    fn finish(self) -> Option<Value> {
+        match self {
+            Self::Rows(n) | Self::NotNull(n) => Some(Value::Int64(n)),
+            Self::Reduce(acc) => acc.finish(),
+        }
+    }
+}
+
+/// Resolved key and aggregation columns plus the output schema.
+pub(crate) struct GroupPlan {
+    key_idxs: Vec<usize>,
+    specs: Vec<AggSpec>,
+    schema: Schema,
+}
+
+impl GroupPlan {
+    /// Validate `keys` / `aggs` against `schema` (same preconditions as
+    /// [`crate::pipeline::DataFrame::group_by`], plus column and type checks).
+    pub(crate) fn new(schema: &Schema, keys: &[&str], aggs: &[Agg]) -> IngestionResult<Self> {
+        if keys.is_empty() {
+            return Err(IngestionError::SchemaMismatch {
+                message: "group_by requires at least one key column".to_string(),
+            });
+        }
+        if aggs.is_empty() {
+            return Err(IngestionError::SchemaMismatch {
+                message: "group_by requires at least one aggregation".to_string(),
+            });
+        }
+
+        let column = |name: &str| {
+            schema
+                .index_of(name)
+                .ok_or_else(|| IngestionError::SchemaMismatch {
+                    message: format!("unknown column '{name}'"),
+                })
+        };
+
+        let mut key_idxs = Vec::with_capacity(keys.len());
+        let mut fields = Vec::with_capacity(keys.len() + aggs.len());
+        for key in keys {
+            let idx = column(key)?;
+            key_idxs.push(idx);
+            fields.push(schema.fields[idx].clone());
+        }
+
+        let mut specs = Vec::with_capacity(aggs.len());
+        for agg in aggs {
+            let (spec, alias, out_type) = match agg {
+                Agg::CountRows { alias } => (AggSpec::Rows, alias, DataType::Int64),
+                Agg::CountNotNull { column: c, alias } => {
+                    (AggSpec::NotNull(column(c)?), alias, DataType::Int64)
+                }
+                Agg::Sum { column: c, alias } => {
+                    reduce_spec(schema, column(c)?, ReduceOp::Sum, alias)?
+                }
+                Agg::Min { column: c, alias } => {
+                    reduce_spec(schema, column(c)?, ReduceOp::Min, alias)?
+                }
+                Agg::Max { column: c, alias } => {
+                    reduce_spec(schema, column(c)?, ReduceOp::Max, alias)?
+                }
+                Agg::Mean { column: c, alias } => {
+                    reduce_spec(schema, column(c)?, ReduceOp::Mean, alias)?
+                }
+                Agg::Variance {
+                    column: c,
+                    alias,
+                    kind,
+                } => reduce_spec(schema, column(c)?, ReduceOp::Variance(*kind), alias)?,
+                Agg::StdDev {
+                    column: c,
+                    alias,
+                    kind,
+                } => reduce_spec(schema, column(c)?, ReduceOp::StdDev(*kind), alias)?,
+                Agg::SumSquares { column: c, alias } => {
+                    reduce_spec(schema, column(c)?, ReduceOp::SumSquares, alias)?
+                }
+                Agg::L2Norm { column: c, alias } => {
+                    reduce_spec(schema, column(c)?, ReduceOp::L2Norm, alias)?
+                }
+                Agg::CountDistinctNonNull { column: c, alias } => {
+                    reduce_spec(schema, column(c)?, ReduceOp::CountDistinctNonNull, alias)?
+                }
+            };
+            fields.push(Field::new(alias.clone(), out_type));
+            specs.push(spec);
+        }
+
+        Ok(Self {
+            key_idxs,
+            specs,
+            schema: Schema::new(fields),
+        })
+    }
+
+    /// Empty per-chunk state.
+    pub(crate) fn partial(&self) -> GroupPartial {
+        GroupPartial::default()
+    }
+
+    /// Fold one input row into `partial`.
+    pub(crate) fn observe(&self, partial: &mut GroupPartial, row: &[Value]) {
+        let key: Vec<String> = self
+            .key_idxs
+            .iter()
+            .map(|&i| group_key_part(row.get(i)))
+            .collect();
+        let slot = match partial.index.get(&key) {
+            Some(&slot) => slot,
+            None => {
+                let slot = partial.keys.len();
+                partial.keys.push(
+                    self.key_idxs
+                        .iter()
+                        .map(|&i| row.get(i).cloned().unwrap_or(Value::Null))
+                        .collect(),
+                );
+                partial.accs.push(self.specs.iter().map(new_acc).collect());
+                partial.index.insert(key, slot);
+                slot
+            }
+        };
+        for (spec, acc) in self.specs.iter().zip(partial.accs[slot].iter_mut()) {
+            match (spec, acc) {
+                (AggSpec::Rows, AggAcc::Rows(n)) => *n += 1,
+                (AggSpec::NotNull(idx), AggAcc::NotNull(n)) => {
+                    if !matches!(row.get(*idx), Some(Value::Null) | None) {
+                        *n += 1;
+                    }
+                }
+                (AggSpec::Reduce { idx, .. }, AggAcc::Reduce(r)) => r.observe(row.get(*idx)),
+                _ => unreachable!("accumulators are built from the plan"),
+            }
+        }
+    }
+
+    /// Build the output dataset: key columns, then one column per aggregation alias.
+    ///
+    /// Returns [`IngestionError::SchemaMismatch`] if a group's Int64 sum overflows.
+    pub(crate) fn finish(&self, partial: GroupPartial) -> IngestionResult<DataSet> {
+        let agg_fields = &self.schema.fields[self.key_idxs.len()..];
+        let mut rows = Vec::with_capacity(partial.keys.len());
+        for (mut key, accs) in partial.keys.into_iter().zip(partial.accs) {
+            for (field, acc) in agg_fields.iter().zip(accs) {
+                let value = acc.finish().ok_or_else(|| IngestionError::SchemaMismatch {
+                    message: format!("aggregation '{}' overflows Int64", field.name),
+                })?;
+                key.push(value);
+            }
+            rows.push(key);
+        }
+        Ok(DataSet::new(self.schema.clone(), rows))
+    }
+}
+
+/// Per-group accumulators in first-appearance order.
+#[derive(Default)]
+pub(crate) struct GroupPartial {
+    index: HashMap<Vec<String>, usize>,
+    keys: Vec<Vec<Value>>,
+    accs: Vec<Vec<AggAcc>>,
+}
+
+impl GroupPartial {
+    /// Number of distinct groups seen so far.
+    pub(crate) fn group_count(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Fold a later chunk's state into this one (groups new to `self` are appended in order).
+    pub(crate) fn merge(&mut self, other: GroupPartial) {
+        let mut by_slot: Vec<(usize, Vec<String>)> = other
+            .index
+            .into_iter()
+            .map(|(key, slot)| (slot, key))
+            .collect();
+        by_slot.sort_unstable_by_key(|(slot, _)| *slot);
+
+        for (((_, key), key_values), accs) in by_slot.into_iter().zip(other.keys).zip(other.accs) {
+            match self.index.get(&key) {
+                Some(&slot) => {
+                    for (mine, theirs) in self.accs[slot].iter_mut().zip(accs) {
+                        mine.merge(theirs);
+                    }
+                }
+                None => {
+                    self.index.insert(key, self.keys.len());
+                    self.keys.push(key_values);
+                    self.accs.push(accs);
+                }
+            }
+        }
+    }
+}
+
+fn reduce_spec<'a>(
+    schema: &Schema,
+    idx: usize,
+    op: ReduceOp,
+    alias: &'a String,
+) -> IngestionResult<(AggSpec, &'a String, DataType)> {
+    let field = &schema.fields[idx];
+    let numeric = matches!(field.data_type, DataType::Int64 | DataType::Float64);
+    let out_type = match op {
+        ReduceOp::CountDistinctNonNull => DataType::Int64,
+        ReduceOp::First | ReduceOp::Last => field.data_type.clone(),
+        _ if !numeric => {
+            return Err(IngestionError::SchemaMismatch {
+                message: format!(
+                    "aggregation {op:?} requires a numeric column, '{}' is {:?}",
+                    field.name, field.data_type
+                ),
+            });
+        }
+        ReduceOp::Sum | ReduceOp::Min | ReduceOp::Max => field.data_type.clone(),
+        _ => DataType::Float64,
+    };
+    Ok((
+        AggSpec::Reduce {
+            idx,
+            data_type: field.data_type.clone(),
+            op,
+        },
+        alias,
+        out_type,
+    ))
+}
+
+fn new_acc(spec: &AggSpec) -> AggAcc {
+    match spec {
+        AggSpec::Rows => AggAcc::Rows(0),
+        AggSpec::NotNull(_) => AggAcc::NotNull(0),
+        AggSpec::Reduce { data_type, op, .. } => AggAcc::Reduce(
+            ColumnReducer::new(data_type, *op).expect("op validated against column type"),
+        ),
+    }
+}
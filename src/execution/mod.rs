@@ -2,13 +2,18 @@
 //!
 //! This module sits "above" [`crate::processing`] and provides:
 //!
-//! - Parallel (chunked) execution for filter/map
+//! - Parallel (chunked) execution for filter/map, fused [`crate::processing::Pipeline`]s,
+//!   grouped aggregation, and duplicate-row removal
 //! - Resource limits / throttling (e.g., in-flight chunks)
 //! - Real-time metrics + observer hooks for monitoring
 
+mod group_by;
 mod observer;
 mod semaphore;
 
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::ops::Range;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
@@ -16,7 +21,10 @@ use rayon::ThreadPool;
 use rayon::ThreadPoolBuilder;
 use rayon::prelude::*;
 
-use crate::processing::{ReduceOp, reduce};
+use crate::error::{IngestionError, IngestionResult};
+use crate::pipeline::Agg;
+use crate::processing::distinct::row_key;
+use crate::processing::{Pipeline, ReduceOp, ReducePipeline, reduce};
 use crate::types::{DataSet, Value};
 
 pub use observer::{
@@ -24,6 +32,7 @@ pub use observer::{
     StdErrExecutionObserver,
 };
 
+use group_by::GroupPlan;
 use semaphore::Semaphore;
 
 /// Configuration for the [`ExecutionEngine`].
@@ -41,6 +50,17 @@ pub struct ExecutionOptions {
     ///
     /// This is an additional throttle on top of `num_threads`.
     pub max_in_flight_chunks: usize,
+    /// Optional externally-owned rayon pool to run on instead of building a new one.
+    ///
+    /// When set, the engine shares this pool and `num_threads` is ignored (the external pool's
+    /// thread count wins). Useful when many short-lived engines run in one process.
+    pub pool: Option<Arc<ThreadPool>>,
+    /// Record each chunk's output row count in
+    /// [`ExecutionMetricsSnapshot::per_chunk_output`] (default `false`).
+    ///
+    /// Useful for spotting skew, e.g. a filter that drops almost everything in some chunks.
+    /// Off by default to avoid the per-run allocation.
+    pub track_chunk_output: bool,
 }
 
 impl Default for ExecutionOptions {
@@ -52,13 +72,15 @@ impl Default for ExecutionOptions {
             num_threads: Some(n),
             chunk_size: 4_096,
             max_in_flight_chunks: n.max(1),
+            pool: None,
+            track_chunk_output: false,
         }
     }
 }
 
 /// A configurable execution engine for in-memory [`DataSet`] pipelines.
 pub struct ExecutionEngine {
-    pool: ThreadPool,
+    pool: Arc<ThreadPool>,
     opts: ExecutionOptions,
     observer: Option<Arc<dyn ExecutionObserver>>,
     metrics: Arc<ExecutionMetrics>,
@@ -67,15 +89,16 @@ pub struct ExecutionEngine {
 impl ExecutionEngine {
     /// Create a new engine with the given options.
     ///
+    /// Builds a dedicated rayon pool unless [`ExecutionOptions::pool`] is set, in which case that
+    /// pool is shared (see [`ExecutionEngine::with_pool`]).
+    ///
     /// # Panics
     ///
     /// Panics if `chunk_size == 0`, `max_in_flight_chunks == 0`, or `num_threads == Some(0)`.
     pub fn new(opts: ExecutionOptions) -> Self {
-        assert!(opts.chunk_size > 0, "chunk_size must be > 0");
-        assert!(
-            opts.max_in_flight_chunks > 0,
-            "max_in_flight_chunks must be > 0"
-        );
+        if let Some(pool) = opts.pool.clone() {
+            return Self::with_pool(pool, opts);
+        }
         if let Some(n) = opts.num_threads {
             assert!(n > 0, "num_threads must be > 0 when set");
         }
@@ -94,9 +117,28 @@ impl ExecutionEngine {
             .build()
             .expect("failed to build rayon thread pool");
 
+        Self::with_pool(Arc::new(pool), opts)
+    }
+
+    /// Create an engine that runs on a shared, externally-owned rayon pool.
+    ///
+    /// Building a pool per engine is wasteful when many small pipelines run in one process; share
+    /// one pool across engines instead. The pool's thread count wins over `opts.num_threads`,
+    /// which is ignored here. Throttling (`max_in_flight_chunks`) and metrics remain per engine.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size == 0` or `max_in_flight_chunks == 0`.
+    pub fn with_pool(pool: Arc<ThreadPool>, opts: ExecutionOptions) -> Self {
+        assert!(opts.chunk_size > 0, "chunk_size must be > 0");
+        assert!(
+            opts.max_in_flight_chunks > 0,
+            "max_in_flight_chunks must be > 0"
+        );
+
         Self {
             pool,
-            opts: opts.clone(),
+            opts,
             observer: None,
             metrics: Arc::new(ExecutionMetrics::new()),
         }
@@ -114,6 +156,9 @@ impl ExecutionEngine {
     }
 
     /// Execute a parallel filter over the dataset.
+    ///
+    /// Output rows keep their input order: chunks may run out of order, but their results are
+    /// concatenated in chunk order, so the result equals [`crate::processing::filter`].
     pub fn filter_parallel<F>(&self, dataset: &DataSet, predicate: F) -> DataSet
     where
         F: Fn(&[Value]) -> bool + Send + Sync,
@@ -127,59 +172,29 @@ impl ExecutionEngine {
         dataset: &DataSet,
         predicate: &(dyn Fn(&[Value]) -> bool + Send + Sync),
     ) -> DataSet {
-        let start = Instant::now();
-        self.metrics.begin_run();
-        self.emit(ExecutionEvent::RunStarted);
-
-        let sem = Semaphore::new(self.opts.max_in_flight_chunks);
-        let chunk_ranges = chunk_ranges(dataset.row_count(), self.opts.chunk_size);
-
-        let per_chunk: Vec<Vec<Vec<Value>>> = chunk_ranges
-            .into_par_iter()
-            .map(|range| {
-                let waited = sem.acquire();
-                if waited > Duration::ZERO {
-                    self.metrics.on_throttle_wait(waited);
-                    self.emit(ExecutionEvent::ThrottleWaited { duration: waited });
+        let start = self.start_run();
+        let per_chunk = self.run_chunks(dataset.row_count(), |range| {
+            let mut out = Vec::new();
+            for row in &dataset.rows[range] {
+                self.metrics.on_row_processed();
+                if predicate(row.as_slice()) {
+                    out.push(row.clone());
                 }
-
-                self.metrics.on_chunk_start();
-                self.emit(ExecutionEvent::ChunkStarted {
-                    start_row: range.start,
-                    row_count: range.end - range.start,
-                });
-
-                let mut out = Vec::new();
-                for row in &dataset.rows[range] {
-                    self.metrics.on_row_processed();
-                    if predicate(row.as_slice()) {
-                        out.push(row.clone());
-                    }
-                }
-
-                self.emit(ExecutionEvent::ChunkFinished {
-                    output_rows: out.len(),
-                });
-                self.metrics.on_chunk_end();
-                sem.release();
-                out
-            })
-            .collect();
-
+            }
+            let output_rows = out.len();
+            (out, output_rows)
+        });
         let rows = per_chunk.into_iter().flatten().collect::<Vec<_>>();
         let out = DataSet::new(dataset.schema.clone(), rows);
-
-        self.metrics.end_run(start.elapsed());
-        self.emit(ExecutionEvent::RunFinished {
-            elapsed: start.elapsed(),
-            metrics: self.metrics.snapshot(),
-        });
-
+        self.finish_run(start);
         out
     }
 
     /// Execute a parallel map over the dataset.
     ///
+    /// Output row `i` is always `mapper(input row i)`, regardless of chunk scheduling (same order
+    /// as [`crate::processing::map`]).
+    ///
     /// # Panics
     ///
     /// Panics if `mapper` returns rows with a different length than the schema field count.
@@ -191,38 +206,96 @@ impl ExecutionEngine {
             .install(|| self.map_parallel_impl(dataset, &mapper))
     }
 
+    /// Execute a parallel transform of a single column, copying the other cells.
+    ///
+    /// Parallel counterpart of [`crate::processing::map_column`]: output equals the sequential
+    /// version, in input row order, with the same metrics/observer events as
+    /// [`ExecutionEngine::map_parallel`]. The schema is unchanged.
+    ///
+    /// Returns [`crate::IngestionError::SchemaMismatch`] if `name` is not in the schema.
+    pub fn map_column_parallel<F>(
+        &self,
+        dataset: &DataSet,
+        name: &str,
+        f: F,
+    ) -> IngestionResult<DataSet>
+    where
+        F: Fn(&Value) -> Value + Send + Sync,
+    {
+        let idx = dataset
+            .schema
+            .index_of(name)
+            .ok_or_else(|| IngestionError::SchemaMismatch {
+                message: format!("unknown column '{name}'"),
+            })?;
+        let mapper = |row: &[Value]| -> Vec<Value> {
+            row.iter()
+                .enumerate()
+                .map(|(i, v)| if i == idx { f(v) } else { v.clone() })
+                .collect()
+        };
+        Ok(self
+            .pool
+            .install(|| self.map_parallel_impl(dataset, &mapper)))
+    }
+
     fn map_parallel_impl(
         &self,
         dataset: &DataSet,
         mapper: &(dyn Fn(&[Value]) -> Vec<Value> + Send + Sync),
     ) -> DataSet {
-        let start = Instant::now();
-        self.metrics.begin_run();
-        self.emit(ExecutionEvent::RunStarted);
-
+        let start = self.start_run();
         let expected_len = dataset.schema.fields.len();
-        let sem = Semaphore::new(self.opts.max_in_flight_chunks);
-        let chunk_ranges = chunk_ranges(dataset.row_count(), self.opts.chunk_size);
-
-        let per_chunk: Vec<Vec<Vec<Value>>> = chunk_ranges
-            .into_par_iter()
-            .map(|range| {
-                let waited = sem.acquire();
-                if waited > Duration::ZERO {
-                    self.metrics.on_throttle_wait(waited);
-                    self.emit(ExecutionEvent::ThrottleWaited { duration: waited });
-                }
+        let per_chunk = self.run_chunks(dataset.row_count(), |range| {
+            let mut out = Vec::with_capacity(range.end - range.start);
+            for row in &dataset.rows[range] {
+                self.metrics.on_row_processed();
+                let mapped = mapper(row.as_slice());
+                assert!(
+                    mapped.len() == expected_len,
+                    "mapped row length {} does not match schema length {}",
+                    mapped.len(),
+                    expected_len
+                );
+                out.push(mapped);
+            }
+            let output_rows = out.len();
+            (out, output_rows)
+        });
+        let rows = per_chunk.into_iter().flatten().collect::<Vec<_>>();
+        let out = DataSet::new(dataset.schema.clone(), rows);
+        self.finish_run(start);
+        out
+    }
 
-                self.metrics.on_chunk_start();
-                self.emit(ExecutionEvent::ChunkStarted {
-                    start_row: range.start,
-                    row_count: range.end - range.start,
-                });
+    /// Execute a fused parallel filter + map over the dataset in a single pass.
+    ///
+    /// `mapper` returns `None` to drop a row or `Some(row)` to keep the mapped row. Output order
+    /// follows input order, as with [`ExecutionEngine::filter_parallel`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mapper` returns rows with a different length than the schema field count.
+    pub fn filter_map_parallel<F>(&self, dataset: &DataSet, mapper: F) -> DataSet
+    where
+        F: Fn(&[Value]) -> Option<Vec<Value>> + Send + Sync,
+    {
+        self.pool
+            .install(|| self.filter_map_parallel_impl(dataset, &mapper))
+    }
 
-                let mut out = Vec::with_capacity(range.end - range.start);
-                for row in &dataset.rows[range] {
-                    self.metrics.on_row_processed();
-                    let mapped = mapper(row.as_slice());
+    fn filter_map_parallel_impl(
+        &self,
+        dataset: &DataSet,
+        mapper: &(dyn Fn(&[Value]) -> Option<Vec<Value>> + Send + Sync),
+    ) -> DataSet {
+        let start = self.start_run();
+        let expected_len = dataset.schema.fields.len();
+        let per_chunk = self.run_chunks(dataset.row_count(), |range| {
+            let mut out = Vec::new();
+            for row in &dataset.rows[range] {
+                self.metrics.on_row_processed();
+                if let Some(mapped) = mapper(row.as_slice()) {
                     assert!(
                         mapped.len() == expected_len,
                         "mapped row length {} does not match schema length {}",
@@ -231,25 +304,169 @@ impl ExecutionEngine {
                     );
                     out.push(mapped);
                 }
-
-                self.emit(ExecutionEvent::ChunkFinished {
-                    output_rows: out.len(),
-                });
-                self.metrics.on_chunk_end();
-                sem.release();
-                out
-            })
-            .collect();
-
+            }
+            let output_rows = out.len();
+            (out, output_rows)
+        });
         let rows = per_chunk.into_iter().flatten().collect::<Vec<_>>();
         let out = DataSet::new(dataset.schema.clone(), rows);
+        self.finish_run(start);
+        out
+    }
 
-        self.metrics.end_run(start.elapsed());
-        self.emit(ExecutionEvent::RunFinished {
-            elapsed: start.elapsed(),
-            metrics: self.metrics.snapshot(),
+    /// Run a fused [`Pipeline`] (filter/map stages) chunk-by-chunk in parallel.
+    ///
+    /// Each row passes through every stage in enqueue order within one pass; output rows keep
+    /// their input order, so the result equals [`Pipeline::run`].
+    pub fn run_pipeline(&self, dataset: &DataSet, pipeline: &Pipeline) -> DataSet {
+        let expected_len = dataset.schema.fields.len();
+        self.filter_map_parallel(dataset, |row| {
+            pipeline.apply_row(row, expected_len).map(Cow::into_owned)
+        })
+    }
+
+    /// Run a [`ReducePipeline`] in parallel: each chunk applies the fused stages and reduces into
+    /// a partial accumulator, and partials are combined in chunk order.
+    ///
+    /// Returns `None` if the reduced column does not exist or an Int64 sum overflows (same as
+    /// [`ReducePipeline::run`]).
+    /// Floating-point results may differ from the sequential run in the last bits because
+    /// partial sums are combined in a different order.
+    pub fn run_reduce_pipeline(
+        &self,
+        dataset: &DataSet,
+        pipeline: &ReducePipeline,
+    ) -> Option<Value> {
+        self.pool
+            .install(|| self.run_reduce_pipeline_impl(dataset, pipeline))
+    }
+
+    fn run_reduce_pipeline_impl(
+        &self,
+        dataset: &DataSet,
+        pipeline: &ReducePipeline,
+    ) -> Option<Value> {
+        let start = self.start_run();
+        self.emit(ExecutionEvent::ReduceStarted {
+            column: pipeline.column().to_string(),
+            op: pipeline.op(),
+        });
+
+        let out = pipeline.reducer(dataset).and_then(|(idx, _)| {
+            let expected_len = dataset.schema.fields.len();
+            let partials = self.run_chunks(dataset.row_count(), |range| {
+                let (_, mut acc) = pipeline.reducer(dataset).expect("column checked above");
+                let mut output_rows = 0usize;
+                for row in &dataset.rows[range] {
+                    self.metrics.on_row_processed();
+                    if let Some(out) = pipeline.pipeline().apply_row(row, expected_len) {
+                        output_rows += 1;
+                        acc.observe(out.get(idx));
+                    }
+                }
+                (acc, output_rows)
+            });
+
+            let (_, mut total) = pipeline.reducer(dataset).expect("column checked above");
+            for partial in partials {
+                total.merge(partial);
+            }
+            total.finish()
+        });
+
+        self.emit(ExecutionEvent::ReduceFinished {
+            result: out.clone(),
+        });
+        self.finish_run(start);
+        out
+    }
+
+    /// Group rows by `keys` and compute `aggs` in parallel.
+    ///
+    /// Each chunk builds per-group partial accumulators; partials are merged in chunk order
+    /// using each aggregation's combine rule (counts add, sums/extrema combine, mean/variance
+    /// merge Welford states). Output columns are the keys followed by one column per alias.
+    ///
+    /// Groups appear in order of first appearance in `dataset` (the Polars-backed
+    /// [`crate::pipeline::DataFrame::group_by`] leaves order unspecified). Values follow
+    /// [`crate::processing::reduce`], so an all-null `Sum` is `Value::Null` rather than `0`;
+    /// floating-point results may differ in the last bits from a sequential run.
+    ///
+    /// Returns [`crate::IngestionError::SchemaMismatch`] for empty `keys` / `aggs`, unknown
+    /// columns, numeric aggregations over non-numeric columns, or an Int64 sum that overflows
+    /// (see [`ReduceOp::Sum`]).
+    pub fn group_by_parallel(
+        &self,
+        dataset: &DataSet,
+        keys: &[&str],
+        aggs: &[Agg],
+    ) -> IngestionResult<DataSet> {
+        let plan = GroupPlan::new(&dataset.schema, keys, aggs)?;
+        self.pool
+            .install(|| self.group_by_parallel_impl(dataset, &plan))
+    }
+
+    fn group_by_parallel_impl(
+        &self,
+        dataset: &DataSet,
+        plan: &GroupPlan,
+    ) -> IngestionResult<DataSet> {
+        let start = self.start_run();
+        let partials = self.run_chunks(dataset.row_count(), |range| {
+            let mut partial = plan.partial();
+            for row in &dataset.rows[range] {
+                self.metrics.on_row_processed();
+                plan.observe(&mut partial, row);
+            }
+            let groups = partial.group_count();
+            (partial, groups)
         });
 
+        let mut total = plan.partial();
+        for partial in partials {
+            total.merge(partial);
+        }
+        let out = plan.finish(total);
+        self.finish_run(start);
+        out
+    }
+
+    /// Remove duplicate rows in parallel; same result and order as
+    /// [`crate::processing::distinct`].
+    ///
+    /// Each chunk hashes its rows into a partial set that remembers the first source index per
+    /// row; partial sets are merged keeping the minimum index, and rows are emitted in index
+    /// order. Floats hash by bit pattern (`0.0` and `-0.0` differ; identical NaNs match).
+    pub fn distinct_parallel(&self, dataset: &DataSet) -> DataSet {
+        self.pool.install(|| self.distinct_parallel_impl(dataset))
+    }
+
+    fn distinct_parallel_impl(&self, dataset: &DataSet) -> DataSet {
+        let start = self.start_run();
+        let partials = self.run_chunks(dataset.row_count(), |range| {
+            let mut partial: HashMap<Vec<String>, usize> = HashMap::new();
+            for (idx, row) in dataset.rows[range.clone()].iter().enumerate() {
+                self.metrics.on_row_processed();
+                partial.entry(row_key(row)).or_insert(range.start + idx);
+            }
+            let unique = partial.len();
+            (partial, unique)
+        });
+
+        let mut first_seen: HashMap<Vec<String>, usize> = HashMap::new();
+        for partial in partials {
+            for (key, idx) in partial {
+                first_seen
+                    .entry(key)
+                    .and_modify(|min| *min = (*min).min(idx))
+                    .or_insert(idx);
+            }
+        }
+        let mut idxs: Vec<usize> = first_seen.into_values().collect();
+        idxs.sort_unstable();
+        let rows = idxs.into_iter().map(|i| dataset.rows[i].clone()).collect();
+        let out = DataSet::new(dataset.schema.clone(), rows);
+        self.finish_run(start);
         out
     }
 
@@ -257,9 +474,7 @@ impl ExecutionEngine {
     ///
     /// This is currently sequential, but is tracked via the observer/metrics hooks.
     pub fn reduce(&self, dataset: &DataSet, column: &str, op: ReduceOp) -> Option<Value> {
-        let start = Instant::now();
-        self.metrics.begin_run();
-        self.emit(ExecutionEvent::RunStarted);
+        let start = self.start_run();
         self.emit(ExecutionEvent::ReduceStarted {
             column: column.to_string(),
             op,
@@ -270,12 +485,78 @@ impl ExecutionEngine {
         self.emit(ExecutionEvent::ReduceFinished {
             result: out.clone(),
         });
+        self.finish_run(start);
+        out
+    }
+
+    /// Reset the metrics for a new run and emit `RunStarted`; returns the run's start time.
+    fn start_run(&self) -> Instant {
+        let start = Instant::now();
+        self.metrics.begin_run();
+        self.emit(ExecutionEvent::RunStarted);
+        start
+    }
+
+    /// Record the elapsed time of the run begun at `start` and emit `RunFinished`.
+    fn finish_run(&self, start: Instant) {
         self.metrics.end_run(start.elapsed());
         self.emit(ExecutionEvent::RunFinished {
             elapsed: start.elapsed(),
             metrics: self.metrics.snapshot(),
         });
-        out
+    }
+
+    /// Run `work` over chunks of `row_count` rows in parallel and return its results in chunk
+    /// order.
+    ///
+    /// Applies the `max_in_flight_chunks` throttle and emits the per-chunk events and metrics.
+    /// `work` receives the chunk's row range and returns its result plus the chunk's output row
+    /// count; it reports each input row itself via `on_row_processed`.
+    fn run_chunks<T, W>(&self, row_count: usize, work: W) -> Vec<T>
+    where
+        T: Send,
+        W: Fn(Range<usize>) -> (T, usize) + Send + Sync,
+    {
+        let sem = Semaphore::new(self.opts.max_in_flight_chunks);
+        self.plan_chunks(row_count)
+            .into_par_iter()
+            .enumerate()
+            .map(|(chunk, range)| {
+                let waited = sem.acquire();
+                if waited > Duration::ZERO {
+                    self.metrics.on_throttle_wait(waited);
+                    self.emit(ExecutionEvent::ThrottleWaited { duration: waited });
+                }
+
+                self.metrics.on_chunk_start();
+                self.emit(ExecutionEvent::ChunkStarted {
+                    start_row: range.start,
+                    row_count: range.end - range.start,
+                });
+
+                let (out, output_rows) = work(range);
+
+                self.finish_chunk(chunk, output_rows);
+                sem.release();
+                out
+            })
+            .collect()
+    }
+
+    /// Split `row_count` rows into chunks, sizing per-chunk output tracking when enabled.
+    fn plan_chunks(&self, row_count: usize) -> Vec<Range<usize>> {
+        let ranges = chunk_ranges(row_count, self.opts.chunk_size);
+        if self.opts.track_chunk_output {
+            self.metrics.track_chunk_output(ranges.len());
+        }
+        ranges
+    }
+
+    /// Emit `ChunkFinished` for chunk index `chunk` and update the metrics.
+    fn finish_chunk(&self, chunk: usize, output_rows: usize) {
+        self.emit(ExecutionEvent::ChunkFinished { output_rows });
+        self.metrics.on_chunk_output(chunk, output_rows);
+        self.metrics.on_chunk_end();
     }
 
     fn emit(&self, event: ExecutionEvent) {
@@ -285,7 +566,7 @@ impl ExecutionEngine {
     }
 }
 
-fn chunk_ranges(row_count: usize, chunk_size: usize) -> Vec<std::ops::Range<usize>> {
+fn chunk_ranges(row_count: usize, chunk_size: usize) -> Vec<Range<usize>> {
     if row_count == 0 {
         return Vec::new();
     }
@@ -302,11 +583,14 @@ fn chunk_ranges(row_count: usize, chunk_size: usize) -> Vec<std::ops::Range<usiz
 #[cfg(test)]
 mod tests {
     use super::{ExecutionEngine, ExecutionOptions};
+    use rayon::ThreadPoolBuilder;
     use std::sync::Arc;
     use std::sync::atomic::{AtomicUsize, Ordering};
     use std::time::Duration;
 
     use crate::execution::{ExecutionEvent, ExecutionObserver};
+    use crate::pipeline::{Agg, DataFrame};
+    use crate::processing::{Pipeline, ReduceOp, VarianceKind, distinct, filter, map, map_column};
     use crate::types::{DataSet, DataType, Field, Schema, Value};
 
     fn dataset_of_n(n: usize) -> DataSet {
@@ -325,6 +609,8 @@ mod tests {
             num_threads: Some(4),
             chunk_size: 1,
             max_in_flight_chunks: 4,
+            pool: None,
+            track_chunk_output: false,
         });
 
         let active = Arc::new(AtomicUsize::new(0));
@@ -419,6 +705,8 @@ mod tests {
             num_threads: Some(4),
             chunk_size: 1,
             max_in_flight_chunks: 1,
+            pool: None,
+            track_chunk_output: false,
         })
         .with_observer(obs_trait);
 
@@ -439,6 +727,8 @@ mod tests {
             num_threads: Some(4),
             chunk_size: 1,
             max_in_flight_chunks: 1,
+            pool: None,
+            track_chunk_output: false,
         });
         let metrics = engine.metrics();
 
@@ -457,4 +747,367 @@ mod tests {
         assert!(snap.throttle_wait > Duration::ZERO);
         assert!(snap.elapsed.is_some());
     }
+
+    #[test]
+    fn per_chunk_output_is_tracked_only_when_enabled() {
+        let ds = dataset_of_n(45);
+        let keep_low = |row: &[Value]| matches!(row[0], Value::Int64(x) if x < 15);
+        let opts = ExecutionOptions {
+            num_threads: Some(4),
+            chunk_size: 10,
+            max_in_flight_chunks: 4,
+            pool: None,
+            track_chunk_output: false,
+        };
+
+        let engine = ExecutionEngine::new(opts.clone());
+        engine.filter_parallel(&ds, keep_low);
+        let snap = engine.metrics().snapshot();
+        assert_eq!(snap.per_chunk_output, None);
+        assert!(!snap.to_string().contains("chunk_output"));
+
+        let engine = ExecutionEngine::new(ExecutionOptions {
+            track_chunk_output: true,
+            ..opts
+        });
+        engine.filter_parallel(&ds, keep_low);
+        let snap = engine.metrics().snapshot();
+        assert_eq!(snap.per_chunk_output, Some(vec![10, 5, 0, 0, 0]));
+        assert!(
+            snap.to_string()
+                .contains("chunk_output(min/avg/max)=0/3.0/10")
+        );
+
+        // Reset per run.
+        engine.map_parallel(&ds, |row| row.to_vec());
+        assert_eq!(
+            engine.metrics().snapshot().per_chunk_output,
+            Some(vec![10, 10, 10, 10, 5])
+        );
+    }
+
+    #[test]
+    fn engines_can_share_an_external_pool() {
+        let ds = dataset_of_n(50);
+        let pool = Arc::new(ThreadPoolBuilder::new().num_threads(2).build().unwrap());
+        let opts = ExecutionOptions {
+            num_threads: Some(8),
+            chunk_size: 4,
+            max_in_flight_chunks: 2,
+            pool: None,
+            track_chunk_output: false,
+        };
+
+        let a = ExecutionEngine::with_pool(Arc::clone(&pool), opts.clone());
+        let b = ExecutionEngine::new(ExecutionOptions {
+            pool: Some(Arc::clone(&pool)),
+            ..opts
+        });
+
+        // External pool wins over `num_threads`.
+        let threads_a = a.pool.install(rayon::current_num_threads);
+        let threads_b = b.pool.install(rayon::current_num_threads);
+        assert_eq!(threads_a, 2);
+        assert_eq!(threads_b, 2);
+        assert!(Arc::ptr_eq(&a.pool, &b.pool));
+
+        let out_a = a.map_parallel(&ds, |row| row.to_vec());
+        let out_b = b.filter_parallel(&ds, |_row| true);
+        assert_eq!(out_a, ds);
+        assert_eq!(out_b, ds);
+    }
+
+    #[test]
+    fn parallel_filter_and_map_preserve_sequential_row_order() {
+        let ds = dataset_of_n(1_000);
+        let engine = ExecutionEngine::new(ExecutionOptions {
+            num_threads: Some(4),
+            chunk_size: 7,
+            max_in_flight_chunks: 4,
+            pool: None,
+            track_chunk_output: false,
+        });
+
+        // Uneven per-row work so later chunks can finish before earlier ones.
+        let jitter = |row: &[Value]| {
+            if matches!(row[0], Value::Int64(x) if x % 97 == 0) {
+                std::thread::sleep(Duration::from_millis(1));
+            }
+        };
+
+        let mapped = engine.map_parallel(&ds, |row| {
+            jitter(row);
+            row.to_vec()
+        });
+        assert_eq!(mapped, map(&ds, |row| row.to_vec()));
+        assert_eq!(mapped, ds);
+
+        let in_range = |row: &[Value]| matches!(row[0], Value::Int64(x) if (100..900).contains(&x));
+        let filtered = engine.filter_parallel(&ds, |row| {
+            jitter(row);
+            in_range(row)
+        });
+        assert_eq!(filtered, filter(&ds, in_range));
+        assert_eq!(filtered.row_count(), 800);
+        assert_eq!(filtered.rows[0], vec![Value::Int64(100)]);
+        assert_eq!(filtered.rows[799], vec![Value::Int64(899)]);
+    }
+
+    #[test]
+    fn map_column_parallel_matches_sequential_map_column() {
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int64),
+            Field::new("name", DataType::Utf8),
+        ]);
+        let rows = (0..500)
+            .map(|i| {
+                let name = if i % 5 == 0 {
+                    Value::Null
+                } else {
+                    Value::Utf8(format!("user-{i}"))
+                };
+                vec![Value::Int64(i), name]
+            })
+            .collect();
+        let ds = DataSet::new(schema, rows);
+        let engine = ExecutionEngine::new(ExecutionOptions {
+            num_threads: Some(4),
+            chunk_size: 16,
+            max_in_flight_chunks: 4,
+            pool: None,
+            track_chunk_output: false,
+        });
+        let upper = |v: &Value| match v {
+            Value::Utf8(s) => Value::Utf8(s.to_uppercase()),
+            other => other.clone(),
+        };
+
+        let out = engine.map_column_parallel(&ds, "name", upper).unwrap();
+        assert_eq!(out, map_column(&ds, "name", upper).unwrap());
+        assert_eq!(out.rows[1][1], Value::Utf8("USER-1".to_string()));
+        assert_eq!(engine.metrics().snapshot().rows_processed, 500);
+        assert!(engine.map_column_parallel(&ds, "missing", upper).is_err());
+    }
+
+    #[test]
+    fn filter_map_parallel_fuses_filter_and_map() {
+        let ds = dataset_of_n(100);
+        let engine = ExecutionEngine::new(ExecutionOptions {
+            num_threads: Some(4),
+            chunk_size: 8,
+            max_in_flight_chunks: 2,
+            pool: None,
+            track_chunk_output: false,
+        });
+        let metrics = engine.metrics();
+
+        let out = engine.filter_map_parallel(&ds, |row| match row[0] {
+            Value::Int64(x) if x % 2 == 0 => Some(vec![Value::Int64(x * 10)]),
+            _ => None,
+        });
+
+        assert_eq!(out.row_count(), 50);
+        assert_eq!(out.rows[0], vec![Value::Int64(0)]);
+        assert_eq!(out.rows[49], vec![Value::Int64(980)]);
+        // Single pass: every input row visited exactly once.
+        assert_eq!(metrics.snapshot().rows_processed, 100);
+    }
+
+    #[test]
+    #[should_panic(expected = "does not match schema length")]
+    fn filter_map_parallel_rejects_wrong_arity() {
+        let ds = dataset_of_n(4);
+        let engine = ExecutionEngine::new(ExecutionOptions {
+            num_threads: Some(1),
+            chunk_size: 4,
+            max_in_flight_chunks: 1,
+            pool: None,
+            track_chunk_output: false,
+        });
+        let _ = engine.filter_map_parallel(&ds, |_row| Some(vec![]));
+    }
+
+    fn even_times_ten() -> Pipeline {
+        Pipeline::new()
+            .filter(|row| matches!(row[0], Value::Int64(x) if x % 2 == 0))
+            .map(|row| match row[0] {
+                Value::Int64(x) => vec![Value::Int64(x * 10)],
+                _ => row.to_vec(),
+            })
+    }
+
+    #[test]
+    fn run_pipeline_matches_sequential_pipeline() {
+        let ds = dataset_of_n(103);
+        let engine = ExecutionEngine::new(ExecutionOptions {
+            num_threads: Some(4),
+            chunk_size: 7,
+            max_in_flight_chunks: 3,
+            pool: None,
+            track_chunk_output: false,
+        });
+        let metrics = engine.metrics();
+
+        let pipeline = even_times_ten();
+        let out = engine.run_pipeline(&ds, &pipeline);
+
+        assert_eq!(out, pipeline.run(&ds));
+        assert_eq!(out.row_count(), 52);
+        assert_eq!(metrics.snapshot().rows_processed, 103);
+    }
+
+    #[test]
+    fn run_reduce_pipeline_combines_chunk_partials() {
+        let ds = dataset_of_n(103);
+        let engine = ExecutionEngine::new(ExecutionOptions {
+            num_threads: Some(4),
+            chunk_size: 7,
+            max_in_flight_chunks: 3,
+            pool: None,
+            track_chunk_output: false,
+        });
+
+        for op in [
+            ReduceOp::Count,
+            ReduceOp::Sum,
+            ReduceOp::Min,
+            ReduceOp::Max,
+            ReduceOp::CountDistinctNonNull,
+        ] {
+            let p = even_times_ten().reduce("id", op);
+            assert_eq!(engine.run_reduce_pipeline(&ds, &p), p.run(&ds), "{op:?}");
+        }
+
+        let p = even_times_ten().reduce("id", ReduceOp::Variance(VarianceKind::Sample));
+        let (Some(Value::Float64(par)), Some(Value::Float64(seq))) =
+            (engine.run_reduce_pipeline(&ds, &p), p.run(&ds))
+        else {
+            panic!("expected float variance");
+        };
+        assert!((par - seq).abs() < 1e-6 * seq.abs());
+
+        let missing = even_times_ten().reduce("missing", ReduceOp::Sum);
+        assert_eq!(engine.run_reduce_pipeline(&ds, &missing), None);
+    }
+
+    #[test]
+    fn group_by_parallel_matches_polars_group_by() {
+        let schema = Schema::new(vec![
+            Field::new("g", DataType::Utf8),
+            Field::new("id", DataType::Int64),
+            Field::new("x", DataType::Float64),
+        ]);
+        let rows = (0..250i64)
+            .map(|i| {
+                vec![
+                    Value::Utf8(format!("k{}", i % 7)),
+                    Value::Int64(i),
+                    if i % 5 == 0 {
+                        Value::Null
+                    } else {
+                        Value::Float64(i as f64 * 0.5)
+                    },
+                ]
+            })
+            .collect();
+        let ds = DataSet::new(schema, rows);
+        let aggs = vec![
+            Agg::CountRows {
+                alias: "n".to_string(),
+            },
+            Agg::CountNotNull {
+                column: "x".to_string(),
+                alias: "nx".to_string(),
+            },
+            Agg::Sum {
+                column: "id".to_string(),
+                alias: "sum_id".to_string(),
+            },
+            Agg::Max {
+                column: "x".to_string(),
+                alias: "max_x".to_string(),
+            },
+            Agg::StdDev {
+                column: "x".to_string(),
+                alias: "sd_x".to_string(),
+                kind: VarianceKind::Sample,
+            },
+        ];
+
+        let engine = ExecutionEngine::new(ExecutionOptions {
+            num_threads: Some(4),
+            chunk_size: 16,
+            max_in_flight_chunks: 3,
+            pool: None,
+            track_chunk_output: false,
+        });
+        let par = engine.group_by_parallel(&ds, &["g"], &aggs).unwrap();
+        // Groups keep first-appearance order.
+        let keys: Vec<_> = par.column_str("g").unwrap().into_iter().flatten().collect();
+        assert_eq!(keys, vec!["k0", "k1", "k2", "k3", "k4", "k5", "k6"]);
+        assert_eq!(engine.metrics().snapshot().rows_processed, 250);
+
+        let mut seq = DataFrame::from_dataset(&ds)
+            .unwrap()
+            .group_by(&["g"], &aggs)
+            .unwrap()
+            .collect()
+            .unwrap();
+        seq.rows
+            .sort_by(|a, b| format!("{:?}", a[0]).cmp(&format!("{:?}", b[0])));
+        assert_eq!(
+            par.schema.field_names().collect::<Vec<_>>(),
+            seq.schema.field_names().collect::<Vec<_>>()
+        );
+        for (p, s) in par.rows.iter().zip(&seq.rows) {
+            assert_eq!(p[..5], s[..5]);
+            match (&p[5], &s[5]) {
+                (Value::Float64(a), Value::Float64(b)) => assert!((a - b).abs() < 1e-9 * b.abs()),
+                (a, b) => assert_eq!(a, b),
+            }
+        }
+
+        assert!(engine.group_by_parallel(&ds, &["g"], &[]).is_err());
+        let bad = [Agg::Mean {
+            column: "g".to_string(),
+            alias: "m".to_string(),
+        }];
+        assert!(engine.group_by_parallel(&ds, &["g"], &bad).is_err());
+    }
+
+    #[test]
+    fn distinct_parallel_matches_sequential_distinct() {
+        let schema = Schema::new(vec![
+            Field::new("k", DataType::Int64),
+            Field::new("x", DataType::Float64),
+        ]);
+        let rows = (0..500i64)
+            .map(|i| {
+                let k = (i * 7919) % 37;
+                let x = if k % 4 == 0 {
+                    Value::Null
+                } else {
+                    Value::Float64((k % 5) as f64)
+                };
+                vec![Value::Int64(k), x]
+            })
+            .collect();
+        let ds = DataSet::new(schema, rows);
+
+        let engine = ExecutionEngine::new(ExecutionOptions {
+            num_threads: Some(4),
+            chunk_size: 16,
+            max_in_flight_chunks: 3,
+            pool: None,
+            track_chunk_output: true,
+        });
+        let par = engine.distinct_parallel(&ds);
+        assert_eq!(par, distinct(&ds));
+        assert_eq!(par.row_count(), 37);
+
+        let snap = engine.metrics().snapshot();
+        assert_eq!(snap.rows_processed, 500);
+        assert_eq!(snap.chunks_finished, 32);
+        assert_eq!(snap.per_chunk_output.map(|v| v.len()), Some(32));
+    }
 }
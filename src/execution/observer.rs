@@ -63,6 +63,8 @@ pub struct ExecutionMetrics {
 
     active_chunks: AtomicUsize,
     max_active_chunks: AtomicUsize,
+
+    per_chunk_output: Mutex<Option<Vec<usize>>>,
 }
 
 impl ExecutionMetrics {
@@ -77,6 +79,7 @@ impl ExecutionMetrics {
             throttle_wait_ns: AtomicU64::new(0),
             active_chunks: AtomicUsize::new(0),
             max_active_chunks: AtomicUsize::new(0),
+            per_chunk_output: Mutex::new(None),
         }
     }
 
@@ -91,6 +94,29 @@ impl ExecutionMetrics {
         self.throttle_wait_ns.store(0, Ordering::SeqCst);
         self.active_chunks.store(0, Ordering::SeqCst);
         self.max_active_chunks.store(0, Ordering::SeqCst);
+        *self
+            .per_chunk_output
+            .lock()
+            .expect("metrics mutex poisoned") = None;
+    }
+
+    /// Start recording per-chunk output row counts for a run with `chunks` chunks.
+    pub fn track_chunk_output(&self, chunks: usize) {
+        *self
+            .per_chunk_output
+            .lock()
+            .expect("metrics mutex poisoned") = Some(vec![0; chunks]);
+    }
+
+    /// Record that chunk `chunk` produced `output_rows` rows (no-op unless tracking).
+    pub fn on_chunk_output(&self, chunk: usize, output_rows: usize) {
+        let mut guard = self
+            .per_chunk_output
+            .lock()
+            .expect("metrics mutex poisoned");
+        if let Some(slot) = guard.as_mut().and_then(|v| v.get_mut(chunk)) {
+            *slot = output_rows;
+        }
     }
 
     pub fn end_run(&self, elapsed: Duration) {
@@ -137,6 +163,11 @@ impl ExecutionMetrics {
             chunks_finished: self.chunks_finished.load(Ordering::SeqCst),
             throttle_wait: Duration::from_nanos(self.throttle_wait_ns.load(Ordering::SeqCst)),
             max_active_chunks: self.max_active_chunks.load(Ordering::SeqCst),
+            per_chunk_output: self
+                .per_chunk_output
+                .lock()
+                .expect("metrics mutex poisoned")
+                .clone(),
         }
     }
 }
@@ -172,6 +203,9 @@ pub struct ExecutionMetricsSnapshot {
     pub chunks_finished: u64,
     pub throttle_wait: Duration,
     pub max_active_chunks: usize,
+    /// Output rows per chunk, in chunk order; `None` unless
+    /// [`super::ExecutionOptions::track_chunk_output`] is set.
+    pub per_chunk_output: Option<Vec<usize>>,
 }
 
 impl fmt::Display for ExecutionMetricsSnapshot {
@@ -186,6 +220,13 @@ impl fmt::Display for ExecutionMetricsSnapshot {
             self.max_active_chunks,
             self.throttle_wait,
             self.elapsed
-        )
+        )?;
+        if let Some(per_chunk) = self.per_chunk_output.as_deref().filter(|v| !v.is_empty()) {
+            let min = per_chunk.iter().min().copied().unwrap_or(0);
+            let max = per_chunk.iter().max().copied().unwrap_or(0);
+            let avg = per_chunk.iter().sum::<usize>() as f64 / per_chunk.len() as f64;
+            write!(f, ", chunk_output(min/avg/max)={min}/{avg:.1}/{max}")?;
+        }
+        Ok(())
     }
 }
@@ -0,0 +1,55 @@
+//! CSV export implementation.
+
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use base64::Engine as _;
+use base64::engine::general_purpose::STANDARD as BASE64;
+
+use crate::error::IngestionResult;
+use crate::types::{DataSet, Value};
+
+/// Write a [`DataSet`] as CSV to `w`.
+///
+/// Rules:
+///
+/// - The first record is the header (schema field names, in order).
+/// - `Value::Null` is written as an empty field; other values use their natural string form
+///   (`Value::Bytes` as standard base64, as in JSON export and CSV ingestion; `Value::Timestamp`
+///   as RFC 3339 UTC).
+/// - Fields containing the delimiter, quotes, or newlines are quoted by the `csv` writer.
+pub fn write_csv<W: Write>(ds: &DataSet, w: &mut W) -> IngestionResult<()> {
+    let mut wtr = ::csv::Writer::from_writer(w);
+    wtr.write_record(ds.schema.field_names())?;
+
+    let mut record: Vec<String> = Vec::with_capacity(ds.schema.fields.len());
+    for row in &ds.rows {
+        record.clear();
+        record.extend(row.iter().map(value_to_csv_field));
+        wtr.write_record(&record)?;
+    }
+
+    wtr.flush()?;
+    Ok(())
+}
+
+/// Write a [`DataSet`] as CSV to a file at `path` (created or truncated).
+///
+/// See [`write_csv`] for formatting rules.
+pub fn write_csv_to_path(ds: &DataSet, path: impl AsRef<Path>) -> IngestionResult<()> {
+    let mut file = File::create(path)?;
+    write_csv(ds, &mut file)
+}
+
+fn value_to_csv_field(v: &Value) -> String {
+    match v {
+        Value::Null => String::new(),
+        Value::Int64(x) => x.to_string(),
+        Value::Float64(x) => x.to_string(),
+        Value::Bool(x) => x.to_string(),
+        Value::Utf8(s) => s.clone(),
+        Value::Bytes(b) => BASE64.encode(b),
+        Value::Timestamp(_) => v.to_string(),
+    }
+}
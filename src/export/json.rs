@@ -0,0 +1,121 @@
+//! JSON / NDJSON export implementation.
+//!
+//! Dotted schema field names (e.g. `user.name`) are written as nested objects, mirroring how
+//! [`crate::ingestion::json`] resolves dot paths on the way in.
+
+use std::io::Write;
+
+use base64::Engine as _;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use serde_json::{Map, Number};
+
+use crate::error::{IngestionError, IngestionResult};
+use crate::types::{DataSet, Value};
+
+/// Write a [`DataSet`] to `w` as a JSON array of objects keyed by schema field names.
+///
+/// `Value::Null` (and non-finite floats, which JSON cannot represent) are written as `null`;
+/// `Value::Bytes` is written as a standard base64 string and `Value::Timestamp` as RFC 3339 UTC
+/// text.
+/// Set `pretty` for indented output.
+pub fn write_json<W: Write>(ds: &DataSet, w: &mut W, pretty: bool) -> IngestionResult<()> {
+    let mut items = Vec::with_capacity(ds.row_count());
+    for (row_idx0, row) in ds.rows.iter().enumerate() {
+        items.push(serde_json::Value::Object(row_to_object(ds, row_idx0, row)?));
+    }
+    let array = serde_json::Value::Array(items);
+
+    let res = if pretty {
+        serde_json::to_writer_pretty(&mut *w, &array)
+    } else {
+        serde_json::to_writer(&mut *w, &array)
+    };
+    res.map_err(json_write_error)?;
+    w.flush()?;
+    Ok(())
+}
+
+/// Write a [`DataSet`] to `w` as newline-delimited JSON (one object per line).
+///
+/// Values are rendered the same way as [`write_json`].
+pub fn write_ndjson<W: Write>(ds: &DataSet, w: &mut W) -> IngestionResult<()> {
+    for (row_idx0, row) in ds.rows.iter().enumerate() {
+        let obj = serde_json::Value::Object(row_to_object(ds, row_idx0, row)?);
+        serde_json::to_writer(&mut *w, &obj).map_err(json_write_error)?;
+        w.write_all(b"\n")?;
+    }
+    w.flush()?;
+    Ok(())
+}
+
+fn row_to_object(
+    ds: &DataSet,
+    row_idx0: usize,
+    row: &[Value],
+) -> IngestionResult<Map<String, serde_json::Value>> {
+    let mut obj = Map::new();
+    for (field, v) in ds.schema.fields.iter().zip(row.iter()) {
+        insert_by_dot_path(&mut obj, &field.name, value_to_json(v)).map_err(|message| {
+            IngestionError::SchemaMismatch {
+                message: format!("row {}: {message}", row_idx0 + 1),
+            }
+        })?;
+    }
+    Ok(obj)
+}
+
+fn value_to_json(v: &Value) -> serde_json::Value {
+    match v {
+        Value::Null => serde_json::Value::Null,
+        Value::Int64(x) => serde_json::Value::Number((*x).into()),
+        Value::Float64(x) => Number::from_f64(*x)
+            .map(serde_json::Value::Number)
+            .unwrap_or(serde_json::Value::Null),
+        Value::Bool(x) => serde_json::Value::Bool(*x),
+        Value::Utf8(s) => serde_json::Value::String(s.clone()),
+        Value::Bytes(b) => serde_json::Value::String(BASE64.encode(b)),
+        Value::Timestamp(_) => serde_json::Value::String(v.to_string()),
+    }
+}
+
+/// Inverse of the ingestion-side `get_by_dot_path`: creates intermediate objects as needed.
+///
+/// A scalar field and a dotted field under the same prefix (`a` and `a.b`) conflict in either
+/// order instead of one silently replacing the other.
+fn insert_by_dot_path(
+    obj: &mut Map<String, serde_json::Value>,
+    path: &str,
+    value: serde_json::Value,
+) -> Result<(), String> {
+    let mut cur = obj;
+    let mut segs = path.split('.').peekable();
+    while let Some(seg) = segs.next() {
+        if segs.peek().is_none() {
+            if cur.get(seg).is_some_and(serde_json::Value::is_object) {
+                return Err(format!(
+                    "field '{path}' conflicts with nested fields under '{path}.'"
+                ));
+            }
+            cur.insert(seg.to_string(), value);
+            return Ok(());
+        }
+        let next = cur
+            .entry(seg.to_string())
+            .or_insert_with(|| serde_json::Value::Object(Map::new()));
+        cur = next
+            .as_object_mut()
+            .ok_or_else(|| format!("field '{path}' conflicts with non-object field '{seg}'"))?;
+    }
+    Ok(())
+}
+
+fn json_write_error(e: serde_json::Error) -> IngestionError {
+    if e.is_io() {
+        IngestionError::Io(e.into())
+    } else {
+        IngestionError::Engine {
+            message: "failed to write json".to_string(),
+            source: Box::new(e),
+        }
+    }
+}
@@ -0,0 +1,14 @@
+//! Export entrypoints: write an in-memory [`crate::types::DataSet`] back out to files.
+//!
+//! This is the inverse of [`crate::ingestion`]. Format-specific writers live under:
+//! - [`csv`]
+//! - [`json`]
+//! - [`parquet`]
+
+pub mod csv;
+pub mod json;
+pub mod parquet;
+
+pub use self::csv::{write_csv, write_csv_to_path};
+pub use self::json::{write_json, write_ndjson};
+pub use self::parquet::write_parquet;
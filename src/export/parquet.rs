@@ -0,0 +1,127 @@
+//! Parquet export implementation.
+//!
+//! Columns are written as `OPTIONAL` so `Value::Null` can be encoded via definition levels.
+
+use std::fs::File;
+use std::path::Path;
+use std::sync::Arc;
+
+use parquet::basic::{ConvertedType, Repetition, Type as PhysicalType};
+use parquet::column::writer::ColumnWriter;
+use parquet::data_type::ByteArray;
+use parquet::file::properties::WriterProperties;
+use parquet::file::writer::SerializedFileWriter;
+use parquet::schema::types::Type;
+
+use crate::error::{IngestionError, IngestionResult};
+use crate::types::{DataSet, DataType, Field, Schema, Value};
+
+/// Write a [`DataSet`] to a Parquet file at `path` (created or truncated).
+///
+/// Type mapping: `Int64` → `INT64`, `Float64` → `DOUBLE`, `Bool` → `BOOLEAN`,
+/// `Utf8` → `BINARY (UTF8)`, `Bytes` → `BINARY`, `Timestamp` → `INT64 (TIMESTAMP_MICROS)`. All rows are written as a single row group.
+pub fn write_parquet(ds: &DataSet, path: impl AsRef<Path>) -> IngestionResult<()> {
+    let message = Arc::new(parquet_message_type(&ds.schema)?);
+    let props = Arc::new(WriterProperties::builder().build());
+    let file = File::create(path)?;
+    let mut writer = SerializedFileWriter::new(file, message, props)?;
+
+    let mut rg = writer.next_row_group()?;
+    let mut col_idx: usize = 0;
+    while let Some(mut col) = rg.next_column()? {
+        let field = &ds.schema.fields[col_idx];
+        let mut def_levels: Vec<i16> = Vec::with_capacity(ds.row_count());
+
+        match col.untyped() {
+            ColumnWriter::Int64ColumnWriter(w) => {
+                let values = collect_column(ds, col_idx, field, &mut def_levels, |v| match v {
+                    Value::Int64(x) | Value::Timestamp(x) => Some(*x),
+                    _ => None,
+                })?;
+                w.write_batch(&values, Some(&def_levels), None)?;
+            }
+            ColumnWriter::DoubleColumnWriter(w) => {
+                let values = collect_column(ds, col_idx, field, &mut def_levels, |v| match v {
+                    Value::Float64(x) => Some(*x),
+                    _ => None,
+                })?;
+                w.write_batch(&values, Some(&def_levels), None)?;
+            }
+            ColumnWriter::BoolColumnWriter(w) => {
+                let values = collect_column(ds, col_idx, field, &mut def_levels, |v| match v {
+                    Value::Bool(x) => Some(*x),
+                    _ => None,
+                })?;
+                w.write_batch(&values, Some(&def_levels), None)?;
+            }
+            ColumnWriter::ByteArrayColumnWriter(w) => {
+                let values = collect_column(ds, col_idx, field, &mut def_levels, |v| match v {
+                    Value::Utf8(s) => Some(ByteArray::from(s.as_str())),
+                    Value::Bytes(b) => Some(ByteArray::from(b.clone())),
+                    _ => None,
+                })?;
+                w.write_batch(&values, Some(&def_levels), None)?;
+            }
+            _ => unreachable!("message type only declares INT64/DOUBLE/BOOLEAN/BINARY columns"),
+        }
+
+        col.close()?;
+        col_idx += 1;
+    }
+    rg.close()?;
+    writer.close()?;
+    Ok(())
+}
+
+fn parquet_message_type(schema: &Schema) -> IngestionResult<Type> {
+    let mut fields = Vec::with_capacity(schema.fields.len());
+    for field in &schema.fields {
+        let (physical, converted) = match field.data_type {
+            DataType::Int64 => (PhysicalType::INT64, ConvertedType::NONE),
+            DataType::Float64 => (PhysicalType::DOUBLE, ConvertedType::NONE),
+            DataType::Bool => (PhysicalType::BOOLEAN, ConvertedType::NONE),
+            DataType::Utf8 | DataType::Enum(_) => (PhysicalType::BYTE_ARRAY, ConvertedType::UTF8),
+            DataType::Bytes => (PhysicalType::BYTE_ARRAY, ConvertedType::NONE),
+            DataType::Timestamp => (PhysicalType::INT64, ConvertedType::TIMESTAMP_MICROS),
+        };
+        let t = Type::primitive_type_builder(&field.name, physical)
+            .with_repetition(Repetition::OPTIONAL)
+            .with_converted_type(converted)
+            .build()?;
+        fields.push(Arc::new(t));
+    }
+    Ok(Type::group_type_builder("schema")
+        .with_fields(fields)
+        .build()?)
+}
+
+/// Gather non-null values for one column, pushing a definition level per row (0 = null).
+fn collect_column<T>(
+    ds: &DataSet,
+    col_idx: usize,
+    field: &Field,
+    def_levels: &mut Vec<i16>,
+    extract: impl Fn(&Value) -> Option<T>,
+) -> IngestionResult<Vec<T>> {
+    let mut values = Vec::with_capacity(ds.row_count());
+    for (row_idx0, row) in ds.rows.iter().enumerate() {
+        match row.get(col_idx) {
+            Some(Value::Null) | None => def_levels.push(0),
+            Some(v) => match extract(v) {
+                Some(x) => {
+                    def_levels.push(1);
+                    values.push(x);
+                }
+                None => {
+                    return Err(IngestionError::ParseError {
+                        row: row_idx0 + 1,
+                        column: field.name.clone(),
+                        raw: format!("{v:?}"),
+                        message: format!("value does not match schema type {:?}", field.data_type),
+                    });
+                }
+            },
+        }
+    }
+    Ok(values)
+}
@@ -1,11 +1,14 @@
 use std::sync::Arc;
 
 use crate::error::IngestionResult;
-use crate::types::{DataSet, Schema};
+use crate::types::{DataSet, DataType, Schema, Value};
 
 use super::observability::IngestionObserver;
 use super::observability::IngestionSeverity;
-use super::unified::{ExcelSheetSelection, IngestionFormat, IngestionOptions, ingest_from_path};
+use super::unified::{
+    CsvEncoding, ExcelSheetSelection, FloatPolicy, IngestionFormat, IngestionOptions,
+    NumericLocale, ingest_from_path,
+};
 
 /// Builder for [`IngestionOptions`].
 ///
@@ -44,6 +47,12 @@ impl IngestionOptionsBuilder {
         self
     }
 
+    /// Ingest the single named Excel sheet (shorthand for
+    /// [`ExcelSheetSelection::Sheet`] via [`Self::excel_sheet_selection`]).
+    pub fn excel_sheet(self, name: impl Into<String>) -> Self {
+        self.excel_sheet_selection(ExcelSheetSelection::Sheet(name.into()))
+    }
+
     /// Configure an observer for success/failure/alerts.
     pub fn observer(mut self, observer: Arc<dyn IngestionObserver>) -> Self {
         self.options.observer = Some(observer);
@@ -56,6 +65,108 @@ impl IngestionOptionsBuilder {
         self
     }
 
+    /// Match CSV/Excel headers against schema field names ignoring ASCII case.
+    pub fn case_insensitive_headers(mut self, enabled: bool) -> Self {
+        self.options.case_insensitive_headers = enabled;
+        self
+    }
+
+    /// Control whitespace trimming of CSV Utf8 cells (default `true`).
+    pub fn trim_strings(mut self, enabled: bool) -> Self {
+        self.options.trim_strings = enabled;
+        self
+    }
+
+    /// Configure handling of `NaN` / infinities in Float64 columns (default `Allow`).
+    pub fn float_policy(mut self, policy: FloatPolicy) -> Self {
+        self.options.float_policy = policy;
+        self
+    }
+
+    /// Match Enum cells against their allowed values ignoring ASCII case (default `false`).
+    pub fn case_insensitive_enums(mut self, enabled: bool) -> Self {
+        self.options.case_insensitive_enums = enabled;
+        self
+    }
+
+    /// Report progress to the observer every `rows` converted rows.
+    pub fn progress_every(mut self, rows: usize) -> Self {
+        self.options.progress_every = Some(rows);
+        self
+    }
+
+    /// Set the decimal/thousands separators for CSV/Excel text numbers.
+    pub fn numeric_locale(mut self, locale: NumericLocale) -> Self {
+        self.options.numeric_locale = locale;
+        self
+    }
+
+    /// Set the encoding of CSV files read from a path (non-UTF-8 needs feature `encoding`).
+    pub fn encoding(mut self, encoding: CsvEncoding) -> Self {
+        self.options.encoding = encoding;
+        self
+    }
+
+    /// Reject CSV rows whose field count differs from the header.
+    pub fn strict_row_width(mut self, strict: bool) -> Self {
+        self.options.strict_row_width = strict;
+        self
+    }
+
+    /// Skip (`true`, the default) or keep CSV records whose fields are all empty.
+    pub fn skip_blank_lines(mut self, skip: bool) -> Self {
+        self.options.skip_blank_lines = skip;
+        self
+    }
+
+    /// Fail ingestion with `RowLimitExceeded` if the input has more than `max_rows` rows.
+    pub fn max_rows(mut self, max_rows: usize) -> Self {
+        self.options.max_rows = Some(max_rows);
+        self
+    }
+
+    /// Round Float64 cells to `places` decimal places at ingest time (lossy).
+    pub fn round_floats(mut self, places: u32) -> Self {
+        self.options.round_floats = Some(places);
+        self
+    }
+
+    /// Accept (`true`, the default) or reject with `EmptyInput` inputs that have no data rows.
+    pub fn allow_empty(mut self, allow: bool) -> Self {
+        self.options.allow_empty = allow;
+        self
+    }
+
+    /// Accept CSV/Excel header `source` for schema field `field` when no header matches the
+    /// field name exactly (see [`IngestionOptions::aliases`]).
+    pub fn alias(mut self, source: impl Into<String>, field: impl Into<String>) -> Self {
+        self.options.aliases.insert(source.into(), field.into());
+        self
+    }
+
+    /// Replace the accepted CSV/Excel bool spellings (empty lists keep the defaults).
+    pub fn bool_tokens<T, F>(mut self, true_tokens: T, false_tokens: F) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<String>,
+        F: IntoIterator,
+        F::Item: Into<String>,
+    {
+        self.options.bool_true_tokens = true_tokens.into_iter().map(Into::into).collect();
+        self.options.bool_false_tokens = false_tokens.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Parse CSV cells and Excel text cells of `data_type` with `parser` instead of the
+    /// built-in rules (see [`IngestionOptions::parsers`]).
+    pub fn parser<F>(mut self, data_type: DataType, parser: F) -> Self
+    where
+        F: Fn(&str) -> Result<Value, String> + Send + Sync + 'static,
+    {
+        self.options.parsers.register(data_type, parser);
+        self
+    }
+
     /// Build the configured [`IngestionOptions`].
     pub fn build(self) -> IngestionOptions {
         self.options
@@ -76,8 +187,10 @@ impl IngestionOptionsBuilder {
 mod tests {
     use super::IngestionOptionsBuilder;
     use crate::ingestion::{
-        ExcelSheetSelection, IngestionFormat, IngestionOptions, IngestionSeverity,
+        CsvEncoding, ExcelSheetSelection, FloatPolicy, IngestionFormat, IngestionOptions,
+        IngestionSeverity, NumericLocale,
     };
+    use crate::types::{DataType, Value};
 
     #[test]
     fn builder_defaults_match_ingestion_options_default() {
@@ -88,6 +201,25 @@ mod tests {
         assert_eq!(built.excel_sheet_selection, direct.excel_sheet_selection);
         assert_eq!(built.alert_at_or_above, direct.alert_at_or_above);
         assert_eq!(built.observer.is_some(), direct.observer.is_some());
+        assert_eq!(
+            built.case_insensitive_headers,
+            direct.case_insensitive_headers
+        );
+        assert_eq!(built.aliases, direct.aliases);
+        assert_eq!(built.trim_strings, direct.trim_strings);
+        assert_eq!(built.float_policy, direct.float_policy);
+        assert_eq!(built.case_insensitive_enums, direct.case_insensitive_enums);
+        assert_eq!(built.progress_every, direct.progress_every);
+        assert_eq!(built.bool_true_tokens, direct.bool_true_tokens);
+        assert_eq!(built.bool_false_tokens, direct.bool_false_tokens);
+        assert_eq!(built.parsers.is_empty(), direct.parsers.is_empty());
+        assert_eq!(built.numeric_locale, direct.numeric_locale);
+        assert_eq!(built.encoding, direct.encoding);
+        assert_eq!(built.strict_row_width, direct.strict_row_width);
+        assert_eq!(built.skip_blank_lines, direct.skip_blank_lines);
+        assert_eq!(built.max_rows, direct.max_rows);
+        assert_eq!(built.allow_empty, direct.allow_empty);
+        assert_eq!(built.round_floats, direct.round_floats);
     }
 
     #[test]
@@ -96,10 +228,61 @@ mod tests {
             .format(IngestionFormat::Csv)
             .excel_sheet_selection(ExcelSheetSelection::AllSheets)
             .alert_at_or_above(IngestionSeverity::Error)
+            .case_insensitive_headers(true)
+            .alias("Customer ID", "id")
+            .trim_strings(false)
+            .float_policy(FloatPolicy::NullifyNonFinite)
+            .case_insensitive_enums(true)
+            .progress_every(500)
+            .bool_tokens(["Y"], ["N"])
+            .parser(DataType::Bool, |s| Ok(Value::Bool(s == "on")))
+            .numeric_locale(NumericLocale {
+                decimal_sep: ',',
+                thousands_sep: Some('.'),
+            })
+            .encoding(CsvEncoding::Latin1)
+            .strict_row_width(true)
+            .skip_blank_lines(false)
+            .max_rows(1_000)
+            .allow_empty(false)
+            .round_floats(2)
             .build();
 
         assert_eq!(built.format, Some(IngestionFormat::Csv));
         assert_eq!(built.excel_sheet_selection, ExcelSheetSelection::AllSheets);
         assert_eq!(built.alert_at_or_above, IngestionSeverity::Error);
+        assert!(built.case_insensitive_headers);
+        assert_eq!(
+            built.aliases.get("Customer ID").map(String::as_str),
+            Some("id")
+        );
+        assert!(!built.trim_strings);
+        assert_eq!(built.float_policy, FloatPolicy::NullifyNonFinite);
+        assert!(built.case_insensitive_enums);
+        assert_eq!(built.progress_every, Some(500));
+        assert_eq!(built.bool_true_tokens, vec!["Y".to_string()]);
+        assert_eq!(built.bool_false_tokens, vec!["N".to_string()]);
+        let bool_parser = built.parsers.get(&DataType::Bool).unwrap();
+        assert_eq!(bool_parser("on"), Ok(Value::Bool(true)));
+        assert_eq!(built.numeric_locale.decimal_sep, ',');
+        assert_eq!(built.encoding, CsvEncoding::Latin1);
+        assert!(built.strict_row_width);
+        assert!(!built.skip_blank_lines);
+        assert_eq!(built.max_rows, Some(1_000));
+        assert!(!built.allow_empty);
+        assert_eq!(built.round_floats, Some(2));
+    }
+
+    #[test]
+    fn options_builder_entrypoint_and_excel_sheet_shorthand() {
+        let built = IngestionOptions::builder()
+            .format(IngestionFormat::Excel)
+            .excel_sheet("Q1")
+            .build();
+        assert_eq!(built.format, Some(IngestionFormat::Excel));
+        assert_eq!(
+            built.excel_sheet_selection,
+            ExcelSheetSelection::Sheet("Q1".to_string())
+        );
     }
 }
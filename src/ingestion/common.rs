@@ -0,0 +1,373 @@
+//! Helpers shared by the format-specific ingestion implementations.
+
+use std::borrow::Cow;
+use std::path::Path;
+
+use crate::error::{IngestionError, IngestionResult};
+use crate::types::{DataSet, DataType, Field, Value};
+
+use super::observability::{IngestionContext, IngestionObserver};
+use super::unified::{FloatPolicy, IngestionFormat, IngestionOptions};
+
+/// Returns `true` if a source header satisfies a schema field name under `options`.
+pub(crate) fn header_matches(header: &str, field_name: &str, options: &IngestionOptions) -> bool {
+    if options.case_insensitive_headers {
+        header.eq_ignore_ascii_case(field_name)
+    } else {
+        header == field_name
+    }
+}
+
+/// Position of the header for schema field `field_name`: a [`header_matches`] match on the name
+/// first, else a header that [`IngestionOptions::aliases`] maps to the field.
+pub(crate) fn header_position<'a>(
+    headers: impl Iterator<Item = &'a str> + Clone,
+    field_name: &str,
+    options: &IngestionOptions,
+) -> Option<usize> {
+    headers
+        .clone()
+        .position(|h| header_matches(h, field_name, options))
+        .or_else(|| {
+            headers.into_iter().position(|h| {
+                options.aliases.iter().any(|(source, field)| {
+                    field == field_name && header_matches(h, source, options)
+                })
+            })
+        })
+}
+
+/// Default accepted spellings when [`IngestionOptions::bool_true_tokens`] is empty.
+const DEFAULT_TRUE_TOKENS: [&str; 5] = ["true", "t", "1", "yes", "y"];
+/// Default accepted spellings when [`IngestionOptions::bool_false_tokens`] is empty.
+const DEFAULT_FALSE_TOKENS: [&str; 5] = ["false", "f", "0", "no", "n"];
+
+/// Parse a (trimmed) bool cell, matching ASCII-case-insensitively against `true_tokens` /
+/// `false_tokens`; an empty token list falls back to the defaults (`true/t/1/yes/y`,
+/// `false/f/0/no/n`). Returns the `ParseError` message on failure.
+pub(crate) fn parse_bool(
+    s: &str,
+    true_tokens: &[String],
+    false_tokens: &[String],
+) -> Result<bool, String> {
+    fn matches<T: AsRef<str>>(s: &str, tokens: &[T]) -> bool {
+        tokens.iter().any(|t| t.as_ref().eq_ignore_ascii_case(s))
+    }
+    let is_true = if true_tokens.is_empty() {
+        matches(s, &DEFAULT_TRUE_TOKENS)
+    } else {
+        matches(s, true_tokens)
+    };
+    if is_true {
+        return Ok(true);
+    }
+    let is_false = if false_tokens.is_empty() {
+        matches(s, &DEFAULT_FALSE_TOKENS)
+    } else {
+        matches(s, false_tokens)
+    };
+    if is_false {
+        return Ok(false);
+    }
+    if true_tokens.is_empty() && false_tokens.is_empty() {
+        Err("expected bool (true/false/1/0/yes/no)".to_string())
+    } else {
+        let list = |custom: &[String], default: &[&str]| {
+            if custom.is_empty() {
+                default.join("/")
+            } else {
+                custom.join("/")
+            }
+        };
+        Err(format!(
+            "expected bool (true: {}; false: {})",
+            list(true_tokens, &DEFAULT_TRUE_TOKENS),
+            list(false_tokens, &DEFAULT_FALSE_TOKENS)
+        ))
+    }
+}
+
+/// Rewrite a trimmed numeric cell into Rust's syntax under [`IngestionOptions::numeric_locale`]:
+/// thousands separators are dropped and the decimal separator becomes `'.'`.
+///
+/// With a non-`'.'` decimal separator, a stray `'.'` is mapped to `'_'` so that parsing fails
+/// instead of silently reading it as a decimal point.
+pub(crate) fn normalize_numeric<'a>(s: &'a str, options: &IngestionOptions) -> Cow<'a, str> {
+    let locale = options.numeric_locale;
+    if locale.decimal_sep == '.' && locale.thousands_sep.is_none() {
+        return Cow::Borrowed(s);
+    }
+    Cow::Owned(
+        s.chars()
+            .filter(|&c| Some(c) != locale.thousands_sep)
+            .map(|c| {
+                if c == locale.decimal_sep {
+                    '.'
+                } else if c == '.' {
+                    '_'
+                } else {
+                    c
+                }
+            })
+            .collect(),
+    )
+}
+
+/// Round `v` to [`IngestionOptions::round_floats`] decimal places (unchanged if unset, if `v`
+/// is not finite, or if the scaled value would overflow).
+pub(crate) fn round_float(v: f64, options: &IngestionOptions) -> f64 {
+    let Some(places) = options.round_floats else {
+        return v;
+    };
+    let scale = 10f64.powi(i32::try_from(places).unwrap_or(i32::MAX));
+    let rounded = (v * scale).round() / scale;
+    if rounded.is_finite() { rounded } else { v }
+}
+
+/// Apply [`round_float`] to the Float64 cells of an already-built [`DataSet`] (for
+/// engine-backed readers).
+pub(crate) fn apply_float_rounding(ds: &mut DataSet, options: &IngestionOptions) {
+    if options.round_floats.is_none() {
+        return;
+    }
+    for row in &mut ds.rows {
+        for cell in row.iter_mut() {
+            if let Value::Float64(v) = cell {
+                *v = round_float(*v, options);
+            }
+        }
+    }
+}
+
+/// Convert a parsed float into a [`Value`], applying [`IngestionOptions::float_policy`] and
+/// [`IngestionOptions::round_floats`].
+pub(crate) fn float_value(
+    v: f64,
+    row: usize,
+    column: &str,
+    raw: &str,
+    options: &IngestionOptions,
+) -> IngestionResult<Value> {
+    if v.is_finite() {
+        return Ok(Value::Float64(round_float(v, options)));
+    }
+    match options.float_policy {
+        FloatPolicy::Allow => Ok(Value::Float64(v)),
+        FloatPolicy::NullifyNonFinite => Ok(Value::Null),
+        FloatPolicy::RejectNonFinite => Err(IngestionError::ParseError {
+            row,
+            column: column.to_owned(),
+            raw: raw.to_owned(),
+            message: "non-finite float rejected by float_policy".to_string(),
+        }),
+    }
+}
+
+/// Apply [`float_value`] (float policy and rounding) to the Float64 columns of an
+/// already-built [`DataSet`] (for engine-backed readers). `first_row` is the user-facing number
+/// of row 0.
+pub(crate) fn apply_float_policy(
+    ds: &mut DataSet,
+    options: &IngestionOptions,
+    first_row: usize,
+) -> IngestionResult<()> {
+    if options.float_policy == FloatPolicy::Allow && options.round_floats.is_none() {
+        return Ok(());
+    }
+    let float_cols: Vec<usize> = ds
+        .schema
+        .fields
+        .iter()
+        .enumerate()
+        .filter(|(_, f)| f.data_type == DataType::Float64)
+        .map(|(i, _)| i)
+        .collect();
+    for (row_idx0, row) in ds.rows.iter_mut().enumerate() {
+        for &c in &float_cols {
+            if let Some(Value::Float64(v)) = row.get(c) {
+                let v = *v;
+                row[c] = float_value(
+                    v,
+                    row_idx0 + first_row,
+                    &ds.schema.fields[c].name,
+                    &v.to_string(),
+                    options,
+                )?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Run `field`'s ingest-time checks against a converted cell: the Utf8 validator and the Int64
+/// range, if set. Nulls always pass.
+pub(crate) fn validate_value(
+    field: &Field,
+    row: usize,
+    column: &str,
+    value: &Value,
+) -> IngestionResult<()> {
+    if let (Some(validator), Value::Utf8(s)) = (&field.validator, value) {
+        validator(s).map_err(|message| IngestionError::ParseError {
+            row,
+            column: column.to_owned(),
+            raw: s.clone(),
+            message,
+        })?;
+    }
+    match (field.int_range, value) {
+        (Some((min, max)), Value::Int64(v)) if !(min..=max).contains(v) => {
+            Err(IngestionError::ParseError {
+                row,
+                column: column.to_owned(),
+                raw: v.to_string(),
+                message: format!("value {v} is outside the allowed range {min}..={max}"),
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Run the [`IngestionOptions::parsers`] entry for `data_type` on `raw`, if one is registered.
+pub(crate) fn custom_parse(
+    row: usize,
+    column: &str,
+    data_type: &DataType,
+    raw: &str,
+    options: &IngestionOptions,
+) -> Option<IngestionResult<Value>> {
+    let parser = options.parsers.get(data_type)?;
+    Some(parser(raw).map_err(|message| IngestionError::ParseError {
+        row,
+        column: column.to_owned(),
+        raw: raw.to_owned(),
+        message,
+    }))
+}
+
+/// Check a converted cell against an [`DataType::Enum`] domain, returning the stored value.
+///
+/// Non-enum types and nulls pass through unchanged. Under
+/// [`IngestionOptions::case_insensitive_enums`] a match is stored with the schema's spelling.
+pub(crate) fn enum_value(
+    data_type: &DataType,
+    row: usize,
+    column: &str,
+    value: Value,
+    options: &IngestionOptions,
+) -> IngestionResult<Value> {
+    let (DataType::Enum(allowed), Value::Utf8(s)) = (data_type, &value) else {
+        return Ok(value);
+    };
+    let found = if options.case_insensitive_enums {
+        allowed.iter().find(|a| a.eq_ignore_ascii_case(s))
+    } else {
+        allowed.iter().find(|a| *a == s)
+    };
+    match found {
+        Some(canonical) => Ok(Value::Utf8(canonical.clone())),
+        None => Err(IngestionError::ParseError {
+            row,
+            column: column.to_owned(),
+            raw: s.clone(),
+            message: format!("value '{s}' is not one of the allowed values {allowed:?}"),
+        }),
+    }
+}
+
+/// Apply [`enum_value`] to the Enum columns of an already-built [`DataSet`] (for engine-backed
+/// readers). `first_row` is the user-facing number of row 0.
+pub(crate) fn apply_enum_domains(
+    ds: &mut DataSet,
+    options: &IngestionOptions,
+    first_row: usize,
+) -> IngestionResult<()> {
+    let enum_cols: Vec<usize> = ds
+        .schema
+        .fields
+        .iter()
+        .enumerate()
+        .filter(|(_, f)| matches!(f.data_type, DataType::Enum(_)))
+        .map(|(i, _)| i)
+        .collect();
+    if enum_cols.is_empty() {
+        return Ok(());
+    }
+    for (row_idx0, row) in ds.rows.iter_mut().enumerate() {
+        for &c in &enum_cols {
+            let field = &ds.schema.fields[c];
+            let value = std::mem::replace(&mut row[c], Value::Null);
+            row[c] = enum_value(
+                &field.data_type,
+                row_idx0 + first_row,
+                &field.name,
+                value,
+                options,
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Fail with [`IngestionError::RowLimitExceeded`] if `rows` is over
+/// [`IngestionOptions::max_rows`].
+pub(crate) fn check_row_limit(options: &IngestionOptions, rows: usize) -> IngestionResult<()> {
+    match options.max_rows {
+        Some(limit) if rows > limit => Err(IngestionError::RowLimitExceeded { limit }),
+        _ => Ok(()),
+    }
+}
+
+/// Fail with [`IngestionError::EmptyInput`] if `ds` has no rows and
+/// [`IngestionOptions::allow_empty`] is `false`; otherwise pass `ds` through.
+pub(crate) fn check_not_empty(options: &IngestionOptions, ds: DataSet) -> IngestionResult<DataSet> {
+    if !options.allow_empty && ds.is_empty() {
+        return Err(IngestionError::EmptyInput);
+    }
+    Ok(ds)
+}
+
+/// Periodic [`IngestionObserver::on_progress`] reporting for one ingestion call.
+pub(crate) struct Progress<'a> {
+    observer: &'a dyn IngestionObserver,
+    ctx: IngestionContext,
+    every: usize,
+}
+
+impl<'a> Progress<'a> {
+    /// `None` unless `options` has both an observer and a non-zero
+    /// [`IngestionOptions::progress_every`].
+    pub(crate) fn new(
+        options: &'a IngestionOptions,
+        path: &Path,
+        format: IngestionFormat,
+    ) -> Option<Self> {
+        let every = options.progress_every.filter(|&n| n > 0)?;
+        let observer = options.observer.as_deref()?;
+        Some(Self {
+            observer,
+            ctx: IngestionContext {
+                path: path.to_path_buf(),
+                format,
+                sheet: None,
+            },
+            every,
+        })
+    }
+
+    /// Record that `rows_so_far` rows have been produced; reports on every multiple of `every`.
+    pub(crate) fn rows_done(&self, rows_so_far: usize) {
+        if rows_so_far % self.every == 0 {
+            self.observer.on_progress(&self.ctx, rows_so_far);
+        }
+    }
+
+    /// Record a batch of `added` rows on top of `before`; reports every multiple of `every`
+    /// the batch crosses, as if the rows had been produced one at a time.
+    pub(crate) fn rows_added(&self, before: usize, added: usize) {
+        let first = (before / self.every + 1) * self.every;
+        for rows_so_far in (first..=before + added).step_by(self.every) {
+            self.observer.on_progress(&self.ctx, rows_so_far);
+        }
+    }
+}
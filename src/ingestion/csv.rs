@@ -2,12 +2,21 @@
 
 use std::path::Path;
 
+use base64::Engine as _;
+use base64::engine::general_purpose::STANDARD as BASE64;
+
 use crate::error::{IngestionError, IngestionResult};
-use crate::types::{DataSet, DataType, Schema, Value};
+use crate::types::{DataSet, DataType, Field, Schema, Value, parse_timestamp};
 
 use polars::prelude::*;
 
+use super::common::{
+    Progress, apply_enum_domains, apply_float_rounding, check_not_empty, check_row_limit,
+    custom_parse, enum_value, float_value, header_position, normalize_numeric, parse_bool,
+    validate_value,
+};
 use super::polars_bridge::{dataframe_to_dataset, polars_error_to_ingestion};
+use super::unified::{CsvEncoding, FloatPolicy, IngestionFormat, IngestionOptions, NumericLocale};
 
 /// Ingest a CSV file into an in-memory [`DataSet`].
 ///
@@ -17,8 +26,14 @@ use super::polars_bridge::{dataframe_to_dataset, polars_error_to_ingestion};
 /// - Headers must contain all schema fields (order can differ).
 /// - Each value is parsed according to the schema field type.
 pub fn ingest_csv_from_path(path: impl AsRef<Path>, schema: &Schema) -> IngestionResult<DataSet> {
-    let path = path.as_ref();
+    ingest_csv_from_path_with_options(path, schema, &IngestionOptions::default())
+}
 
+fn ingest_csv_with_polars(
+    path: &Path,
+    schema: &Schema,
+    options: &IngestionOptions,
+) -> IngestionResult<DataSet> {
     // Phase 1 delegation: use Polars' CSV reader for robust parsing of CSV mechanics
     // (quoting, escaping, delimiter handling, etc.), then convert into our `DataSet`.
     let df = LazyCsvReader::new(path.to_string_lossy().as_ref().into())
@@ -27,8 +42,315 @@ pub fn ingest_csv_from_path(path: impl AsRef<Path>, schema: &Schema) -> Ingestio
         .map_err(|e| polars_error_to_ingestion("failed to read csv with polars", e))?
         .collect()
         .map_err(|e| polars_error_to_ingestion("failed to collect csv with polars", e))?;
+    let df = if options.skip_blank_lines {
+        drop_blank_rows(df)?
+    } else {
+        df
+    };
+
+    let progress = Progress::new(options, path, IngestionFormat::Csv);
+    let mut ds = dataframe_to_dataset(&df, schema, "column", 2, progress.as_ref())?;
+    apply_float_rounding(&mut ds, options);
+    apply_enum_domains(&mut ds, options, 2)?;
+    Ok(ds)
+}
+
+/// Ingest a CSV file using the CSV-relevant settings of [`IngestionOptions`].
+///
+/// With default options this is identical to [`ingest_csv_from_path`]. When an option needs
+/// record-level control (e.g. `case_insensitive_headers`), the file is parsed with the same
+/// reader as [`ingest_csv_from_reader_with_options`] instead of Polars.
+pub fn ingest_csv_from_path_with_options(
+    path: impl AsRef<Path>,
+    schema: &Schema,
+    options: &IngestionOptions,
+) -> IngestionResult<DataSet> {
+    let path = path.as_ref();
+    let ds = if needs_record_parser(schema, options) {
+        let mut rdr = path_reader(path, options)?;
+        let progress = Progress::new(options, path, IngestionFormat::Csv);
+        ingest_csv_records(&mut rdr, schema, options, progress.as_ref())?
+    } else {
+        ingest_csv_with_polars(path, schema, options)?
+    };
+    check_not_empty(options, ds)
+}
+
+/// Ingest a CSV file by parsing chunks of records in parallel (rayon), keeping file row order.
+///
+/// The file is read into memory and split into roughly one chunk per rayon thread, always at a
+/// record boundary: a newline inside a quoted field never starts a new chunk. Each chunk is
+/// parsed with the record-level reader and the same rules as
+/// [`ingest_csv_from_path_with_options`], and error row numbers (and the positions of malformed
+/// record errors) refer to the whole file. The first error in file order is returned. `max_rows`
+/// is checked against the total row count. `progress_every` is reported as each chunk
+/// finishes, with running totals across chunks; chunks finish out of order, so one callback may
+/// cover several multiples and the totals need not arrive in increasing order.
+///
+/// Chunk splitting assumes the default `"` quote character.
+pub fn ingest_csv_parallel(
+    path: impl AsRef<Path>,
+    schema: &Schema,
+    options: &IngestionOptions,
+) -> IngestionResult<DataSet> {
+    use rayon::prelude::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let path = path.as_ref();
+    let bytes = match options.encoding {
+        CsvEncoding::Utf8 => std::fs::read(path)?,
+        encoding => read_transcoded(path, encoding)?,
+    };
+    options.numeric_locale.validate()?;
+    let mut rdr = csv::ReaderBuilder::new()
+        .has_headers(true)
+        .flexible(true)
+        .from_reader(bytes.as_slice());
+    let headers = rdr.headers()?.clone();
+    let col_idxs = column_indexes(&headers, schema, options)?;
+    let body_pos = rdr.position().clone();
+
+    // Row limits are checked on the merged total, not per chunk.
+    let chunk_options = IngestionOptions {
+        max_rows: None,
+        ..options.clone()
+    };
+    let progress = Progress::new(options, path, IngestionFormat::Csv);
+    let rows_done = AtomicUsize::new(0);
+    let parsed: Vec<IngestionResult<Vec<Vec<Value>>>> =
+        record_chunks(&bytes, &body_pos, rayon::current_num_threads())
+            .into_par_iter()
+            .map(|chunk| {
+                // Start each reader at the chunk's file position so malformed-record errors
+                // report whole-file positions.
+                let mut rdr = csv::ReaderBuilder::new()
+                    .has_headers(false)
+                    .flexible(true)
+                    .from_reader(std::io::Cursor::new(&bytes[..chunk.end]));
+                rdr.seek_raw(std::io::SeekFrom::Start(chunk.pos.byte()), chunk.pos)?;
+                let rows = fold_data_records(
+                    &mut rdr,
+                    &headers,
+                    &col_idxs,
+                    schema,
+                    &chunk_options,
+                    Vec::new(),
+                    |mut rows, row| {
+                        rows.push(row);
+                        rows
+                    },
+                )?;
+                if let Some(p) = &progress {
+                    p.rows_added(
+                        rows_done.fetch_add(rows.len(), Ordering::Relaxed),
+                        rows.len(),
+                    );
+                }
+                Ok(rows)
+            })
+            .collect();
+
+    let mut rows = Vec::new();
+    for chunk in parsed {
+        match chunk {
+            Ok(chunk_rows) => rows.extend(chunk_rows),
+            Err(IngestionError::ParseError {
+                row,
+                column,
+                raw,
+                message,
+            }) => {
+                return Err(IngestionError::ParseError {
+                    row: row + rows.len(),
+                    column,
+                    raw,
+                    message,
+                });
+            }
+            Err(e) => return Err(e),
+        }
+    }
+    check_row_limit(options, rows.len())?;
+    check_not_empty(options, DataSet::new(schema.clone(), rows))
+}
 
-    dataframe_to_dataset(&df, schema, "column", 2)
+/// Ingest the records appended to a CSV file since `byte_offset`, for tailing append-only logs.
+///
+/// Same as [`ingest_csv_since_with_options`] with default [`IngestionOptions`].
+pub fn ingest_csv_since(
+    path: impl AsRef<Path>,
+    schema: &Schema,
+    byte_offset: u64,
+) -> IngestionResult<(DataSet, u64)> {
+    ingest_csv_since_with_options(path, schema, byte_offset, &IngestionOptions::default())
+}
+
+/// Ingest the records appended to a CSV file since `byte_offset`, using the CSV-relevant
+/// settings of [`IngestionOptions`].
+///
+/// Returns the new rows and the offset to pass to the next call. Start with `byte_offset = 0`,
+/// which skips the header line; later calls pass the returned offset. The header is not read
+/// again, so schema fields map to CSV columns by position. Only whole records are consumed: a
+/// trailing record without its newline yet (still being written) is left for the next poll.
+///
+/// Assumes the file only grows (no truncation or rewrites of already-read bytes); an offset
+/// past the end of the file yields no rows and is returned unchanged. Error rows count the
+/// header as row 1 and the first record after `byte_offset` as row 2.
+pub fn ingest_csv_since_with_options(
+    path: impl AsRef<Path>,
+    schema: &Schema,
+    byte_offset: u64,
+    options: &IngestionOptions,
+) -> IngestionResult<(DataSet, u64)> {
+    use std::io::{Read, Seek, SeekFrom};
+
+    options.numeric_locale.validate()?;
+    let mut file = std::fs::File::open(path.as_ref())?;
+    let mut appended = Vec::new();
+    file.seek(SeekFrom::Start(byte_offset))?;
+    file.read_to_end(&mut appended)?;
+    let complete = complete_records_len(&appended);
+    appended.truncate(complete);
+    let body = decode_bytes(appended, options.encoding)?;
+
+    let headers: csv::StringRecord = schema.fields.iter().map(|f| f.name.as_str()).collect();
+    let col_idxs: Vec<usize> = (0..schema.fields.len()).collect();
+    let mut rdr = csv::ReaderBuilder::new()
+        .has_headers(byte_offset == 0)
+        .flexible(true)
+        .from_reader(body.as_slice());
+    let rows = fold_data_records(
+        &mut rdr,
+        &headers,
+        &col_idxs,
+        schema,
+        options,
+        Vec::new(),
+        |mut rows, row| {
+            rows.push(row);
+            rows
+        },
+    )?;
+    Ok((
+        DataSet::new(schema.clone(), rows),
+        byte_offset + complete as u64,
+    ))
+}
+
+/// Length of the prefix of `body` that ends with a record boundary (a newline outside double
+/// quotes); `0` if there is no complete record.
+fn complete_records_len(body: &[u8]) -> usize {
+    let mut in_quotes = false;
+    let mut end = 0;
+    for (i, &b) in body.iter().enumerate() {
+        match b {
+            b'"' => in_quotes = !in_quotes,
+            b'\n' if !in_quotes => end = i + 1,
+            _ => {}
+        }
+    }
+    end
+}
+
+/// A record-aligned slice of a CSV file for [`ingest_csv_parallel`]: the reader position at its
+/// first byte and its end offset.
+struct RecordChunk {
+    pos: csv::Position,
+    end: usize,
+}
+
+/// Split the data records of `bytes` (which start at `body_pos`, just past the header) into about
+/// `parts` chunks that each end on a record boundary (a newline outside double quotes), so every
+/// chunk holds whole records.
+///
+/// Each chunk carries the position the sequential reader would have at its start: lines count
+/// every `\n`, and records count non-empty lines (the CSV reader skips empty ones).
+fn record_chunks(bytes: &[u8], body_pos: &csv::Position, parts: usize) -> Vec<RecordChunk> {
+    let body_start = usize::try_from(body_pos.byte()).unwrap_or(bytes.len());
+    let target = (bytes.len().saturating_sub(body_start) / parts.max(1)).max(1);
+    let mut chunks = Vec::with_capacity(parts);
+    let mut pos = body_pos.clone();
+    let (mut line, mut record) = (pos.line(), pos.record());
+    let mut in_quotes = false;
+    let mut line_empty = true;
+    for (i, &b) in bytes.iter().enumerate().skip(body_start) {
+        match b {
+            b'"' => {
+                in_quotes = !in_quotes;
+                line_empty = false;
+            }
+            b'\n' => {
+                line += 1;
+                if in_quotes {
+                    continue;
+                }
+                if !line_empty {
+                    record += 1;
+                }
+                line_empty = true;
+                if i + 1 - pos.byte() as usize >= target {
+                    let mut next = csv::Position::new();
+                    next.set_byte(i as u64 + 1)
+                        .set_line(line)
+                        .set_record(record);
+                    chunks.push(RecordChunk {
+                        pos: std::mem::replace(&mut pos, next),
+                        end: i + 1,
+                    });
+                }
+            }
+            b'\r' => {}
+            _ => line_empty = false,
+        }
+    }
+    if (pos.byte() as usize) < bytes.len() {
+        chunks.push(RecordChunk {
+            pos,
+            end: bytes.len(),
+        });
+    }
+    chunks
+}
+
+/// Ingest a CSV file, skipping rows with bad cells instead of failing on the first one.
+///
+/// Returns the good rows plus every cell-level error ([`IngestionError::ParseError`], several
+/// per row if several cells are bad) in row order. Structural problems — an unreadable file, a
+/// missing schema column, or a malformed CSV record — still fail the whole call. Parsing uses
+/// the record-level reader with default [`IngestionOptions`].
+pub fn ingest_csv_collect_errors(
+    path: impl AsRef<Path>,
+    schema: &Schema,
+) -> IngestionResult<(DataSet, Vec<IngestionError>)> {
+    let options = IngestionOptions::default();
+    let mut rdr = path_reader(path.as_ref(), &options)?;
+    let headers = rdr.headers()?.clone();
+    let col_idxs = column_indexes(&headers, schema, &options)?;
+
+    let mut rows = Vec::new();
+    let mut errors = Vec::new();
+    let mut rows_seen = 0usize;
+    for result in rdr.records() {
+        let record = result?;
+        if is_blank_record(&record, &options) {
+            continue;
+        }
+        rows_seen += 1;
+        let user_row = rows_seen + 1;
+        let before = errors.len();
+        let mut row: Vec<Value> = Vec::with_capacity(schema.fields.len());
+        for (field, &csv_idx) in schema.fields.iter().zip(col_idxs.iter()) {
+            let raw = record.get(csv_idx).unwrap_or("");
+            match parse_cell(user_row, field, raw, &options) {
+                Ok(v) => row.push(v),
+                Err(e) => errors.push(e),
+            }
+        }
+        if errors.len() == before {
+            rows.push(row);
+        }
+    }
+    Ok((DataSet::new(schema.clone(), rows), errors))
 }
 
 /// Ingest CSV data from an existing CSV reader.
@@ -36,12 +358,163 @@ pub fn ingest_csv_from_reader<R: std::io::Read>(
     rdr: &mut csv::Reader<R>,
     schema: &Schema,
 ) -> IngestionResult<DataSet> {
+    ingest_csv_from_reader_with_options(rdr, schema, &IngestionOptions::default())
+}
+
+/// Ingest CSV data from an existing CSV reader, honoring the CSV-relevant settings of
+/// [`IngestionOptions`] (e.g. `case_insensitive_headers`).
+///
+/// Progress callbacks (see [`IngestionOptions::progress_every`]) carry an empty path.
+pub fn ingest_csv_from_reader_with_options<R: std::io::Read>(
+    rdr: &mut csv::Reader<R>,
+    schema: &Schema,
+    options: &IngestionOptions,
+) -> IngestionResult<DataSet> {
+    let progress = Progress::new(options, Path::new(""), IngestionFormat::Csv);
+    let ds = ingest_csv_records(rdr, schema, options, progress.as_ref())?;
+    check_not_empty(options, ds)
+}
+
+fn ingest_csv_records<R: std::io::Read>(
+    rdr: &mut csv::Reader<R>,
+    schema: &Schema,
+    options: &IngestionOptions,
+    progress: Option<&Progress>,
+) -> IngestionResult<DataSet> {
+    let rows = fold_csv_records(rdr, schema, options, Vec::new(), |mut rows, row| {
+        rows.push(row);
+        if let Some(p) = progress {
+            p.rows_done(rows.len());
+        }
+        rows
+    })?;
+    Ok(DataSet::new(schema.clone(), rows))
+}
+
+/// Fold every row of a CSV file through the record parser, one row at a time.
+pub(crate) fn fold_csv_path<A>(
+    path: &Path,
+    schema: &Schema,
+    options: &IngestionOptions,
+    init: A,
+    f: impl FnMut(A, Vec<Value>) -> A,
+) -> IngestionResult<A> {
+    let mut rdr = path_reader(path, options)?;
+    fold_csv_records(&mut rdr, schema, options, init, f)
+}
+
+fn fold_csv_records<R: std::io::Read, A>(
+    rdr: &mut csv::Reader<R>,
+    schema: &Schema,
+    options: &IngestionOptions,
+    init: A,
+    f: impl FnMut(A, Vec<Value>) -> A,
+) -> IngestionResult<A> {
+    options.numeric_locale.validate()?;
     let headers = rdr.headers()?.clone();
+    let col_idxs = column_indexes(&headers, schema, options)?;
+    fold_data_records(rdr, &headers, &col_idxs, schema, options, init, f)
+}
 
-    // Map schema fields -> CSV column indexes (allows re-ordered CSV columns).
+/// Fold the data records of `rdr` (positioned past the header) with precomputed column indexes,
+/// one row at a time. `headers` is only used for the `strict_row_width` check.
+///
+/// Error rows are 1-based file rows counting the header as row 1; blank records are skipped
+/// without being counted.
+fn fold_data_records<R: std::io::Read, A>(
+    rdr: &mut csv::Reader<R>,
+    headers: &csv::StringRecord,
+    col_idxs: &[usize],
+    schema: &Schema,
+    options: &IngestionOptions,
+    init: A,
+    mut f: impl FnMut(A, Vec<Value>) -> A,
+) -> IngestionResult<A> {
+    let mut acc = init;
+    let mut rows_seen = 0usize;
+    for result in rdr.records() {
+        let record = result?;
+        if is_blank_record(&record, options) {
+            continue;
+        }
+        rows_seen += 1;
+        // Report 1-based row number for users; +1 again because header is row 1.
+        let user_row = rows_seen + 1;
+        check_row_limit(options, rows_seen)?;
+        check_row_width(user_row, headers, &record, options)?;
+
+        let mut row: Vec<Value> = Vec::with_capacity(schema.fields.len());
+        for (field, &csv_idx) in schema.fields.iter().zip(col_idxs.iter()) {
+            let raw = record.get(csv_idx).unwrap_or("");
+            row.push(parse_cell(user_row, field, raw, options)?);
+        }
+        acc = f(acc, row);
+    }
+
+    Ok(acc)
+}
+
+/// Type-check the first `sample_rows` data rows of a CSV file against `schema` with the record
+/// parser, collecting every cell error instead of stopping at the first. Returns
+/// `(checked_rows, issues)`.
+///
+/// Missing columns (or an invalid `numeric_locale`) are reported as a single issue and no rows
+/// are checked; a malformed record ends the scan.
+pub(crate) fn validate_csv_sample(
+    path: &Path,
+    schema: &Schema,
+    options: &IngestionOptions,
+    sample_rows: usize,
+) -> IngestionResult<(usize, Vec<IngestionError>)> {
+    let mut rdr = path_reader(path, options)?;
+    let headers = rdr.headers()?.clone();
+    let col_idxs = match options
+        .numeric_locale
+        .validate()
+        .and_then(|()| column_indexes(&headers, schema, options))
+    {
+        Ok(idxs) => idxs,
+        Err(e) => return Ok((0, vec![e])),
+    };
+
+    let mut issues = Vec::new();
+    let mut checked = 0usize;
+    let records = rdr
+        .records()
+        .filter(|r| !matches!(r, Ok(record) if is_blank_record(record, options)));
+    for result in records.take(sample_rows) {
+        let record = match result {
+            Ok(r) => r,
+            Err(e) => {
+                issues.push(e.into());
+                break;
+            }
+        };
+        checked += 1;
+        let user_row = checked + 1;
+        if let Err(e) = check_row_width(user_row, &headers, &record, options) {
+            issues.push(e);
+            continue;
+        }
+        for (field, &csv_idx) in schema.fields.iter().zip(col_idxs.iter()) {
+            let raw = record.get(csv_idx).unwrap_or("");
+            if let Err(e) = parse_cell(user_row, field, raw, options) {
+                issues.push(e);
+            }
+        }
+    }
+    Ok((checked, issues))
+}
+
+/// Map schema fields -> CSV column indexes (allows re-ordered CSV columns).
+fn column_indexes(
+    headers: &csv::StringRecord,
+    schema: &Schema,
+    options: &IngestionOptions,
+) -> IngestionResult<Vec<usize>> {
     let mut col_idxs = Vec::with_capacity(schema.fields.len());
     for field in &schema.fields {
-        match headers.iter().position(|h| h == field.name) {
+        match header_position(headers.iter(), &field.name, options) {
             Some(idx) => col_idxs.push(idx),
             None => {
                 return Err(IngestionError::SchemaMismatch {
@@ -54,81 +527,290 @@ pub fn ingest_csv_from_reader<R: std::io::Read>(
             }
         }
     }
+    Ok(col_idxs)
+}
 
-    let mut rows: Vec<Vec<Value>> = Vec::new();
-    for (row_idx0, result) in rdr.records().enumerate() {
-        // Report 1-based row number for users; +1 again because header is row 1.
-        let user_row = row_idx0 + 2;
+/// With `strict_row_width`, reject a record whose field count differs from the header.
+fn check_row_width(
+    user_row: usize,
+    headers: &csv::StringRecord,
+    record: &csv::StringRecord,
+    options: &IngestionOptions,
+) -> IngestionResult<()> {
+    if !options.strict_row_width || record.len() == headers.len() {
+        return Ok(());
+    }
+    let (column, kind) = if record.len() < headers.len() {
+        (headers.get(record.len()).unwrap_or_default(), "missing")
+    } else {
+        ("", "extra")
+    };
+    Err(IngestionError::ParseError {
+        row: user_row,
+        column: column.to_string(),
+        raw: String::new(),
+        message: format!(
+            "row has {} fields but the header has {} ({kind} fields)",
+            record.len(),
+            headers.len()
+        ),
+    })
+}
+
+/// Whether `record` should be skipped under [`IngestionOptions::skip_blank_lines`].
+fn is_blank_record(record: &csv::StringRecord, options: &IngestionOptions) -> bool {
+    options.skip_blank_lines && record.iter().all(str::is_empty)
+}
+
+/// Drop rows where every column is null (Polars reads empty CSV fields as null), matching
+/// [`IngestionOptions::skip_blank_lines`] on the record parser.
+fn drop_blank_rows(df: DataFrame) -> IngestionResult<DataFrame> {
+    let mut keep = BooleanChunked::full("keep".into(), df.width() == 0, df.height());
+    for column in df.get_columns() {
+        keep = keep | column.is_not_null();
+    }
+    if keep.all() {
+        return Ok(df);
+    }
+    df.filter(&keep)
+        .map_err(|e| polars_error_to_ingestion("failed to drop blank csv rows", e))
+}
+
+/// Parse one raw cell for `field`, applying its pre-transform, enum domains, and field
+/// validation.
+fn parse_cell(
+    user_row: usize,
+    field: &Field,
+    raw: &str,
+    options: &IngestionOptions,
+) -> IngestionResult<Value> {
+    let transformed = field.pre_transform.as_ref().map(|t| t(raw));
+    let raw = transformed.as_deref().unwrap_or(raw);
+    let value = parse_typed_value(user_row, &field.name, &field.data_type, raw, options)?;
+    let value = enum_value(&field.data_type, user_row, &field.name, value, options)?;
+    validate_value(field, user_row, &field.name, &value)?;
+    Ok(value)
+}
+
+/// Open `path` for the record parser, transcoding to UTF-8 first unless `options.encoding` is
+/// already [`CsvEncoding::Utf8`].
+fn path_reader(
+    path: &Path,
+    options: &IngestionOptions,
+) -> IngestionResult<csv::Reader<Box<dyn std::io::Read>>> {
+    let input: Box<dyn std::io::Read> = match options.encoding {
+        CsvEncoding::Utf8 => Box::new(std::fs::File::open(path)?),
+        encoding => Box::new(std::io::Cursor::new(read_transcoded(path, encoding)?)),
+    };
+    Ok(csv::ReaderBuilder::new()
+        .has_headers(true)
+        .flexible(true)
+        .from_reader(input))
+}
+
+/// Read the whole file and decode it from `encoding` into UTF-8 bytes.
+fn read_transcoded(path: &Path, encoding: CsvEncoding) -> IngestionResult<Vec<u8>> {
+    decode_bytes(std::fs::read(path)?, encoding)
+}
+
+/// Decode `bytes` from `encoding` into UTF-8 bytes.
+#[cfg(feature = "encoding")]
+fn decode_bytes(bytes: Vec<u8>, encoding: CsvEncoding) -> IngestionResult<Vec<u8>> {
+    let text = match encoding {
+        CsvEncoding::Utf8 => return Ok(bytes),
+        CsvEncoding::Latin1 => encoding_rs::mem::decode_latin1(&bytes),
+        CsvEncoding::Windows1252 => {
+            encoding_rs::WINDOWS_1252
+                .decode_without_bom_handling(&bytes)
+                .0
+        }
+    };
+    Ok(text.into_owned().into_bytes())
+}
+
+#[cfg(not(feature = "encoding"))]
+fn decode_bytes(bytes: Vec<u8>, encoding: CsvEncoding) -> IngestionResult<Vec<u8>> {
+    if encoding == CsvEncoding::Utf8 {
+        return Ok(bytes);
+    }
+    Err(IngestionError::SchemaMismatch {
+        message: format!("csv encoding {encoding:?} requires Cargo feature 'encoding'"),
+    })
+}
+
+/// Whether `schema` or `options` require the record-level `csv` reader rather than the Polars
+/// fast path.
+fn needs_record_parser(schema: &Schema, options: &IngestionOptions) -> bool {
+    schema.fields.iter().any(|f| {
+        f.pre_transform.is_some() || matches!(f.data_type, DataType::Bytes | DataType::Timestamp)
+    }) || options.case_insensitive_headers
+        || !options.aliases.is_empty()
+        || !options.trim_strings
+        || options.float_policy != FloatPolicy::Allow
+        || !options.bool_true_tokens.is_empty()
+        || !options.bool_false_tokens.is_empty()
+        || !options.parsers.is_empty()
+        || options.numeric_locale != NumericLocale::default()
+        || options.encoding != CsvEncoding::Utf8
+        || options.strict_row_width
+        || options.max_rows.is_some()
+}
+
+/// Infer a [`Schema`] from a CSV file's header and up to `sample_rows` data rows.
+///
+/// Each column is typed by trying, in order, Int64 → Float64 → Bool against its non-empty
+/// sampled cells, falling back to Utf8 (also used for columns with no non-empty samples).
+///
+/// Inference is best-effort: values beyond the sample are not inspected, so callers can adjust
+/// the returned schema before ingesting.
+pub fn infer_schema(path: impl AsRef<Path>, sample_rows: usize) -> IngestionResult<Schema> {
+    let mut rdr = csv::ReaderBuilder::new()
+        .has_headers(true)
+        .from_path(path.as_ref())?;
+    let headers = rdr.headers()?.clone();
+
+    let mut states = vec![CsvInferState::default(); headers.len()];
+    for result in rdr.records().take(sample_rows) {
         let record = result?;
+        for (state, raw) in states.iter_mut().zip(record.iter()) {
+            state.observe(raw);
+        }
+    }
 
-        let mut row: Vec<Value> = Vec::with_capacity(schema.fields.len());
-        for (field, &csv_idx) in schema.fields.iter().zip(col_idxs.iter()) {
-            let raw = record.get(csv_idx).unwrap_or("");
-            row.push(parse_typed_value(
-                user_row,
-                &field.name,
-                &field.data_type,
-                raw,
-            )?);
+    let fields = headers
+        .iter()
+        .zip(states)
+        .map(|(name, st)| crate::types::Field::new(name, st.finish_type()))
+        .collect();
+    Ok(Schema::new(fields))
+}
+
+/// Per-column candidate types; a candidate is dropped once a sampled cell fails to parse as it.
+#[derive(Debug, Clone)]
+struct CsvInferState {
+    seen: bool,
+    int: bool,
+    float: bool,
+    bool: bool,
+}
+
+impl Default for CsvInferState {
+    fn default() -> Self {
+        Self {
+            seen: false,
+            int: true,
+            float: true,
+            bool: true,
         }
-        rows.push(row);
     }
+}
 
-    Ok(DataSet::new(schema.clone(), rows))
+impl CsvInferState {
+    fn observe(&mut self, raw: &str) {
+        let trimmed = raw.trim();
+        if trimmed.is_empty() {
+            return;
+        }
+        self.seen = true;
+        self.int &= trimmed.parse::<i64>().is_ok();
+        self.float &= trimmed.parse::<f64>().is_ok();
+        self.bool &= parse_bool(trimmed, &[], &[]).is_ok();
+    }
+
+    fn finish_type(self) -> DataType {
+        if !self.seen {
+            DataType::Utf8
+        } else if self.int {
+            DataType::Int64
+        } else if self.float {
+            DataType::Float64
+        } else if self.bool {
+            DataType::Bool
+        } else {
+            DataType::Utf8
+        }
+    }
 }
 
-fn parse_typed_value(
+/// Parse one raw CSV cell into `data_type` (also used by [`crate::processing::cast_column`]).
+pub(crate) fn parse_typed_value(
     row: usize,
     column: &str,
     data_type: &DataType,
     raw: &str,
+    options: &IngestionOptions,
 ) -> IngestionResult<Value> {
+    // Numeric/bool/base64 parsing always trims; `trim_strings` only affects Utf8 cells.
     let trimmed = raw.trim();
-    if trimmed.is_empty() {
+    let keep_whitespace =
+        matches!(data_type, DataType::Utf8 | DataType::Enum(_)) && !options.trim_strings;
+    let blank = if keep_whitespace {
+        raw.is_empty()
+    } else {
+        trimmed.is_empty()
+    };
+    if blank {
         return Ok(Value::Null);
     }
+    if let Some(parsed) = custom_parse(row, column, data_type, raw, options) {
+        return parsed;
+    }
+    if keep_whitespace {
+        return Ok(Value::Utf8(raw.to_owned()));
+    }
 
     match data_type {
-        DataType::Utf8 => Ok(Value::Utf8(trimmed.to_owned())),
-        DataType::Int64 => {
-            trimmed
-                .parse::<i64>()
-                .map(Value::Int64)
+        DataType::Utf8 | DataType::Enum(_) => Ok(Value::Utf8(trimmed.to_owned())),
+        DataType::Bytes => {
+            BASE64
+                .decode(trimmed)
+                .map(Value::Bytes)
                 .map_err(|e| IngestionError::ParseError {
                     row,
                     column: column.to_owned(),
                     raw: raw.to_owned(),
-                    message: e.to_string(),
+                    message: format!("invalid base64: {e}"),
                 })
         }
+        DataType::Int64 => normalize_numeric(trimmed, options)
+            .parse::<i64>()
+            .map(Value::Int64)
+            .map_err(|e| IngestionError::ParseError {
+                row,
+                column: column.to_owned(),
+                raw: raw.to_owned(),
+                message: e.to_string(),
+            }),
         DataType::Float64 => {
-            trimmed
+            let v = normalize_numeric(trimmed, options)
                 .parse::<f64>()
-                .map(Value::Float64)
                 .map_err(|e| IngestionError::ParseError {
                     row,
                     column: column.to_owned(),
                     raw: raw.to_owned(),
                     message: e.to_string(),
-                })
+                })?;
+            float_value(v, row, column, raw, options)
         }
-        DataType::Bool => {
-            parse_bool(trimmed)
-                .map(Value::Bool)
-                .map_err(|message| IngestionError::ParseError {
-                    row,
-                    column: column.to_owned(),
-                    raw: raw.to_owned(),
-                    message,
-                })
-        }
-    }
-}
-
-fn parse_bool(s: &str) -> Result<bool, String> {
-    match s.to_ascii_lowercase().as_str() {
-        "true" | "t" | "1" | "yes" | "y" => Ok(true),
-        "false" | "f" | "0" | "no" | "n" => Ok(false),
-        _ => Err("expected bool (true/false/1/0/yes/no)".to_string()),
+        DataType::Bool => parse_bool(
+            trimmed,
+            &options.bool_true_tokens,
+            &options.bool_false_tokens,
+        )
+        .map(Value::Bool)
+        .map_err(|message| IngestionError::ParseError {
+            row,
+            column: column.to_owned(),
+            raw: raw.to_owned(),
+            message,
+        }),
+        DataType::Timestamp => parse_timestamp(trimmed)
+            .map(Value::Timestamp)
+            .map_err(|message| IngestionError::ParseError {
+                row,
+                column: column.to_owned(),
+                raw: raw.to_owned(),
+                message,
+            }),
     }
 }
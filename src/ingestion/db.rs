@@ -10,8 +10,10 @@
 use std::convert::TryFrom;
 
 use arrow::array::{
-    Array, BooleanArray, Float32Array, Float64Array, Int8Array, Int16Array, Int32Array, Int64Array,
-    StringArray, UInt8Array, UInt16Array, UInt32Array, UInt64Array,
+    Array, BinaryArray, BooleanArray, Float32Array, Float64Array, Int8Array, Int16Array,
+    Int32Array, Int64Array, StringArray, TimestampMicrosecondArray, TimestampMillisecondArray,
+    TimestampNanosecondArray, TimestampSecondArray, UInt8Array, UInt16Array, UInt32Array,
+    UInt64Array,
 };
 use arrow::datatypes::DataType as ArrowDataType;
 use arrow::record_batch::RecordBatch;
@@ -86,6 +88,8 @@ fn infer_schema_from_record_batches_lossy(batches: &[RecordBatch]) -> IngestionR
             | ArrowDataType::UInt32
             | ArrowDataType::UInt64 => DataType::Int64,
             ArrowDataType::Utf8 | ArrowDataType::LargeUtf8 => DataType::Utf8,
+            ArrowDataType::Binary => DataType::Bytes,
+            ArrowDataType::Timestamp(_, _) => DataType::Timestamp,
             // Phase 1: map everything else to Utf8 (stringify at conversion time when possible).
             _ => DataType::Utf8,
         };
@@ -229,7 +233,7 @@ fn arrow_value_to_value(
             }
         }
 
-        DataType::Utf8 => {
+        DataType::Utf8 | DataType::Enum(_) => {
             if let Some(a) = arr.as_any().downcast_ref::<StringArray>() {
                 Ok(Value::Utf8(a.value(row).to_string()))
             } else {
@@ -237,6 +241,31 @@ fn arrow_value_to_value(
                 Ok(Value::Utf8(format!("{arr:?}")))
             }
         }
+
+        DataType::Bytes => arr
+            .as_any()
+            .downcast_ref::<BinaryArray>()
+            .map(|a| Value::Bytes(a.value(row).to_vec()))
+            .ok_or_else(|| IngestionError::SchemaMismatch {
+                message: format!("column '{name}' is not binary"),
+            }),
+
+        // Arrow timestamps are UTC-based in every unit; normalize to microseconds.
+        DataType::Timestamp => {
+            if let Some(a) = arr.as_any().downcast_ref::<TimestampMicrosecondArray>() {
+                Ok(Value::Timestamp(a.value(row)))
+            } else if let Some(a) = arr.as_any().downcast_ref::<TimestampMillisecondArray>() {
+                Ok(Value::Timestamp(a.value(row).saturating_mul(1_000)))
+            } else if let Some(a) = arr.as_any().downcast_ref::<TimestampNanosecondArray>() {
+                Ok(Value::Timestamp(a.value(row).div_euclid(1_000)))
+            } else if let Some(a) = arr.as_any().downcast_ref::<TimestampSecondArray>() {
+                Ok(Value::Timestamp(a.value(row).saturating_mul(1_000_000)))
+            } else {
+                Err(IngestionError::SchemaMismatch {
+                    message: format!("column '{name}' is not a timestamp Arrow array"),
+                })
+            }
+        }
     }
 }
 
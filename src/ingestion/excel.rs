@@ -3,7 +3,13 @@ use std::path::Path;
 use calamine::{Data, Reader, open_workbook_auto};
 
 use crate::error::{IngestionError, IngestionResult};
-use crate::types::{DataSet, DataType, Schema, Value};
+use crate::types::{DataSet, DataType, Schema, Value, days_from_civil, parse_timestamp};
+
+use super::common::{
+    Progress, check_not_empty, check_row_limit, custom_parse, enum_value, float_value,
+    header_position, normalize_numeric, parse_bool, validate_value,
+};
+use super::unified::{IngestionFormat, IngestionOptions};
 
 /// Ingest an Excel document (`.xlsx`, `.xls`, `.ods`, etc.) into an in-memory `DataSet`.
 ///
@@ -16,9 +22,20 @@ pub fn ingest_excel_from_path(
     path: impl AsRef<Path>,
     sheet_name: Option<&str>,
     schema: &Schema,
+) -> IngestionResult<DataSet> {
+    ingest_excel_from_path_with_options(path, sheet_name, schema, &IngestionOptions::default())
+}
+
+/// Like [`ingest_excel_from_path`], honoring the Excel-relevant settings of [`IngestionOptions`]
+/// (e.g. `case_insensitive_headers`).
+pub fn ingest_excel_from_path_with_options(
+    path: impl AsRef<Path>,
+    sheet_name: Option<&str>,
+    schema: &Schema,
+    options: &IngestionOptions,
 ) -> IngestionResult<DataSet> {
     let sheets: Option<Vec<&str>> = sheet_name.map(|s| vec![s]);
-    ingest_excel_workbook_from_path(path, sheets.as_deref(), schema)
+    ingest_excel_workbook_from_path_with_options(path, sheets.as_deref(), schema, options)
 }
 
 /// Ingest multiple sheets from an Excel workbook and concatenate all rows into one `DataSet`.
@@ -32,6 +49,35 @@ pub fn ingest_excel_workbook_from_path(
     sheet_names: Option<&[&str]>,
     schema: &Schema,
 ) -> IngestionResult<DataSet> {
+    ingest_excel_workbook_from_path_with_options(
+        path,
+        sheet_names,
+        schema,
+        &IngestionOptions::default(),
+    )
+}
+
+/// Like [`ingest_excel_workbook_from_path`], honoring the Excel-relevant settings of
+/// [`IngestionOptions`].
+pub fn ingest_excel_workbook_from_path_with_options(
+    path: impl AsRef<Path>,
+    sheet_names: Option<&[&str]>,
+    schema: &Schema,
+    options: &IngestionOptions,
+) -> IngestionResult<DataSet> {
+    ingest_excel_workbook_tracking_sheet(path.as_ref(), sheet_names, schema, options, &mut None)
+}
+
+/// Workbook ingestion that leaves the name of the sheet being read in `current_sheet`, so
+/// callers can report which sheet an error came from.
+pub(crate) fn ingest_excel_workbook_tracking_sheet(
+    path: &Path,
+    sheet_names: Option<&[&str]>,
+    schema: &Schema,
+    options: &IngestionOptions,
+    current_sheet: &mut Option<String>,
+) -> IngestionResult<DataSet> {
+    options.numeric_locale.validate()?;
     let mut workbook = open_workbook_auto(path)?;
 
     let sheets: Vec<String> = match sheet_names {
@@ -44,14 +90,23 @@ pub fn ingest_excel_workbook_from_path(
         });
     }
 
+    let progress = Progress::new(options, path, IngestionFormat::Excel);
     let mut all_rows: Vec<Vec<Value>> = Vec::new();
     for sheet in sheets {
+        *current_sheet = Some(sheet.clone());
         let range = workbook.worksheet_range(&sheet)?;
-        let mut sheet_rows = ingest_sheet_range(&sheet, &range, schema)?;
+        let mut sheet_rows = ingest_sheet_range(
+            &sheet,
+            &range,
+            schema,
+            options,
+            all_rows.len(),
+            progress.as_ref(),
+        )?;
         all_rows.append(&mut sheet_rows);
     }
 
-    Ok(DataSet::new(schema.clone(), all_rows))
+    check_not_empty(options, DataSet::new(schema.clone(), all_rows))
 }
 
 /// Infer a schema from an Excel workbook by reading the header row and scanning cell types.
@@ -97,9 +152,13 @@ fn ingest_sheet_range(
     sheet: &str,
     range: &calamine::Range<Data>,
     schema: &Schema,
+    options: &IngestionOptions,
+    // Rows produced by earlier sheets (for progress and `max_rows`).
+    rows_before: usize,
+    progress: Option<&Progress>,
 ) -> IngestionResult<Vec<Vec<Value>>> {
-    let (header_row_idx, col_idxs, header_cells) =
-        build_header_projection(range, schema).map_err(|e| wrap_schema_err_with_sheet(sheet, e))?;
+    let (header_row_idx, col_idxs, header_cells) = build_header_projection(range, schema, options)
+        .map_err(|e| wrap_schema_err_with_sheet(sheet, e))?;
 
     let mut rows: Vec<Vec<Value>> = Vec::new();
     for (idx0, row) in range.rows().enumerate() {
@@ -109,14 +168,26 @@ fn ingest_sheet_range(
 
         // Report 1-based row number (Excel-like).
         let user_row = idx0 + 1;
+        check_row_limit(options, rows_before + rows.len() + 1)?;
 
         let mut out_row: Vec<Value> = Vec::with_capacity(schema.fields.len());
         for (field, &col_idx) in schema.fields.iter().zip(col_idxs.iter()) {
             let cell = row.get(col_idx).unwrap_or(&Data::Empty);
+            let transformed = match (&field.pre_transform, cell) {
+                (Some(t), Data::String(s)) => Some(Data::String(t(s))),
+                _ => None,
+            };
+            let cell = transformed.as_ref().unwrap_or(cell);
             let col_label = format!("{sheet}:{name}", name = field.name);
-            out_row.push(convert_cell(user_row, &col_label, &field.data_type, cell)?);
+            let value = convert_cell(user_row, &col_label, &field.data_type, cell, options)?;
+            let value = enum_value(&field.data_type, user_row, &col_label, value, options)?;
+            validate_value(field, user_row, &col_label, &value)?;
+            out_row.push(value);
         }
         rows.push(out_row);
+        if let Some(p) = progress {
+            p.rows_done(rows_before + rows.len());
+        }
     }
 
     // Use header_cells only to avoid unused warning in some feature builds.
@@ -242,6 +313,7 @@ fn wrap_schema_err_with_sheet(sheet: &str, err: IngestionError) -> IngestionErro
 fn build_header_projection(
     range: &calamine::Range<Data>,
     schema: &Schema,
+    options: &IngestionOptions,
 ) -> IngestionResult<(usize, Vec<usize>, Vec<String>)> {
     let mut header_row_idx: Option<usize> = None;
     let mut header_cells: Option<Vec<String>> = None;
@@ -263,7 +335,7 @@ fn build_header_projection(
     // Build a projection of schema field -> column index by searching header_cells.
     let mut col_idxs: Vec<usize> = Vec::with_capacity(schema.fields.len());
     for f in &schema.fields {
-        match header_cells.iter().position(|h| h.trim() == f.name) {
+        match header_position(header_cells.iter().map(|h| h.trim()), &f.name, options) {
             Some(idx) => col_idxs.push(idx),
             None => {
                 return Err(IngestionError::SchemaMismatch {
@@ -304,16 +376,27 @@ fn convert_cell(
     column: &str,
     data_type: &DataType,
     c: &Data,
+    options: &IngestionOptions,
 ) -> IngestionResult<Value> {
     if matches!(c, Data::Empty) {
         return Ok(Value::Null);
     }
+    if let Data::String(s) = c {
+        if let Some(parsed) = custom_parse(row, column, data_type, s, options) {
+            return parsed;
+        }
+    }
 
     match data_type {
-        DataType::Utf8 => Ok(Value::Utf8(cell_to_string(c))),
-        DataType::Bool => parse_bool_cell(row, column, c).map(Value::Bool),
-        DataType::Int64 => parse_i64_cell(row, column, c).map(Value::Int64),
-        DataType::Float64 => parse_f64_cell(row, column, c).map(Value::Float64),
+        DataType::Utf8 | DataType::Enum(_) => Ok(Value::Utf8(cell_to_string(c))),
+        DataType::Bytes => Ok(Value::Bytes(cell_to_string(c).into_bytes())),
+        DataType::Bool => parse_bool_cell(row, column, c, options).map(Value::Bool),
+        DataType::Int64 => parse_i64_cell(row, column, c, options).map(Value::Int64),
+        DataType::Float64 => {
+            let v = parse_f64_cell(row, column, c, options)?;
+            float_value(v, row, column, &c.to_string(), options)
+        }
+        DataType::Timestamp => parse_timestamp_cell(row, column, c).map(Value::Timestamp),
     }
 }
 
@@ -324,12 +407,22 @@ fn cell_to_string(c: &Data) -> String {
     }
 }
 
-fn parse_bool_cell(row: usize, column: &str, c: &Data) -> IngestionResult<bool> {
+fn parse_bool_cell(
+    row: usize,
+    column: &str,
+    c: &Data,
+    options: &IngestionOptions,
+) -> IngestionResult<bool> {
     match c {
         Data::Bool(b) => Ok(*b),
         Data::Int(i) => Ok(*i != 0),
         Data::Float(f) => Ok(*f != 0.0),
-        Data::String(s) => parse_bool_str(s).map_err(|message| IngestionError::ParseError {
+        Data::String(s) => parse_bool(
+            s.trim(),
+            &options.bool_true_tokens,
+            &options.bool_false_tokens,
+        )
+        .map_err(|message| IngestionError::ParseError {
             row,
             column: column.to_string(),
             raw: s.clone(),
@@ -344,15 +437,12 @@ fn parse_bool_cell(row: usize, column: &str, c: &Data) -> IngestionResult<bool>
     }
 }
 
-fn parse_bool_str(s: &str) -> Result<bool, String> {
-    match s.trim().to_ascii_lowercase().as_str() {
-        "true" | "t" | "1" | "yes" | "y" => Ok(true),
-        "false" | "f" | "0" | "no" | "n" => Ok(false),
-        _ => Err("expected bool (true/false/1/0/yes/no)".to_string()),
-    }
-}
-
-fn parse_i64_cell(row: usize, column: &str, c: &Data) -> IngestionResult<i64> {
+fn parse_i64_cell(
+    row: usize,
+    column: &str,
+    c: &Data,
+    options: &IngestionOptions,
+) -> IngestionResult<i64> {
     match c {
         Data::Int(i) => Ok(*i),
         Data::Float(f) => {
@@ -367,8 +457,7 @@ fn parse_i64_cell(row: usize, column: &str, c: &Data) -> IngestionResult<i64> {
                 })
             }
         }
-        Data::String(s) => s
-            .trim()
+        Data::String(s) => normalize_numeric(s.trim(), options)
             .parse::<i64>()
             .map_err(|e| IngestionError::ParseError {
                 row,
@@ -385,12 +474,43 @@ fn parse_i64_cell(row: usize, column: &str, c: &Data) -> IngestionResult<i64> {
     }
 }
 
-fn parse_f64_cell(row: usize, column: &str, c: &Data) -> IngestionResult<f64> {
+/// Date-time cells (millisecond precision, 1900 and 1904 date systems) or ISO 8601 text, as
+/// epoch microseconds.
+fn parse_timestamp_cell(row: usize, column: &str, c: &Data) -> IngestionResult<i64> {
+    match c {
+        Data::DateTime(dt) if dt.is_datetime() => {
+            let (y, mo, d, h, mi, s, ms) = dt.to_ymd_hms_milli();
+            let days = days_from_civil(i64::from(y), i64::from(mo), i64::from(d));
+            let secs = ((i64::from(h) * 60 + i64::from(mi)) * 60) + i64::from(s);
+            Ok((days * 86_400 + secs) * 1_000_000 + i64::from(ms) * 1_000)
+        }
+        Data::String(s) | Data::DateTimeIso(s) => {
+            parse_timestamp(s.trim()).map_err(|message| IngestionError::ParseError {
+                row,
+                column: column.to_string(),
+                raw: s.clone(),
+                message,
+            })
+        }
+        _ => Err(IngestionError::ParseError {
+            row,
+            column: column.to_string(),
+            raw: c.to_string(),
+            message: "expected date-time".to_string(),
+        }),
+    }
+}
+
+fn parse_f64_cell(
+    row: usize,
+    column: &str,
+    c: &Data,
+    options: &IngestionOptions,
+) -> IngestionResult<f64> {
     match c {
         Data::Float(f) => Ok(*f),
         Data::Int(i) => Ok(*i as f64),
-        Data::String(s) => s
-            .trim()
+        Data::String(s) => normalize_numeric(s.trim(), options)
             .parse::<f64>()
             .map_err(|e| IngestionError::ParseError {
                 row,
@@ -5,19 +5,41 @@
 //! - Newline-delimited JSON (NDJSON): `{"a":1}\n{"a":2}\n`
 //!
 //! Nested fields are supported using dot paths in schema field names (e.g. `user.name`).
+//! [`DataType::Bytes`] fields are read from standard base64 strings and [`DataType::Timestamp`]
+//! fields from ISO 8601 strings.
 
+use std::fmt;
 use std::fs::File;
+use std::io::BufReader;
 use std::path::Path;
 
 use crate::error::{IngestionError, IngestionResult};
-use crate::types::{DataSet, DataType, Schema, Value};
+use crate::types::{DataSet, DataType, Schema, Value, parse_timestamp};
 
+use base64::Engine as _;
+use base64::engine::general_purpose::STANDARD as BASE64;
 use polars::prelude::*;
+use serde::de::{Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
 
+use super::common::{
+    Progress, apply_enum_domains, apply_float_policy, check_not_empty, check_row_limit, enum_value,
+    validate_value,
+};
 use super::polars_bridge::{dataframe_to_dataset, polars_error_to_ingestion};
+use super::unified::{IngestionFormat, IngestionOptions};
 
 /// Ingest JSON into an in-memory `DataSet`.
 pub fn ingest_json_from_path(path: impl AsRef<Path>, schema: &Schema) -> IngestionResult<DataSet> {
+    ingest_json_from_path_with_options(path, schema, &IngestionOptions::default())
+}
+
+/// Ingest JSON from a path, honoring the JSON-relevant settings of [`IngestionOptions`]
+/// (`float_policy`, `case_insensitive_enums`, `progress_every`).
+pub fn ingest_json_from_path_with_options(
+    path: impl AsRef<Path>,
+    schema: &Schema,
+    options: &IngestionOptions,
+) -> IngestionResult<DataSet> {
     let path = path.as_ref();
     let ext = path.extension().and_then(|s| s.to_str()).unwrap_or("");
 
@@ -32,6 +54,7 @@ pub fn ingest_json_from_path(path: impl AsRef<Path>, schema: &Schema) -> Ingesti
         .with_json_format(json_format)
         .finish()
         .map_err(|e| json_polars_error("failed to read json with polars", e))?;
+    check_row_limit(options, df.height())?;
 
     // Ensure required top-level fields exist before we build any lazy projections.
     // (Dotted fields like `user.name` are handled via derived columns below.)
@@ -63,7 +86,62 @@ pub fn ingest_json_from_path(path: impl AsRef<Path>, schema: &Schema) -> Ingesti
         .collect()
         .map_err(|e| json_polars_error("failed to project json fields", e))?;
 
-    dataframe_to_dataset(&projected, schema, "field", 1)
+    // Bytes (base64) and Timestamp (ISO 8601) fields arrive as strings: read them as Utf8, then
+    // decode.
+    let read_schema = Schema::new(
+        schema
+            .fields
+            .iter()
+            .map(|f| match f.data_type {
+                DataType::Bytes | DataType::Timestamp => {
+                    crate::types::Field::new(f.name.clone(), DataType::Utf8)
+                }
+                _ => f.clone(),
+            })
+            .collect(),
+    );
+    let progress = Progress::new(options, path, IngestionFormat::Json);
+    let mut ds = dataframe_to_dataset(&projected, &read_schema, "field", 1, progress.as_ref())?;
+    for (c, field) in schema.fields.iter().enumerate() {
+        if !matches!(field.data_type, DataType::Bytes | DataType::Timestamp) {
+            continue;
+        }
+        for (row_idx0, row) in ds.rows.iter_mut().enumerate() {
+            if let Value::Utf8(s) = &row[c] {
+                row[c] = match field.data_type {
+                    DataType::Bytes => decode_base64(row_idx0 + 1, &field.name, s)?,
+                    _ => decode_timestamp(row_idx0 + 1, &field.name, s)?,
+                };
+            }
+        }
+    }
+    ds.schema = schema.clone();
+    apply_float_policy(&mut ds, options, 1)?;
+    apply_enum_domains(&mut ds, options, 1)?;
+    check_not_empty(options, ds)
+}
+
+fn decode_base64(row: usize, column: &str, s: &str) -> IngestionResult<Value> {
+    BASE64
+        .decode(s)
+        .map(Value::Bytes)
+        .map_err(|e| IngestionError::ParseError {
+            row,
+            column: column.to_string(),
+            raw: s.to_string(),
+            message: format!("invalid base64: {e}"),
+        })
+}
+
+fn decode_timestamp(row: usize, column: &str, s: &str) -> IngestionResult<Value> {
+    parse_timestamp(s.trim())
+        .map(Value::Timestamp)
+        .map_err(|message| IngestionError::ParseError {
+            row,
+            column: column.to_string(),
+            raw: s.to_string(),
+            message,
+        })
 }
 
 fn json_polars_error(action: &str, err: PolarsError) -> IngestionError {
@@ -75,7 +153,7 @@ fn json_polars_error(action: &str, err: PolarsError) -> IngestionError {
     }
 }
 
-fn expr_for_dot_path(path: &str) -> Expr {
+pub(crate) fn expr_for_dot_path(path: &str) -> Expr {
     let mut iter = path.split('.');
     let root = iter.next().unwrap_or(path);
     let mut expr = col(root);
@@ -122,37 +200,128 @@ pub fn ingest_json_from_str(input: &str, schema: &Schema) -> IngestionResult<Dat
     }
 }
 
-fn ingest_json_values(values: &[serde_json::Value], schema: &Schema) -> IngestionResult<DataSet> {
-    let mut rows: Vec<Vec<Value>> = Vec::with_capacity(values.len());
-
-    for (idx0, v) in values.iter().enumerate() {
-        let row_num = idx0 + 1;
-        let obj = v
-            .as_object()
-            .ok_or_else(|| IngestionError::SchemaMismatch {
-                message: format!("row {row_num} is not a json object"),
-            })?;
+/// Ingest a file holding one top-level JSON array of objects, decoding one element at a time.
+///
+/// Unlike [`ingest_json_from_path`], the file is never read into memory whole: elements are
+/// parsed from a buffered reader, converted to a row, and dropped, so peak memory is the
+/// resulting [`DataSet`] plus one object. Conversion rules match [`ingest_json_from_str`]
+/// (dot paths, base64 Bytes, 1-based row numbers). NDJSON and single objects are rejected with
+/// [`IngestionError::SchemaMismatch`]. Use [`fold_json_array`] to avoid materializing the rows.
+pub fn ingest_json_array_streaming(
+    path: impl AsRef<Path>,
+    schema: &Schema,
+) -> IngestionResult<DataSet> {
+    let rows = fold_json_array(path, schema, Vec::new(), |mut rows, row| {
+        rows.push(row);
+        rows
+    })?;
+    Ok(DataSet::new(schema.clone(), rows))
+}
 
-        let mut row: Vec<Value> = Vec::with_capacity(schema.fields.len());
-        for field in &schema.fields {
-            let jv = get_by_dot_path(obj, &field.name).ok_or_else(|| {
-                IngestionError::SchemaMismatch {
-                    message: format!("row {row_num} missing required field '{}'", field.name),
+/// Fold every element of a top-level JSON array of objects into an accumulator, one row at a
+/// time, without holding the file or the rows in memory (see [`ingest_json_array_streaming`]).
+///
+/// The first bad element aborts the fold with its error.
+pub fn fold_json_array<A>(
+    path: impl AsRef<Path>,
+    schema: &Schema,
+    init: A,
+    f: impl FnMut(A, Vec<Value>) -> A,
+) -> IngestionResult<A> {
+    struct ArrayRows<'a, A, F> {
+        schema: &'a Schema,
+        acc: &'a mut Option<A>,
+        f: F,
+        failed: &'a mut Option<IngestionError>,
+    }
+
+    impl<'de, A, F: FnMut(A, Vec<Value>) -> A> Visitor<'de> for ArrayRows<'_, A, F> {
+        type Value = ();
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a json array of objects")
+        }
+
+        fn visit_seq<S: SeqAccess<'de>>(mut self, mut seq: S) -> Result<(), S::Error> {
+            let mut row_num = 0usize;
+            while let Some(item) = seq.next_element::<serde_json::Value>()? {
+                row_num += 1;
+                match json_object_to_row(row_num, &item, self.schema) {
+                    Ok(row) => {
+                        let acc = self.acc.take().expect("accumulator present between rows");
+                        *self.acc = Some((self.f)(acc, row));
+                    }
+                    Err(e) => {
+                        *self.failed = Some(e);
+                        return Err(serde::de::Error::custom("row conversion failed"));
+                    }
                 }
-            })?;
-            row.push(convert_json_value(
-                row_num,
-                &field.name,
-                &field.data_type,
-                jv,
-            )?);
+            }
+            Ok(())
         }
-        rows.push(row);
     }
 
+    let reader = BufReader::new(File::open(path)?);
+    let mut de = serde_json::Deserializer::from_reader(reader);
+    let mut acc = Some(init);
+    let mut failed = None;
+    let visitor = ArrayRows {
+        schema,
+        acc: &mut acc,
+        f,
+        failed: &mut failed,
+    };
+    let parsed = de.deserialize_seq(visitor).and_then(|()| de.end());
+    if let Some(e) = failed {
+        return Err(e);
+    }
+    parsed.map_err(|e| IngestionError::SchemaMismatch {
+        message: format!("invalid json array: {e}"),
+    })?;
+    Ok(acc.expect("accumulator present after the array"))
+}
+
+fn ingest_json_values(values: &[serde_json::Value], schema: &Schema) -> IngestionResult<DataSet> {
+    let rows = values
+        .iter()
+        .enumerate()
+        .map(|(idx0, v)| json_object_to_row(idx0 + 1, v, schema))
+        .collect::<IngestionResult<Vec<_>>>()?;
     Ok(DataSet::new(schema.clone(), rows))
 }
 
+/// Convert one top-level JSON object (1-based `row_num`) into a row in schema order.
+fn json_object_to_row(
+    row_num: usize,
+    v: &serde_json::Value,
+    schema: &Schema,
+) -> IngestionResult<Vec<Value>> {
+    let obj = v
+        .as_object()
+        .ok_or_else(|| IngestionError::SchemaMismatch {
+            message: format!("row {row_num} is not a json object"),
+        })?;
+
+    let mut row: Vec<Value> = Vec::with_capacity(schema.fields.len());
+    for field in &schema.fields {
+        let jv =
+            get_by_dot_path(obj, &field.name).ok_or_else(|| IngestionError::SchemaMismatch {
+                message: format!("row {row_num} missing required field '{}'", field.name),
+            })?;
+        let value = convert_json_value(row_num, &field.name, &field.data_type, jv)?;
+        let value = enum_value(
+            &field.data_type,
+            row_num,
+            &field.name,
+            value,
+            &IngestionOptions::default(),
+        )?;
+        validate_value(field, row_num, &field.name, &value)?;
+        row.push(value);
+    }
+    Ok(row)
+}
+
 fn get_by_dot_path<'a>(
     root: &'a serde_json::Map<String, serde_json::Value>,
     path: &str,
@@ -184,7 +353,7 @@ fn convert_json_value(
     }
 
     match data_type {
-        DataType::Utf8 => v
+        DataType::Utf8 | DataType::Enum(_) => v
             .as_str()
             .map(|s| Value::Utf8(s.to_string()))
             .ok_or_else(|| IngestionError::ParseError {
@@ -193,6 +362,24 @@ fn convert_json_value(
                 raw: v.to_string(),
                 message: "expected string".to_string(),
             }),
+        DataType::Bytes => match v.as_str() {
+            Some(s) => decode_base64(row, column, s),
+            None => Err(IngestionError::ParseError {
+                row,
+                column: column.to_string(),
+                raw: v.to_string(),
+                message: "expected base64 string".to_string(),
+            }),
+        },
+        DataType::Timestamp => match v.as_str() {
+            Some(s) => decode_timestamp(row, column, s),
+            None => Err(IngestionError::ParseError {
+                row,
+                column: column.to_string(),
+                raw: v.to_string(),
+                message: "expected ISO 8601 timestamp string".to_string(),
+            }),
+        },
         DataType::Bool => v
             .as_bool()
             .map(Value::Bool)
@@ -235,3 +422,252 @@ fn convert_json_value(
         }
     }
 }
+
+/// Infer a [`Schema`] from up to `sample_rows` top-level objects of a JSON / NDJSON file.
+///
+/// Nested objects are flattened into dotted field names (e.g. `user.name`), matching how
+/// ingestion resolves dot paths. Per leaf:
+///
+/// - int and float observations widen to Float64
+/// - conflicting kinds (e.g. string vs number), arrays, and all-null leaves fall back to Utf8
+/// - a key that is `null` in some rows and an object in others only yields its dotted leaves
+///
+/// Fields are ordered by first appearance. Inference is best-effort; callers can adjust the
+/// returned schema before ingesting.
+pub fn infer_schema(path: impl AsRef<Path>, sample_rows: usize) -> IngestionResult<Schema> {
+    Ok(Schema::new(
+        infer_leaves(path.as_ref(), sample_rows)?
+            .into_iter()
+            .map(|(name, st)| crate::types::Field::new(name, st.finish_type()))
+            .collect(),
+    ))
+}
+
+/// Flatten nested JSON objects into dotted leaf columns and ingest them, without a schema.
+///
+/// Leaf paths and types are inferred from up to `sample_rows` objects exactly as in
+/// [`infer_schema`], except that **arrays are skipped**: a leaf that only ever held arrays is
+/// left out (one mixing arrays with other kinds becomes Utf8, as in `infer_schema`). Every row
+/// of the file is then ingested with the inferred schema, which is returned alongside the data.
+/// Meant for quick inspection of unfamiliar JSON; pass the schema to
+/// [`ingest_json_from_path`] once it has been reviewed.
+///
+/// Returns [`IngestionError::SchemaMismatch`] if the sample has no scalar leaves; later rows
+/// that do not fit the inferred types fail like any other ingestion.
+pub fn flatten_to_schema(
+    path: impl AsRef<Path>,
+    sample_rows: usize,
+) -> IngestionResult<(Schema, DataSet)> {
+    let path = path.as_ref();
+    let schema = Schema::new(
+        infer_leaves(path, sample_rows)?
+            .into_iter()
+            .filter(|(_, st)| *st != JsonInferState::Array)
+            .map(|(name, st)| crate::types::Field::new(name, st.finish_type()))
+            .collect(),
+    );
+    if schema.fields.is_empty() {
+        return Err(IngestionError::SchemaMismatch {
+            message: "json sample has no scalar fields to flatten".to_string(),
+        });
+    }
+    let ds = ingest_json_from_path(path, &schema)?;
+    Ok((schema, ds))
+}
+
+/// Dotted leaf paths with their merged inference state, in first-appearance order.
+fn infer_leaves(path: &Path, sample_rows: usize) -> IngestionResult<Vec<(String, JsonInferState)>> {
+    let input = std::fs::read_to_string(path)?;
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Err(IngestionError::SchemaMismatch {
+            message: "json input is empty".to_string(),
+        });
+    }
+
+    let docs: Vec<JsonNode> = match serde_json::from_str::<JsonNode>(trimmed) {
+        Ok(JsonNode::Array(items)) => items.into_iter().take(sample_rows).collect(),
+        Ok(obj @ JsonNode::Object(_)) => vec![obj],
+        Ok(_) => {
+            return Err(IngestionError::SchemaMismatch {
+                message: "json must be an object, an array of objects, or NDJSON".to_string(),
+            });
+        }
+        Err(_) => {
+            let mut docs = Vec::new();
+            for (i, line) in trimmed.lines().enumerate() {
+                if docs.len() >= sample_rows {
+                    break;
+                }
+                let line = line.trim();
+                if line.is_empty() {
+                    continue;
+                }
+                let v = serde_json::from_str::<JsonNode>(line).map_err(|e| {
+                    IngestionError::SchemaMismatch {
+                        message: format!("invalid ndjson at line {}: {}", i + 1, e),
+                    }
+                })?;
+                docs.push(v);
+            }
+            docs
+        }
+    };
+
+    let mut leaves: Vec<(String, JsonInferState)> = Vec::new();
+    for (idx0, doc) in docs.iter().enumerate() {
+        let JsonNode::Object(entries) = doc else {
+            return Err(IngestionError::SchemaMismatch {
+                message: format!("row {} is not a json object", idx0 + 1),
+            });
+        };
+        observe_object("", entries, &mut leaves);
+    }
+
+    // Null is neutral: a key that is `null` in some rows and an object in others is the parent
+    // of its dotted leaves, not a column of its own.
+    let null_parents: Vec<String> = leaves
+        .iter()
+        .filter(|(name, st)| {
+            *st == JsonInferState::Unknown
+                && leaves
+                    .iter()
+                    .any(|(other, _)| other.starts_with(&format!("{name}.")))
+        })
+        .map(|(name, _)| name.clone())
+        .collect();
+    leaves.retain(|(name, _)| !null_parents.contains(name));
+    Ok(leaves)
+}
+
+fn observe_object(
+    prefix: &str,
+    entries: &[(String, JsonNode)],
+    leaves: &mut Vec<(String, JsonInferState)>,
+) {
+    for (key, node) in entries {
+        let path = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{prefix}.{key}")
+        };
+        let next = match node {
+            JsonNode::Object(children) => {
+                observe_object(&path, children, leaves);
+                continue;
+            }
+            JsonNode::Null => JsonInferState::Unknown,
+            JsonNode::Bool => JsonInferState::Bool,
+            JsonNode::Int => JsonInferState::Int,
+            JsonNode::Float => JsonInferState::Float,
+            JsonNode::String => JsonInferState::Utf8,
+            JsonNode::Array(_) => JsonInferState::Array,
+        };
+        match leaves.iter_mut().find(|(name, _)| *name == path) {
+            Some((_, st)) => st.merge(next),
+            None => leaves.push((path, next)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum JsonInferState {
+    Unknown,
+    Bool,
+    Int,
+    Float,
+    Utf8,
+    /// Only arrays seen so far (typed as Utf8; skipped by [`flatten_to_schema`]).
+    Array,
+}
+
+impl JsonInferState {
+    fn merge(&mut self, next: JsonInferState) {
+        use JsonInferState::*;
+        *self = match (*self, next) {
+            (x, Unknown) | (Unknown, x) => x,
+            (a, b) if a == b => a,
+            (Int, Float) | (Float, Int) => Float,
+            _ => Utf8,
+        };
+    }
+
+    fn finish_type(self) -> DataType {
+        match self {
+            JsonInferState::Bool => DataType::Bool,
+            JsonInferState::Int => DataType::Int64,
+            JsonInferState::Float => DataType::Float64,
+            JsonInferState::Utf8 | JsonInferState::Unknown | JsonInferState::Array => {
+                DataType::Utf8
+            }
+        }
+    }
+}
+
+/// Minimal JSON shape that keeps object keys in document order (unlike `serde_json::Map`,
+/// which sorts keys unless the `preserve_order` feature is enabled). Scalars keep only their kind.
+enum JsonNode {
+    Null,
+    Bool,
+    Int,
+    Float,
+    String,
+    Array(Vec<JsonNode>),
+    Object(Vec<(String, JsonNode)>),
+}
+
+impl<'de> Deserialize<'de> for JsonNode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct NodeVisitor;
+
+        impl<'de> Visitor<'de> for NodeVisitor {
+            type Value = JsonNode;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("any JSON value")
+            }
+
+            fn visit_unit<E>(self) -> Result<JsonNode, E> {
+                Ok(JsonNode::Null)
+            }
+
+            fn visit_bool<E>(self, _: bool) -> Result<JsonNode, E> {
+                Ok(JsonNode::Bool)
+            }
+
+            fn visit_i64<E>(self, _: i64) -> Result<JsonNode, E> {
+                Ok(JsonNode::Int)
+            }
+
+            fn visit_u64<E>(self, _: u64) -> Result<JsonNode, E> {
+                Ok(JsonNode::Int)
+            }
+
+            fn visit_f64<E>(self, _: f64) -> Result<JsonNode, E> {
+                Ok(JsonNode::Float)
+            }
+
+            fn visit_str<E>(self, _: &str) -> Result<JsonNode, E> {
+                Ok(JsonNode::String)
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<JsonNode, A::Error> {
+                let mut items = Vec::new();
+                while let Some(item) = seq.next_element()? {
+                    items.push(item);
+                }
+                Ok(JsonNode::Array(items))
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<JsonNode, A::Error> {
+                let mut entries = Vec::new();
+                while let Some((k, v)) = map.next_entry::<String, JsonNode>()? {
+                    entries.push((k, v));
+                }
+                Ok(JsonNode::Object(entries))
+            }
+        }
+
+        deserializer.deserialize_any(NodeVisitor)
+    }
+}
@@ -16,6 +16,7 @@
 //! - [`parquet`]
 
 pub mod builder;
+pub(crate) mod common;
 pub mod csv;
 #[cfg(feature = "excel")]
 pub mod excel;
@@ -31,6 +32,8 @@ pub mod excel {
     use crate::error::{IngestionError, IngestionResult};
     use crate::types::{DataSet, Schema};
 
+    use super::unified::IngestionOptions;
+
     fn disabled() -> IngestionError {
         IngestionError::SchemaMismatch {
             message: "excel ingestion is disabled; enable Cargo feature 'excel'".to_string(),
@@ -45,6 +48,15 @@ pub mod excel {
         Err(disabled())
     }
 
+    pub fn ingest_excel_from_path_with_options(
+        _path: impl AsRef<Path>,
+        _sheet_name: Option<&str>,
+        _schema: &Schema,
+        _options: &IngestionOptions,
+    ) -> IngestionResult<DataSet> {
+        Err(disabled())
+    }
+
     pub fn ingest_excel_workbook_from_path(
         _path: impl AsRef<Path>,
         _sheet_names: Option<&[&str]>,
@@ -53,6 +65,25 @@ pub mod excel {
         Err(disabled())
     }
 
+    pub fn ingest_excel_workbook_from_path_with_options(
+        _path: impl AsRef<Path>,
+        _sheet_names: Option<&[&str]>,
+        _schema: &Schema,
+        _options: &IngestionOptions,
+    ) -> IngestionResult<DataSet> {
+        Err(disabled())
+    }
+
+    pub(crate) fn ingest_excel_workbook_tracking_sheet(
+        _path: &Path,
+        _sheet_names: Option<&[&str]>,
+        _schema: &Schema,
+        _options: &IngestionOptions,
+        _current_sheet: &mut Option<String>,
+    ) -> IngestionResult<DataSet> {
+        Err(disabled())
+    }
+
     pub fn infer_excel_schema_from_path(
         _path: impl AsRef<Path>,
         _sheet_name: Option<&str>,
@@ -100,12 +131,17 @@ pub mod unified;
 
 pub use builder::IngestionOptionsBuilder;
 pub use observability::{
-    CompositeObserver, FileObserver, IngestionContext, IngestionObserver, IngestionSeverity,
-    IngestionStats, StdErrObserver,
+    CompositeObserver, CountingObserver, FileObserver, IngestionContext, IngestionCounts,
+    IngestionObserver, IngestionSeverity, IngestionStats, NullObserver, RateLimitObserver,
+    StdErrObserver,
 };
+#[cfg(feature = "tokio")]
+pub use unified::ingest_from_path_async;
 pub use unified::{
-    ExcelSheetSelection, IngestionFormat, IngestionOptions, IngestionRequest,
-    infer_schema_from_path, ingest_from_path, ingest_from_path_infer,
+    CsvEncoding, DEFAULT_VALIDATE_SAMPLE_ROWS, ExcelSheetSelection, FloatPolicy, IngestionFormat,
+    IngestionOptions, IngestionRequest, NumericLocale, ParserRegistry, TypeParser,
+    ValidationReport, fold_rows, infer_schema_from_path, ingest_from_path,
+    ingest_from_path_detailed, ingest_from_path_infer, validate, validate_with_sample_rows,
 };
 
 pub use db::{ingest_from_db, ingest_from_db_infer};
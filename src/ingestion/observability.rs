@@ -1,11 +1,14 @@
+use std::collections::VecDeque;
 use std::fmt;
 use std::fs::OpenOptions;
 use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use crate::error::IngestionError;
+use crate::types::Schema;
 
 use super::unified::IngestionFormat;
 
@@ -29,6 +32,9 @@ pub struct IngestionContext {
     pub path: PathBuf,
     /// Format used for ingestion.
     pub format: IngestionFormat,
+    /// Excel sheet that was being read when ingestion failed; `None` for other formats and
+    /// for successful runs.
+    pub sheet: Option<String>,
 }
 
 /// Minimal stats reported on successful ingestion.
@@ -65,9 +71,35 @@ pub trait IngestionObserver: Send + Sync {
     ) {
         self.on_failure(ctx, severity, error)
     }
+
+    /// Veto hook consulted by [`CompositeObserver`] before it fans out `on_alert`.
+    ///
+    /// Returning `false` suppresses the alert for every child of the composite (e.g. a
+    /// rate-limiter dropping duplicate alerts); `on_failure` is still delivered. Default: `true`.
+    fn should_alert(&self, _ctx: &IngestionContext, _severity: IngestionSeverity) -> bool {
+        true
+    }
+
+    /// Called periodically while rows are being converted, with the number of rows produced so
+    /// far (including earlier sheets of a workbook).
+    ///
+    /// Only invoked when [`super::IngestionOptions::progress_every`] is set, every that many rows.
+    /// Reader-based entrypoints that have no path report an empty `ctx.path`.
+    fn on_progress(&self, _ctx: &IngestionContext, _rows_so_far: usize) {}
+
+    /// Called when [`super::infer_schema_from_path`] (or [`super::ingest_from_path_infer`])
+    /// infers a schema, before any rows are ingested.
+    ///
+    /// Fires independently of `on_success` / `on_failure`, so the inferred schema can be logged
+    /// for reproducibility even when ingestion never runs.
+    fn on_schema_inferred(&self, _ctx: &IngestionContext, _schema: &Schema) {}
 }
 
 /// An observer that fans out callbacks to a list of observers.
+///
+/// Before forwarding `on_alert`, every child's [`IngestionObserver::should_alert`] is asked
+/// (each exactly once, in order, so stateful checks see every alert); if any returns `false`,
+/// no child receives the alert.
 #[derive(Default)]
 pub struct CompositeObserver {
     observers: Vec<Arc<dyn IngestionObserver>>,
@@ -112,10 +144,210 @@ impl IngestionObserver for CompositeObserver {
         severity: IngestionSeverity,
         error: &IngestionError,
     ) {
+        if !self.should_alert(ctx, severity) {
+            return;
+        }
         for o in &self.observers {
             o.on_alert(ctx, severity, error);
         }
     }
+
+    fn should_alert(&self, ctx: &IngestionContext, severity: IngestionSeverity) -> bool {
+        // No short-circuit: every child sees every alert.
+        let mut allow = true;
+        for o in &self.observers {
+            allow &= o.should_alert(ctx, severity);
+        }
+        allow
+    }
+
+    fn on_progress(&self, ctx: &IngestionContext, rows_so_far: usize) {
+        for o in &self.observers {
+            o.on_progress(ctx, rows_so_far);
+        }
+    }
+
+    fn on_schema_inferred(&self, ctx: &IngestionContext, schema: &Schema) {
+        for o in &self.observers {
+            o.on_schema_inferred(ctx, schema);
+        }
+    }
+}
+
+/// An observer that ignores every callback, for when "observe nothing" should be explicit.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NullObserver;
+
+impl IngestionObserver for NullObserver {}
+
+/// Point-in-time counters read from a [`CountingObserver`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct IngestionCounts {
+    /// Successful ingestions.
+    pub success_count: usize,
+    /// Failed ingestions (alerts are not counted again).
+    pub failure_count: usize,
+    /// Rows summed over successful ingestions.
+    pub total_rows: usize,
+}
+
+/// Counts successes, failures, and ingested rows with atomics; share it via `Arc` and read it
+/// with [`CountingObserver::snapshot`].
+#[derive(Debug, Default)]
+pub struct CountingObserver {
+    success_count: AtomicUsize,
+    failure_count: AtomicUsize,
+    total_rows: AtomicUsize,
+}
+
+impl CountingObserver {
+    /// Create an observer with all counters at zero.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Current counter values. Each counter is read independently, so a snapshot taken while
+    /// ingestions are running may mix before/after values.
+    pub fn snapshot(&self) -> IngestionCounts {
+        IngestionCounts {
+            success_count: self.success_count.load(Ordering::Relaxed),
+            failure_count: self.failure_count.load(Ordering::Relaxed),
+            total_rows: self.total_rows.load(Ordering::Relaxed),
+        }
+    }
+}
+
+impl IngestionObserver for CountingObserver {
+    fn on_success(&self, _ctx: &IngestionContext, stats: IngestionStats) {
+        self.success_count.fetch_add(1, Ordering::Relaxed);
+        self.total_rows.fetch_add(stats.rows, Ordering::Relaxed);
+    }
+
+    fn on_failure(
+        &self,
+        _ctx: &IngestionContext,
+        _severity: IngestionSeverity,
+        _error: &IngestionError,
+    ) {
+        self.failure_count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Alerts always follow an `on_failure` for the same error, so they are not counted.
+    fn on_alert(
+        &self,
+        _ctx: &IngestionContext,
+        _severity: IngestionSeverity,
+        _error: &IngestionError,
+    ) {
+    }
+}
+
+/// Forwards at most `max_per` events per sliding `window` to an inner observer and drops the
+/// rest, so a job failing in a tight loop cannot flood logs.
+///
+/// Every callback (`on_success`, `on_failure`, `on_alert`, `on_progress`,
+/// `on_schema_inferred`) counts as one event;
+/// [`IngestionObserver::should_alert`] is passed through without counting. Dropped events are
+/// tallied in [`RateLimitObserver::dropped_count`].
+pub struct RateLimitObserver {
+    inner: Arc<dyn IngestionObserver>,
+    max_per: usize,
+    window: Duration,
+    recent: Mutex<VecDeque<Instant>>,
+    dropped: AtomicUsize,
+}
+
+impl RateLimitObserver {
+    /// Wrap `inner`, allowing at most `max_per` events in any `window`.
+    pub fn new(inner: Arc<dyn IngestionObserver>, max_per: usize, window: Duration) -> Self {
+        Self {
+            inner,
+            max_per,
+            window,
+            recent: Mutex::new(VecDeque::with_capacity(max_per)),
+            dropped: AtomicUsize::new(0),
+        }
+    }
+
+    /// Number of events dropped so far.
+    pub fn dropped_count(&self) -> usize {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    /// Record an event if the window has room; otherwise count it as dropped.
+    fn admit(&self) -> bool {
+        let now = Instant::now();
+        let mut recent = self.recent.lock().unwrap_or_else(|e| e.into_inner());
+        while recent
+            .front()
+            .is_some_and(|&t| now.duration_since(t) >= self.window)
+        {
+            recent.pop_front();
+        }
+        if recent.len() < self.max_per {
+            recent.push_back(now);
+            true
+        } else {
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+            false
+        }
+    }
+}
+
+impl fmt::Debug for RateLimitObserver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RateLimitObserver")
+            .field("max_per", &self.max_per)
+            .field("window", &self.window)
+            .field("dropped", &self.dropped_count())
+            .finish()
+    }
+}
+
+impl IngestionObserver for RateLimitObserver {
+    fn on_success(&self, ctx: &IngestionContext, stats: IngestionStats) {
+        if self.admit() {
+            self.inner.on_success(ctx, stats);
+        }
+    }
+
+    fn on_failure(
+        &self,
+        ctx: &IngestionContext,
+        severity: IngestionSeverity,
+        error: &IngestionError,
+    ) {
+        if self.admit() {
+            self.inner.on_failure(ctx, severity, error);
+        }
+    }
+
+    fn on_alert(
+        &self,
+        ctx: &IngestionContext,
+        severity: IngestionSeverity,
+        error: &IngestionError,
+    ) {
+        if self.admit() {
+            self.inner.on_alert(ctx, severity, error);
+        }
+    }
+
+    fn should_alert(&self, ctx: &IngestionContext, severity: IngestionSeverity) -> bool {
+        self.inner.should_alert(ctx, severity)
+    }
+
+    fn on_progress(&self, ctx: &IngestionContext, rows_so_far: usize) {
+        if self.admit() {
+            self.inner.on_progress(ctx, rows_so_far);
+        }
+    }
+
+    fn on_schema_inferred(&self, ctx: &IngestionContext, schema: &Schema) {
+        if self.admit() {
+            self.inner.on_schema_inferred(ctx, schema);
+        }
+    }
 }
 
 /// Logs ingestion events to stderr.
@@ -139,10 +371,11 @@ impl IngestionObserver for StdErrObserver {
         error: &IngestionError,
     ) {
         eprintln!(
-            "[ingest][{:?}] format={:?} path={} err={}",
+            "[ingest][{:?}] format={:?} path={}{} err={}",
             severity,
             ctx.format,
             ctx.path.display(),
+            sheet_suffix(ctx),
             error
         );
     }
@@ -154,13 +387,32 @@ impl IngestionObserver for StdErrObserver {
         error: &IngestionError,
     ) {
         eprintln!(
-            "[ALERT][ingest][{:?}] format={:?} path={} err={}",
+            "[ALERT][ingest][{:?}] format={:?} path={}{} err={}",
             severity,
             ctx.format,
             ctx.path.display(),
+            sheet_suffix(ctx),
             error
         );
     }
+
+    fn on_progress(&self, ctx: &IngestionContext, rows_so_far: usize) {
+        eprintln!(
+            "[ingest][progress] format={:?} path={} rows={}",
+            ctx.format,
+            ctx.path.display(),
+            rows_so_far
+        );
+    }
+
+    fn on_schema_inferred(&self, ctx: &IngestionContext, schema: &Schema) {
+        eprintln!(
+            "[ingest][schema] format={:?} path={} fields={}",
+            ctx.format,
+            ctx.path.display(),
+            schema_summary(schema)
+        );
+    }
 }
 
 /// Appends ingestion events to a local log file.
@@ -211,11 +463,12 @@ impl IngestionObserver for FileObserver {
         error: &IngestionError,
     ) {
         self.append_line(&format!(
-            "{} fail severity={:?} format={:?} path={} err={}",
+            "{} fail severity={:?} format={:?} path={}{} err={}",
             unix_ts(),
             severity,
             ctx.format,
             ctx.path.display(),
+            sheet_suffix(ctx),
             error
         ));
     }
@@ -227,14 +480,43 @@ impl IngestionObserver for FileObserver {
         error: &IngestionError,
     ) {
         self.append_line(&format!(
-            "{} ALERT severity={:?} format={:?} path={} err={}",
+            "{} ALERT severity={:?} format={:?} path={}{} err={}",
             unix_ts(),
             severity,
             ctx.format,
             ctx.path.display(),
+            sheet_suffix(ctx),
             error
         ));
     }
+
+    fn on_schema_inferred(&self, ctx: &IngestionContext, schema: &Schema) {
+        self.append_line(&format!(
+            "{} schema format={:?} path={} fields={}",
+            unix_ts(),
+            ctx.format,
+            ctx.path.display(),
+            schema_summary(schema)
+        ));
+    }
+}
+
+/// ` sheet=<name>` when the context names an Excel sheet, else empty.
+fn sheet_suffix(ctx: &IngestionContext) -> String {
+    ctx.sheet
+        .as_deref()
+        .map(|s| format!(" sheet={s}"))
+        .unwrap_or_default()
+}
+
+/// `name:type` pairs joined by commas, e.g. `id:int64,name:utf8`.
+fn schema_summary(schema: &Schema) -> String {
+    schema
+        .fields
+        .iter()
+        .map(|f| format!("{}:{}", f.name, f.data_type.as_str()))
+        .collect::<Vec<_>>()
+        .join(",")
 }
 
 fn unix_ts() -> u64 {
@@ -1,24 +1,57 @@
 //! Parquet ingestion implementation.
 
+use std::fs::File;
 use std::path::Path;
 
 use crate::error::{IngestionError, IngestionResult};
-use crate::types::{DataSet, DataType, Schema};
+use crate::types::{DataSet, DataType, Schema, Value};
 
+use parquet::basic::{LogicalType, TimeUnit as ParquetTimeUnit, Type as PhysicalType};
+use parquet::column::reader::ColumnReader;
+use parquet::data_type::Int96;
+use parquet::file::reader::{ChunkReader, FileReader, RowGroupReader, SerializedFileReader};
+use parquet::record::Field as ParquetField;
+use parquet::schema::types::{ColumnDescriptor, Type as ParquetType};
 use polars::prelude::*;
 
+use super::common::{
+    Progress, apply_enum_domains, apply_float_rounding, check_not_empty, check_row_limit,
+    enum_value, round_float, validate_value,
+};
+use super::json::expr_for_dot_path;
 use super::polars_bridge::{dataframe_to_dataset, polars_error_to_ingestion};
+use super::unified::{IngestionFormat, IngestionOptions};
 
 /// Ingest a Parquet file into an in-memory `DataSet`.
 ///
 /// Notes:
 /// - Validates that all schema fields exist as columns
+/// - A dotted field name like `address.city` resolves to the leaf of a nested group column,
+///   mirroring the JSON dot-path semantics (a null group yields `Value::Null`)
+/// - Timestamp columns (`TIMESTAMP_MILLIS`, `TIMESTAMP_MICROS`, `TIMESTAMP_NANOS`, legacy
+///   `INT96`) read into a [`DataType::Timestamp`] field as UTC microseconds (nanoseconds are
+///   truncated)
 /// - Delegates Parquet decoding to Polars, then converts into `DataSet`
 pub fn ingest_parquet_from_path(
     path: impl AsRef<Path>,
     schema: &Schema,
+) -> IngestionResult<DataSet> {
+    ingest_parquet_from_path_with_options(path, schema, &IngestionOptions::default())
+}
+
+/// Ingest a Parquet file, honoring the Parquet-relevant settings of [`IngestionOptions`]
+/// (`case_insensitive_enums`, `progress_every`).
+pub fn ingest_parquet_from_path_with_options(
+    path: impl AsRef<Path>,
+    schema: &Schema,
+    options: &IngestionOptions,
 ) -> IngestionResult<DataSet> {
     let path = path.as_ref();
+    if options.max_rows.is_some() {
+        let reader = SerializedFileReader::new(File::open(path)?)?;
+        let rows = reader.metadata().file_metadata().num_rows();
+        check_row_limit(options, usize::try_from(rows).unwrap_or(usize::MAX))?;
+    }
 
     let df = LazyFrame::scan_parquet(
         path.to_string_lossy().as_ref().into(),
@@ -27,13 +60,480 @@ pub fn ingest_parquet_from_path(
     .map_err(|e| polars_error_to_ingestion("failed to read parquet with polars", e))?
     .collect()
     .map_err(|e| polars_error_to_ingestion("failed to collect parquet with polars", e))?;
+    let df = with_nested_leaf_columns(df, schema)?;
 
     // Parquet: keep "type mismatch" strictness. If the physical/logical Parquet column type is
     // incompatible with the requested schema type (e.g. UTF8 string column for an Int64 field),
     // we surface this as a ParseError (tests rely on this behavior).
     validate_parquet_column_types(&df, schema)?;
 
-    dataframe_to_dataset(&df, schema, "column", 1)
+    let progress = Progress::new(options, path, IngestionFormat::Parquet);
+    let mut ds = dataframe_to_dataset(&df, schema, "column", 1, progress.as_ref())?;
+    apply_float_rounding(&mut ds, options);
+    apply_enum_domains(&mut ds, options, 1)?;
+    check_not_empty(options, ds)
+}
+
+/// Add a derived column for every dotted schema field that is not a top-level column, reading
+/// the nested struct leaf (e.g. `address.city` -> `address` struct, field `city`).
+fn with_nested_leaf_columns(df: DataFrame, schema: &Schema) -> IngestionResult<DataFrame> {
+    let mut derived: Vec<Expr> = Vec::new();
+    for field in &schema.fields {
+        if !field.name.contains('.') || df.column(&field.name).is_ok() {
+            continue;
+        }
+        let root = field.name.split('.').next().unwrap_or(&field.name);
+        if df.column(root).is_err() {
+            return Err(missing_column(&field.name));
+        }
+        derived.push(expr_for_dot_path(&field.name));
+    }
+    if derived.is_empty() {
+        return Ok(df);
+    }
+    df.lazy()
+        .with_columns(derived)
+        .collect()
+        .map_err(|_| IngestionError::SchemaMismatch {
+            message: "missing required nested column in parquet file".to_string(),
+        })
+}
+
+fn missing_column(name: &str) -> IngestionError {
+    IngestionError::SchemaMismatch {
+        message: format!("missing required column '{name}'"),
+    }
+}
+
+/// Number of row groups in a Parquet file (read from the footer only).
+///
+/// Use this to pick valid indices for [`ingest_parquet_row_groups`].
+pub fn parquet_row_group_count(path: impl AsRef<Path>) -> IngestionResult<usize> {
+    let reader = SerializedFileReader::new(File::open(path)?)?;
+    Ok(reader.metadata().num_row_groups())
+}
+
+/// Ingest only the listed row groups of a Parquet file, in the order given.
+///
+/// Only the requested groups (and only the schema's columns) are decoded, which makes this a
+/// cheap way to sample large files. Type rules match [`ingest_parquet_from_path`]; rows are
+/// numbered from 1 across the selected groups in error messages.
+///
+/// Returns [`IngestionError::SchemaMismatch`] if an index is `>=` the file's row group count
+/// (see [`parquet_row_group_count`]) or a schema column is missing.
+pub fn ingest_parquet_row_groups(
+    path: impl AsRef<Path>,
+    schema: &Schema,
+    groups: &[usize],
+) -> IngestionResult<DataSet> {
+    let reader = SerializedFileReader::new(File::open(path)?)?;
+    let available = reader.metadata().num_row_groups();
+    if let Some(bad) = groups.iter().find(|&&g| g >= available) {
+        return Err(IngestionError::SchemaMismatch {
+            message: format!("row group {bad} out of range: file has {available} row groups"),
+        });
+    }
+    let options = IngestionOptions::default();
+    let rows = fold_records(
+        &reader,
+        schema,
+        groups.iter().copied(),
+        &options,
+        Vec::new(),
+        |mut rows, row| {
+            rows.push(row);
+            rows
+        },
+    )?;
+    Ok(DataSet::new(schema.clone(), rows))
+}
+
+/// Ingest a Parquet file keeping only rows where `pred` holds for the value of `column`.
+///
+/// Rows are decoded one at a time through the Parquet record API and dropped immediately when
+/// they fail the predicate, so only matching rows are materialized. This is a convenience filter,
+/// not true predicate pushdown: every row group is still read and decoded (row-group statistics
+/// are not consulted). `pred` also sees `Value::Null` cells. Error rows are numbered from 1
+/// across all decoded rows.
+///
+/// Returns [`IngestionError::SchemaMismatch`] if `column` is not in `schema` or a schema column is
+/// missing from the file.
+pub fn ingest_parquet_filtered(
+    path: impl AsRef<Path>,
+    schema: &Schema,
+    column: &str,
+    pred: impl Fn(&Value) -> bool,
+) -> IngestionResult<DataSet> {
+    let idx = schema
+        .index_of(column)
+        .ok_or_else(|| IngestionError::SchemaMismatch {
+            message: format!("filter column '{column}' is not in the schema"),
+        })?;
+    let reader = SerializedFileReader::new(File::open(path)?)?;
+    let groups = 0..reader.metadata().num_row_groups();
+    let options = IngestionOptions::default();
+    let rows = fold_records(
+        &reader,
+        schema,
+        groups,
+        &options,
+        Vec::new(),
+        |mut rows, row| {
+            if pred(&row[idx]) {
+                rows.push(row);
+            }
+            rows
+        },
+    )?;
+    Ok(DataSet::new(schema.clone(), rows))
+}
+
+/// Ingest a Parquet file through a read-only memory map instead of buffered file reads.
+///
+/// Pages are decoded straight from the mapped bytes, which avoids copying the file into heap
+/// buffers when the same large file is read repeatedly. Rows go through the Parquet record API
+/// with the type rules of [`ingest_parquet_from_path`] (dotted nested names, timestamps as
+/// [`Value::Timestamp`]), so the result matches the standard path. Top-level `INT96` and
+/// nanosecond timestamp columns are decoded from the raw column to keep microseconds; nested
+/// ones keep only the record API's millisecond precision.
+///
+/// Safety caveat: the file must not be truncated or modified by another process while it is
+/// being read. The OS may then deliver `SIGBUS` or expose changed bytes, which this function
+/// cannot detect. Only map files that are written once and then read.
+#[cfg(feature = "mmap")]
+pub fn ingest_parquet_mmap(path: impl AsRef<Path>, schema: &Schema) -> IngestionResult<DataSet> {
+    let file = File::open(path)?;
+    // SAFETY: read-only mapping; the caller guarantees the file is not truncated or rewritten
+    // while mapped (see the doc comment above).
+    let map = unsafe { memmap2::Mmap::map(&file)? };
+    let reader = SerializedFileReader::new(bytes::Bytes::from_owner(map))?;
+    let groups = 0..reader.metadata().num_row_groups();
+    let options = IngestionOptions::default();
+    let rows = fold_records(
+        &reader,
+        schema,
+        groups,
+        &options,
+        Vec::new(),
+        |mut rows, row| {
+            rows.push(row);
+            rows
+        },
+    )?;
+    Ok(DataSet::new(schema.clone(), rows))
+}
+
+/// Fold every row of a Parquet file through the record API, one row at a time.
+pub(crate) fn fold_parquet_path<A>(
+    path: &Path,
+    schema: &Schema,
+    options: &IngestionOptions,
+    init: A,
+    f: impl FnMut(A, Vec<Value>) -> A,
+) -> IngestionResult<A> {
+    let reader = SerializedFileReader::new(File::open(path)?)?;
+    let groups = 0..reader.metadata().num_row_groups();
+    fold_records(&reader, schema, groups, options, init, f)
+}
+
+/// Decode `groups` (in order) through the record API, folding each row into the accumulator.
+fn fold_records<A, R: ChunkReader + 'static>(
+    reader: &SerializedFileReader<R>,
+    schema: &Schema,
+    groups: impl IntoIterator<Item = usize>,
+    options: &IngestionOptions,
+    init: A,
+    mut f: impl FnMut(A, Vec<Value>) -> A,
+) -> IngestionResult<A> {
+    let (projection, paths) = schema_projection(reader, schema)?;
+    let raw_leaves = raw_timestamp_leaves(reader, schema, &paths);
+    let null = ParquetField::Null;
+    let mut acc = init;
+    let mut decoded = 0usize;
+    for group in groups {
+        let row_group = reader.get_row_group(group)?;
+        let raw: Vec<Option<Vec<Option<i64>>>> = raw_leaves
+            .iter()
+            .map(|leaf| {
+                leaf.map(|i| raw_timestamp_micros(row_group.as_ref(), i))
+                    .transpose()
+            })
+            .collect::<IngestionResult<_>>()?;
+        for (group_row, record) in row_group
+            .get_row_iter(Some(projection.clone()))?
+            .enumerate()
+        {
+            let record = record?;
+            decoded += 1;
+            check_row_limit(options, decoded)?;
+            let user_row = decoded;
+            let mut row = Vec::with_capacity(schema.fields.len());
+            for ((field, path), raw) in schema.fields.iter().zip(&paths).zip(&raw) {
+                let value = match raw {
+                    Some(micros) => match micros.get(group_row).copied().flatten() {
+                        Some(us) => Value::Timestamp(us),
+                        None => Value::Null,
+                    },
+                    None => {
+                        let cell = leaf_cell(&record, path).unwrap_or(&null);
+                        cell_to_value(user_row, field, cell, options)?
+                    }
+                };
+                row.push(value);
+            }
+            acc = f(acc, row);
+        }
+    }
+    Ok(acc)
+}
+
+/// Per schema field, the leaf column index of a top-level `INT96` or nanosecond timestamp column
+/// read into a [`DataType::Timestamp`] field. The record API decodes `INT96` as milliseconds and
+/// nanosecond timestamps as plain longs, so these are re-read with [`raw_timestamp_micros`].
+fn raw_timestamp_leaves<R: ChunkReader + 'static>(
+    reader: &SerializedFileReader<R>,
+    schema: &Schema,
+    paths: &[Vec<usize>],
+) -> Vec<Option<usize>> {
+    let columns = reader.metadata().file_metadata().schema_descr().columns();
+    schema
+        .fields
+        .iter()
+        .zip(paths)
+        .map(|(field, path)| {
+            if field.data_type != DataType::Timestamp || path.len() != 1 {
+                return None;
+            }
+            columns.iter().position(|c| {
+                c.path().parts().len() == 1 && c.name() == field.name && is_nanos_timestamp(c)
+            })
+        })
+        .collect()
+}
+
+fn is_nanos_timestamp(column: &ColumnDescriptor) -> bool {
+    column.max_rep_level() == 0
+        && match column.physical_type() {
+            PhysicalType::INT96 => true,
+            PhysicalType::INT64 => matches!(
+                column.logical_type(),
+                Some(LogicalType::Timestamp {
+                    unit: ParquetTimeUnit::NANOS(_),
+                    ..
+                })
+            ),
+            _ => false,
+        }
+}
+
+/// Read one row group of a column picked by [`raw_timestamp_leaves`] as epoch microseconds per
+/// row (`None` for nulls), truncating nanoseconds like the Polars path.
+fn raw_timestamp_micros(
+    row_group: &dyn RowGroupReader,
+    leaf: usize,
+) -> IngestionResult<Vec<Option<i64>>> {
+    let rows = usize::try_from(row_group.metadata().num_rows()).unwrap_or(0);
+    let max_def = row_group
+        .metadata()
+        .column(leaf)
+        .column_descr()
+        .max_def_level();
+    let mut def_levels = Vec::with_capacity(rows);
+    let levels = (max_def > 0).then_some(&mut def_levels);
+    let nanos: Vec<i64> = match row_group.get_column_reader(leaf)? {
+        ColumnReader::Int96ColumnReader(mut r) => {
+            let mut values: Vec<Int96> = Vec::with_capacity(rows);
+            r.read_records(rows, levels, None, &mut values)?;
+            values.iter().map(Int96::to_nanos).collect()
+        }
+        ColumnReader::Int64ColumnReader(mut r) => {
+            let mut values = Vec::with_capacity(rows);
+            r.read_records(rows, levels, None, &mut values)?;
+            values
+        }
+        _ => unreachable!("raw_timestamp_leaves only picks INT96/INT64 columns"),
+    };
+    let mut values = nanos.into_iter().map(|ns| ns.div_euclid(1_000));
+    if max_def == 0 {
+        return Ok(values.map(Some).collect());
+    }
+    Ok(def_levels
+        .iter()
+        .map(|&d| if d == max_def { values.next() } else { None })
+        .collect())
+}
+
+/// Type-check the first `sample_rows` rows of a Parquet file against `schema`, collecting every
+/// cell error instead of stopping at the first. Returns `(checked_rows, issues)`.
+///
+/// A missing column is reported as a single issue and no rows are checked.
+pub(crate) fn validate_parquet_sample(
+    path: &Path,
+    schema: &Schema,
+    options: &IngestionOptions,
+    sample_rows: usize,
+) -> IngestionResult<(usize, Vec<IngestionError>)> {
+    let reader = SerializedFileReader::new(File::open(path)?)?;
+    let (projection, paths) = match schema_projection(&reader, schema) {
+        Ok(p) => p,
+        Err(e) => return Ok((0, vec![e])),
+    };
+    // Raw timestamp columns always decode; their record cells need not type-check.
+    let raw_leaves = raw_timestamp_leaves(&reader, schema, &paths);
+    let null = ParquetField::Null;
+    let mut issues = Vec::new();
+    let mut checked = 0usize;
+    for record in reader.get_row_iter(Some(projection))?.take(sample_rows) {
+        let record = match record {
+            Ok(r) => r,
+            Err(e) => {
+                issues.push(e.into());
+                break;
+            }
+        };
+        checked += 1;
+        for ((field, path), raw) in schema.fields.iter().zip(&paths).zip(&raw_leaves) {
+            if raw.is_some() {
+                continue;
+            }
+            let cell = leaf_cell(&record, path).unwrap_or(&null);
+            if let Err(e) = cell_to_value(checked, field, cell, options) {
+                issues.push(e);
+            }
+        }
+    }
+    Ok((checked, issues))
+}
+
+/// Project to the top-level columns the schema needs and return, per schema field, the index
+/// path to its cell in a projected record: the projected column index, then one child index per
+/// nesting level for dotted names like `address.city`.
+fn schema_projection<R: ChunkReader + 'static>(
+    reader: &SerializedFileReader<R>,
+    schema: &Schema,
+) -> IngestionResult<(ParquetType, Vec<Vec<usize>>)> {
+    let file_fields = reader
+        .metadata()
+        .file_metadata()
+        .schema_descr()
+        .root_schema()
+        .get_fields();
+    let mut projected: Vec<std::sync::Arc<ParquetType>> = Vec::new();
+    let mut paths = Vec::with_capacity(schema.fields.len());
+    for field in &schema.fields {
+        // An exact top-level match wins over a dot-path lookup.
+        let (column, rest) = match file_fields.iter().find(|t| t.name() == field.name) {
+            Some(column) => (column, None),
+            None => {
+                let (root, rest) = field
+                    .name
+                    .split_once('.')
+                    .ok_or_else(|| missing_column(&field.name))?;
+                let column = file_fields
+                    .iter()
+                    .find(|t| t.name() == root)
+                    .ok_or_else(|| missing_column(&field.name))?;
+                (column, Some(rest))
+            }
+        };
+        let root_idx = match projected.iter().position(|t| t.name() == column.name()) {
+            Some(i) => i,
+            None => {
+                projected.push(column.clone());
+                projected.len() - 1
+            }
+        };
+        let mut path = vec![root_idx];
+        let mut ty: &ParquetType = column;
+        for seg in rest.into_iter().flat_map(|r| r.split('.')) {
+            let child = ty
+                .is_group()
+                .then(|| ty.get_fields().iter().position(|t| t.name() == seg))
+                .flatten()
+                .ok_or_else(|| missing_column(&field.name))?;
+            path.push(child);
+            ty = &ty.get_fields()[child];
+        }
+        paths.push(path);
+    }
+    let projection = ParquetType::group_type_builder("schema")
+        .with_fields(projected)
+        .build()?;
+    Ok((projection, paths))
+}
+
+/// Walk `path` (see [`schema_projection`]) through nested groups; `None` when a group on the way
+/// is null.
+fn leaf_cell<'a>(record: &'a parquet::record::Row, path: &[usize]) -> Option<&'a ParquetField> {
+    let (&first, rest) = path.split_first()?;
+    let mut cell = record.get_column_iter().nth(first)?.1;
+    for &i in rest {
+        match cell {
+            ParquetField::Group(group) => cell = group.get_column_iter().nth(i)?.1,
+            _ => return None,
+        }
+    }
+    Some(cell)
+}
+
+/// Convert one record cell, applying enum domains and field validation.
+fn cell_to_value(
+    row: usize,
+    field: &crate::types::Field,
+    cell: &ParquetField,
+    options: &IngestionOptions,
+) -> IngestionResult<Value> {
+    let value = match record_field_to_value(row, field, cell)? {
+        Value::Float64(v) => Value::Float64(round_float(v, options)),
+        other => other,
+    };
+    let value = enum_value(&field.data_type, row, &field.name, value, options)?;
+    validate_value(field, row, &field.name, &value)?;
+    Ok(value)
+}
+
+fn record_field_to_value(
+    row: usize,
+    field: &crate::types::Field,
+    cell: &ParquetField,
+) -> IngestionResult<Value> {
+    let int = match cell {
+        ParquetField::Byte(v) => Some(i64::from(*v)),
+        ParquetField::Short(v) => Some(i64::from(*v)),
+        ParquetField::Int(v) => Some(i64::from(*v)),
+        ParquetField::Long(v) => Some(*v),
+        ParquetField::UByte(v) => Some(i64::from(*v)),
+        ParquetField::UShort(v) => Some(i64::from(*v)),
+        ParquetField::UInt(v) => Some(i64::from(*v)),
+        ParquetField::ULong(v) => i64::try_from(*v).ok(),
+        _ => None,
+    };
+    // The record API decodes INT96 as milliseconds; only nested INT96 columns reach this point
+    // (see `raw_timestamp_leaves`), and they lose sub-millisecond digits.
+    let timestamp = match cell {
+        ParquetField::TimestampMillis(v) => v.checked_mul(1_000),
+        ParquetField::TimestampMicros(v) => Some(*v),
+        _ => None,
+    };
+    let value = match (&field.data_type, cell) {
+        (_, ParquetField::Null) => Some(Value::Null),
+        (DataType::Int64, _) => int.map(Value::Int64),
+        (DataType::Float64, ParquetField::Double(v)) => Some(Value::Float64(*v)),
+        (DataType::Float64, ParquetField::Float(v)) => Some(Value::Float64(f64::from(*v))),
+        (DataType::Float64, _) => int.map(|v| Value::Float64(v as f64)),
+        (DataType::Bool, ParquetField::Bool(v)) => Some(Value::Bool(*v)),
+        (DataType::Utf8 | DataType::Enum(_), ParquetField::Str(s)) => Some(Value::Utf8(s.clone())),
+        (DataType::Bytes, ParquetField::Bytes(b)) => Some(Value::Bytes(b.data().to_vec())),
+        (DataType::Bytes, ParquetField::Str(s)) => Some(Value::Bytes(s.as_bytes().to_vec())),
+        (DataType::Timestamp, _) => timestamp.map(Value::Timestamp),
+        _ => None,
+    };
+    value.ok_or_else(|| IngestionError::ParseError {
+        row,
+        column: field.name.clone(),
+        raw: cell.to_string(),
+        message: "parquet column type mismatch".to_string(),
+    })
 }
 
 fn validate_parquet_column_types(df: &DataFrame, schema: &Schema) -> IngestionResult<()> {
@@ -64,8 +564,10 @@ fn dtype_compatible_with_schema(
     use polars::datatypes::DataType as P;
 
     match schema_dtype {
-        DataType::Utf8 => matches!(polars_dtype, P::String),
+        DataType::Utf8 | DataType::Enum(_) => matches!(polars_dtype, P::String),
+        DataType::Bytes => matches!(polars_dtype, P::Binary | P::BinaryOffset | P::String),
         DataType::Bool => matches!(polars_dtype, P::Boolean),
+        DataType::Timestamp => matches!(polars_dtype, P::Datetime(_, _)),
         DataType::Int64 => matches!(
             polars_dtype,
             P::Int8 | P::Int16 | P::Int32 | P::Int64 | P::UInt8 | P::UInt16 | P::UInt32 | P::UInt64
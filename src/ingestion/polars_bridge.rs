@@ -3,6 +3,8 @@ use polars::prelude::*;
 use crate::error::{IngestionError, IngestionResult};
 use crate::types::{DataSet, DataType, Schema, Value};
 
+use super::common::{Progress, validate_value};
+
 pub(crate) fn polars_error_to_ingestion(action: &str, err: PolarsError) -> IngestionError {
     match err {
         PolarsError::IO { error, .. } => {
@@ -35,6 +37,7 @@ pub(crate) fn infer_schema_from_dataframe(df: &DataFrame) -> IngestionResult<Sch
             P::Float32 | P::Float64 => DataType::Float64,
             P::Boolean => DataType::Bool,
             P::String => DataType::Utf8,
+            P::Datetime(_, _) => DataType::Timestamp,
             other => {
                 return Err(IngestionError::SchemaMismatch {
                     message: format!("unsupported polars dtype for output schema: {other}"),
@@ -49,7 +52,7 @@ pub(crate) fn infer_schema_from_dataframe(df: &DataFrame) -> IngestionResult<Sch
 /// Infer an output schema from a Polars [`DataFrame`], using a **lossy** mapping into our limited
 /// logical type system.
 ///
-/// - Supported mappings: Int64/Float64/Boolean/String
+/// - Supported mappings: Int64/Float64/Boolean/String/Datetime
 /// - Other Polars dtypes are mapped to `DataType::Utf8` (stringified during conversion)
 pub(crate) fn infer_schema_from_dataframe_lossy(df: &DataFrame) -> IngestionResult<Schema> {
     use polars::datatypes::DataType as P;
@@ -69,6 +72,7 @@ pub(crate) fn infer_schema_from_dataframe_lossy(df: &DataFrame) -> IngestionResu
             P::Float32 | P::Float64 => DataType::Float64,
             P::Boolean => DataType::Bool,
             P::String => DataType::Utf8,
+            P::Datetime(_, _) => DataType::Timestamp,
             _ => DataType::Utf8,
         };
         fields.push(crate::types::Field::new(s.name().to_string(), dt));
@@ -139,7 +143,7 @@ pub(crate) fn dataset_to_dataframe(ds: &DataSet) -> IngestionResult<DataFrame> {
                 }
                 cols.push(Series::new((&field.name).into(), v).into());
             }
-            DataType::Utf8 => {
+            DataType::Utf8 | DataType::Enum(_) => {
                 let mut v: Vec<Option<String>> = Vec::with_capacity(nrows);
                 for (row_idx0, row) in ds.rows.iter().enumerate() {
                     match row.get(col_idx) {
@@ -157,6 +161,50 @@ pub(crate) fn dataset_to_dataframe(ds: &DataSet) -> IngestionResult<DataFrame> {
                 }
                 cols.push(Series::new((&field.name).into(), v).into());
             }
+            DataType::Bytes => {
+                let mut v: Vec<Option<&[u8]>> = Vec::with_capacity(nrows);
+                for (row_idx0, row) in ds.rows.iter().enumerate() {
+                    match row.get(col_idx) {
+                        Some(Value::Null) | None => v.push(None),
+                        Some(Value::Bytes(x)) => v.push(Some(x.as_slice())),
+                        Some(other) => {
+                            return Err(IngestionError::ParseError {
+                                row: row_idx0 + 1,
+                                column: field.name.clone(),
+                                raw: format!("{other:?}"),
+                                message: "value does not match schema type Bytes".to_string(),
+                            });
+                        }
+                    }
+                }
+                cols.push(Series::new((&field.name).into(), v).into());
+            }
+            DataType::Timestamp => {
+                let mut v: Vec<Option<i64>> = Vec::with_capacity(nrows);
+                for (row_idx0, row) in ds.rows.iter().enumerate() {
+                    match row.get(col_idx) {
+                        Some(Value::Null) | None => v.push(None),
+                        Some(Value::Timestamp(x)) => v.push(Some(*x)),
+                        Some(other) => {
+                            return Err(IngestionError::ParseError {
+                                row: row_idx0 + 1,
+                                column: field.name.clone(),
+                                raw: format!("{other:?}"),
+                                message: "value does not match schema type Timestamp".to_string(),
+                            });
+                        }
+                    }
+                }
+                let micros = Series::new((&field.name).into(), v)
+                    .cast(&polars::datatypes::DataType::Datetime(
+                        TimeUnit::Microseconds,
+                        None,
+                    ))
+                    .map_err(|e| {
+                        polars_error_to_ingestion("failed to build timestamp column", e)
+                    })?;
+                cols.push(micros.into());
+            }
         }
     }
 
@@ -169,6 +217,7 @@ pub(crate) fn dataframe_to_dataset(
     schema: &Schema,
     missing_kind: &'static str,
     user_row_start: usize,
+    progress: Option<&Progress>,
 ) -> IngestionResult<DataSet> {
     for field in &schema.fields {
         if df.column(&field.name).is_err() {
@@ -192,7 +241,15 @@ pub(crate) fn dataframe_to_dataset(
             DataType::Int64 => polars::datatypes::DataType::Int64,
             DataType::Float64 => polars::datatypes::DataType::Float64,
             DataType::Bool => polars::datatypes::DataType::Boolean,
-            DataType::Utf8 => polars::datatypes::DataType::String,
+            DataType::Utf8 | DataType::Enum(_) => polars::datatypes::DataType::String,
+            DataType::Bytes => polars::datatypes::DataType::Binary,
+            // Keep any time zone: the physical values are UTC either way.
+            DataType::Timestamp => match s.dtype() {
+                polars::datatypes::DataType::Datetime(_, tz) => {
+                    polars::datatypes::DataType::Datetime(TimeUnit::Microseconds, tz.clone())
+                }
+                _ => polars::datatypes::DataType::Datetime(TimeUnit::Microseconds, None),
+            },
         };
 
         let casted = s.cast(&target).map_err(|e| IngestionError::ParseError {
@@ -223,8 +280,16 @@ pub(crate) fn dataframe_to_dataset(
                 (DataType::Int64, AnyValue::Int64(v)) => Value::Int64(v),
                 (DataType::Float64, AnyValue::Float64(v)) => Value::Float64(v),
                 (DataType::Bool, AnyValue::Boolean(v)) => Value::Bool(v),
-                (DataType::Utf8, AnyValue::String(v)) => Value::Utf8(v.to_string()),
-                (DataType::Utf8, AnyValue::StringOwned(v)) => Value::Utf8(v.to_string()),
+                (DataType::Utf8 | DataType::Enum(_), AnyValue::String(v)) => {
+                    Value::Utf8(v.to_string())
+                }
+                (DataType::Utf8 | DataType::Enum(_), AnyValue::StringOwned(v)) => {
+                    Value::Utf8(v.to_string())
+                }
+                (DataType::Bytes, AnyValue::Binary(v)) => Value::Bytes(v.to_vec()),
+                (DataType::Bytes, AnyValue::BinaryOwned(v)) => Value::Bytes(v),
+                (DataType::Timestamp, AnyValue::Datetime(v, _, _)) => Value::Timestamp(v),
+                (DataType::Timestamp, AnyValue::DatetimeOwned(v, _, _)) => Value::Timestamp(v),
                 (dt, other) => {
                     return Err(IngestionError::ParseError {
                         row: user_row,
@@ -234,9 +299,13 @@ pub(crate) fn dataframe_to_dataset(
                     });
                 }
             };
+            validate_value(field, user_row, &field.name, &v)?;
             out.push(v);
         }
         out_rows.push(out);
+        if let Some(p) = progress {
+            p.rows_done(out_rows.len());
+        }
     }
 
     Ok(DataSet::new(schema.clone(), out_rows))
@@ -8,14 +8,16 @@
 //! - If an [`super::observability::IngestionObserver`] is provided, success/failure/alerts are
 //!   reported to it.
 
+use std::collections::HashMap;
 use std::error::Error as StdError;
 use std::fmt;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use crate::error::{IngestionError, IngestionResult};
-use crate::types::{DataSet, Schema};
+use crate::types::{DataSet, Schema, Value};
 
+use super::builder::IngestionOptionsBuilder;
 use super::observability::{
     IngestionContext, IngestionObserver, IngestionSeverity, IngestionStats,
 };
@@ -68,6 +70,133 @@ impl Default for ExcelSheetSelection {
     }
 }
 
+/// How non-finite Float64 values (`NaN`, `inf`, `-inf`) are handled during ingestion.
+///
+/// Non-finite floats flow straight into [`crate::processing::reduce`]: a single `NaN` turns
+/// `Sum`/`Mean`/`Variance` into `NaN`, and an infinity dominates `Sum`/`Min`/`Max`. Use
+/// [`FloatPolicy::NullifyNonFinite`] to have them ignored like other nulls, or
+/// [`FloatPolicy::RejectNonFinite`] to fail fast.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FloatPolicy {
+    /// Keep non-finite values as-is (default; preserves previous behavior).
+    #[default]
+    Allow,
+    /// Fail with [`IngestionError::ParseError`] on the first non-finite value.
+    RejectNonFinite,
+    /// Replace non-finite values with `Value::Null`.
+    NullifyNonFinite,
+}
+
+/// Separators used when parsing Int64/Float64 text cells (CSV, and string cells in Excel).
+///
+/// The default (`'.'` decimal, no thousands separator) matches Rust's own number parsing. For
+/// European-style input such as `1.234,56`, use `decimal_sep: ','` and `thousands_sep:
+/// Some('.')`. Thousands separators are removed wherever they appear (grouping is not checked).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumericLocale {
+    /// Decimal separator (default `'.'`).
+    pub decimal_sep: char,
+    /// Optional thousands separator, stripped before parsing (default `None`).
+    pub thousands_sep: Option<char>,
+}
+
+impl Default for NumericLocale {
+    fn default() -> Self {
+        Self {
+            decimal_sep: '.',
+            thousands_sep: None,
+        }
+    }
+}
+
+impl NumericLocale {
+    /// Returns [`IngestionError::SchemaMismatch`] if both separators are the same character.
+    pub fn validate(&self) -> IngestionResult<()> {
+        if self.thousands_sep == Some(self.decimal_sep) {
+            return Err(IngestionError::SchemaMismatch {
+                message: format!(
+                    "numeric_locale is ambiguous: decimal and thousands separators are both '{}'",
+                    self.decimal_sep
+                ),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Custom text parser for one [`crate::types::DataType`] (see [`ParserRegistry`]).
+pub type TypeParser = Arc<dyn Fn(&str) -> Result<Value, String> + Send + Sync>;
+
+/// Custom parsers for CSV cells and Excel text cells, keyed by [`crate::types::DataType`].
+///
+/// A registered parser replaces the built-in parse step for its type: it receives the raw,
+/// untrimmed cell text (after any [`crate::types::Field::pre_transform`]) and returns the
+/// value, or an error message reported as [`IngestionError::ParseError`]. Empty cells become
+/// `Value::Null` before any parser runs, as with the built-ins (in CSV, whitespace-only cells
+/// count as empty unless `trim_strings` is off for Utf8 or the type is Bytes).
+/// Enum membership and field validators still run on the result. Types without a parser use
+/// the built-ins, so the default (empty) registry changes nothing.
+#[derive(Clone, Default)]
+pub struct ParserRegistry {
+    parsers: HashMap<crate::types::DataType, TypeParser>,
+}
+
+impl ParserRegistry {
+    /// An empty registry (built-in parsing for every type).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use `parser` for cells of `data_type`, replacing any parser registered before.
+    pub fn register<F>(&mut self, data_type: crate::types::DataType, parser: F)
+    where
+        F: Fn(&str) -> Result<Value, String> + Send + Sync + 'static,
+    {
+        self.parsers.insert(data_type, Arc::new(parser));
+    }
+
+    /// Builder-style [`Self::register`].
+    pub fn with_parser<F>(mut self, data_type: crate::types::DataType, parser: F) -> Self
+    where
+        F: Fn(&str) -> Result<Value, String> + Send + Sync + 'static,
+    {
+        self.register(data_type, parser);
+        self
+    }
+
+    /// The custom parser for `data_type`, if any.
+    pub fn get(&self, data_type: &crate::types::DataType) -> Option<&TypeParser> {
+        self.parsers.get(data_type)
+    }
+
+    /// `true` when no custom parser is registered.
+    pub fn is_empty(&self) -> bool {
+        self.parsers.is_empty()
+    }
+}
+
+impl fmt::Debug for ParserRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.parsers.keys()).finish()
+    }
+}
+
+/// Character encoding of CSV input read from a path.
+///
+/// Non-UTF-8 encodings need the `encoding` Cargo feature (decoding uses `encoding_rs`);
+/// without it, ingestion fails with [`IngestionError::SchemaMismatch`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CsvEncoding {
+    /// UTF-8 (default); invalid bytes are an error.
+    #[default]
+    Utf8,
+    /// ISO-8859-1: every byte maps to the code point of the same value.
+    Latin1,
+    /// Windows-1252, the usual "ANSI" codepage of legacy Windows exports (differs from Latin-1
+    /// in `0x80..=0x9F`, e.g. `0x80` is `€`).
+    Windows1252,
+}
+
 /// Options controlling unified ingestion behavior.
 ///
 /// Use [`Default`] for common cases.
@@ -81,6 +210,86 @@ pub struct IngestionOptions {
     pub observer: Option<Arc<dyn IngestionObserver>>,
     /// Severity threshold at which `on_alert` is invoked.
     pub alert_at_or_above: IngestionSeverity,
+    /// Match schema field names against CSV/Excel headers ignoring ASCII case (default `false`).
+    ///
+    /// Missing columns are still reported as [`IngestionError::SchemaMismatch`].
+    pub case_insensitive_headers: bool,
+    /// Alternative CSV/Excel header names, mapping source header -> schema field name (default
+    /// empty).
+    ///
+    /// A field is looked up by its own name first; only when no header matches it exactly does a
+    /// header listed here as an alias for it count (so an exact match always wins). Alias keys
+    /// follow `case_insensitive_headers` like field names do. The ingested column keeps the
+    /// schema field name.
+    pub aliases: HashMap<String, String>,
+    /// Trim leading/trailing whitespace from CSV Utf8 cells (default `true`).
+    ///
+    /// When `false`, Utf8 cells keep surrounding whitespace and only truly empty cells become
+    /// `Value::Null`. Int64/Float64/Bool cells are always trimmed before parsing.
+    pub trim_strings: bool,
+    /// Handling of `NaN` / infinities in Float64 columns for CSV, JSON, and Excel (default
+    /// [`FloatPolicy::Allow`]).
+    pub float_policy: FloatPolicy,
+    /// Match [`crate::types::DataType::Enum`] cells against the allowed values ignoring ASCII
+    /// case (default `false`); matches are stored with the schema's spelling.
+    pub case_insensitive_enums: bool,
+    /// Call [`IngestionObserver::on_progress`] every this many converted rows (default `None`,
+    /// i.e. never; `Some(0)` is treated the same). Requires [`Self::observer`].
+    pub progress_every: Option<usize>,
+    /// Spellings accepted as `true` for CSV/Excel Bool cells, matched ignoring ASCII case
+    /// (default empty = `true/t/1/yes/y`). A non-empty list replaces the defaults.
+    pub bool_true_tokens: Vec<String>,
+    /// Spellings accepted as `false` for CSV/Excel Bool cells, matched ignoring ASCII case
+    /// (default empty = `false/f/0/no/n`). A non-empty list replaces the defaults.
+    pub bool_false_tokens: Vec<String>,
+    /// Custom per-type parsers for CSV cells and Excel text cells (default empty = built-ins).
+    ///
+    /// See [`ParserRegistry`]. Overrides `bool_*_tokens`, `numeric_locale`, `trim_strings`, and
+    /// `float_policy` for the registered types.
+    pub parsers: ParserRegistry,
+    /// Decimal/thousands separators for CSV and Excel text numbers (default `'.'`, none).
+    ///
+    /// Ingestion fails with [`IngestionError::SchemaMismatch`] if the separators are equal.
+    pub numeric_locale: NumericLocale,
+    /// Encoding of CSV files read from a path (default [`CsvEncoding::Utf8`]).
+    ///
+    /// Other encodings decode the whole file into UTF-8 in memory before parsing (also in
+    /// [`fold_rows`]; `ingest_csv_since_with_options` decodes only the new records). Reader-based CSV entrypoints ignore this option.
+    pub encoding: CsvEncoding,
+    /// Reject CSV rows whose field count differs from the header (default `false`).
+    ///
+    /// When `false`, missing trailing fields read as `Value::Null`, like empty cells. When
+    /// `true`, such a row fails with [`IngestionError::ParseError`] naming the row and the
+    /// first missing (or first extra) column.
+    pub strict_row_width: bool,
+    /// Skip CSV records whose fields are all empty, like `,,` (default `true`).
+    ///
+    /// Skipped records are not rows: they do not count toward `max_rows` and do not advance
+    /// the row numbers in errors. They are dropped before the `strict_row_width` check, so a
+    /// blank record never fails it. Lines with no characters at all are always skipped by the
+    /// parser, whatever this setting.
+    pub skip_blank_lines: bool,
+    /// Fail with [`IngestionError::RowLimitExceeded`] if the input has more than this many rows
+    /// (default `None`, no limit).
+    ///
+    /// A safety valve against unexpectedly large inputs, not a way to preview: nothing is
+    /// returned once the cap is passed (slice the result, or use
+    /// [`DataSet::to_pretty_string`], to look at the first rows). CSV and Excel stop at the
+    /// first row past the cap, Parquet checks the row count in the footer before decoding, and
+    /// JSON checks after parsing the document. Also applies to [`fold_rows`].
+    pub max_rows: Option<usize>,
+    /// Accept inputs with no data rows (default `true`, returning an empty [`DataSet`]).
+    ///
+    /// When `false`, a zero-row result (e.g. a header-only CSV, an empty JSON array, a Parquet
+    /// file without rows, or sheets with only a header) fails with
+    /// [`IngestionError::EmptyInput`] instead. Applies to every format; not to [`fold_rows`].
+    pub allow_empty: bool,
+    /// Round Float64 cells to this many decimal places at ingest time (default `None`).
+    ///
+    /// Applies to CSV, JSON, Excel, and Parquet, e.g. for reproducible dataset hashes. Rounding
+    /// is lossy (half away from zero, in binary floating point) and happens before anything else
+    /// sees the data, so later `Sum`/`Min`/`Max`/`Mean` work on the rounded values.
+    pub round_floats: Option<u32>,
 }
 
 impl fmt::Debug for IngestionOptions {
@@ -90,6 +299,22 @@ impl fmt::Debug for IngestionOptions {
             .field("excel_sheet_selection", &self.excel_sheet_selection)
             .field("observer_set", &self.observer.is_some())
             .field("alert_at_or_above", &self.alert_at_or_above)
+            .field("case_insensitive_headers", &self.case_insensitive_headers)
+            .field("aliases", &self.aliases)
+            .field("trim_strings", &self.trim_strings)
+            .field("float_policy", &self.float_policy)
+            .field("case_insensitive_enums", &self.case_insensitive_enums)
+            .field("progress_every", &self.progress_every)
+            .field("bool_true_tokens", &self.bool_true_tokens)
+            .field("bool_false_tokens", &self.bool_false_tokens)
+            .field("parsers", &self.parsers)
+            .field("numeric_locale", &self.numeric_locale)
+            .field("encoding", &self.encoding)
+            .field("strict_row_width", &self.strict_row_width)
+            .field("skip_blank_lines", &self.skip_blank_lines)
+            .field("max_rows", &self.max_rows)
+            .field("allow_empty", &self.allow_empty)
+            .field("round_floats", &self.round_floats)
             .finish()
     }
 }
@@ -101,10 +326,33 @@ impl Default for IngestionOptions {
             excel_sheet_selection: ExcelSheetSelection::default(),
             observer: None,
             alert_at_or_above: IngestionSeverity::Critical,
+            case_insensitive_headers: false,
+            aliases: HashMap::new(),
+            trim_strings: true,
+            float_policy: FloatPolicy::default(),
+            case_insensitive_enums: false,
+            progress_every: None,
+            bool_true_tokens: Vec::new(),
+            bool_false_tokens: Vec::new(),
+            parsers: ParserRegistry::default(),
+            numeric_locale: NumericLocale::default(),
+            encoding: CsvEncoding::default(),
+            strict_row_width: false,
+            skip_blank_lines: true,
+            max_rows: None,
+            allow_empty: true,
+            round_floats: None,
         }
     }
 }
 
+impl IngestionOptions {
+    /// Start an [`IngestionOptionsBuilder`] from the default options.
+    pub fn builder() -> IngestionOptionsBuilder {
+        IngestionOptionsBuilder::new()
+    }
+}
+
 /// Unified ingestion entry point for path-based sources.
 ///
 /// - If `options.format` is `None`, format is inferred from the file extension.
@@ -253,26 +501,39 @@ pub fn ingest_from_path(
     schema: &Schema,
     options: &IngestionOptions,
 ) -> IngestionResult<DataSet> {
+    ingest_from_path_detailed(path, schema, options).map(|(ds, _)| ds)
+}
+
+/// Like [`ingest_from_path`], but also returns the format that was used (either
+/// [`IngestionOptions::format`] or the one inferred from the extension).
+///
+/// Handy for logging or for format-specific post-processing. Observer callbacks are identical.
+pub fn ingest_from_path_detailed(
+    path: impl AsRef<Path>,
+    schema: &Schema,
+    options: &IngestionOptions,
+) -> IngestionResult<(DataSet, IngestionFormat)> {
     let path = path.as_ref();
     let fmt = match options.format {
         Some(f) => f,
         None => infer_format_from_path(path)?,
     };
 
-    let ctx = IngestionContext {
-        path: path.to_path_buf(),
-        format: fmt,
-    };
-
+    let mut sheet = None;
     let result = match fmt {
-        IngestionFormat::Csv => csv::ingest_csv_from_path(path, schema),
-        IngestionFormat::Json => json::ingest_json_from_path(path, schema),
-        IngestionFormat::Parquet => parquet::ingest_parquet_from_path(path, schema),
-        IngestionFormat::Excel => {
-            ingest_excel_dispatch(path, schema, &options.excel_sheet_selection)
+        IngestionFormat::Csv => csv::ingest_csv_from_path_with_options(path, schema, options),
+        IngestionFormat::Json => json::ingest_json_from_path_with_options(path, schema, options),
+        IngestionFormat::Parquet => {
+            parquet::ingest_parquet_from_path_with_options(path, schema, options)
         }
+        IngestionFormat::Excel => ingest_excel_dispatch(path, schema, options, &mut sheet),
     };
 
+    let ctx = IngestionContext {
+        path: path.to_path_buf(),
+        format: fmt,
+        sheet: if result.is_err() { sheet } else { None },
+    };
     if let Some(obs) = options.observer.as_ref() {
         match &result {
             Ok(ds) => obs.on_success(
@@ -291,7 +552,145 @@ pub fn ingest_from_path(
         }
     }
 
-    result
+    result.map(|ds| (ds, fmt))
+}
+
+/// Rows type-checked by [`validate`] when no explicit sample size is given.
+pub const DEFAULT_VALIDATE_SAMPLE_ROWS: usize = 1_000;
+
+/// Outcome of a dry-run [`validate`] (distinct from [`crate::validation::ValidationReport`],
+/// which checks an already-ingested dataset).
+#[derive(Debug)]
+pub struct ValidationReport {
+    /// `true` when no issues were found.
+    pub ok: bool,
+    /// Data rows that were type-checked.
+    pub checked_rows: usize,
+    /// Every problem found: a missing column, or one error per bad cell in the sample.
+    pub issues: Vec<IngestionError>,
+}
+
+/// Dry-run ingestion: check that `schema` fits the file without building a [`DataSet`].
+///
+/// Same as [`validate_with_sample_rows`] with [`DEFAULT_VALIDATE_SAMPLE_ROWS`].
+pub fn validate(
+    path: impl AsRef<Path>,
+    schema: &Schema,
+    options: &IngestionOptions,
+) -> IngestionResult<ValidationReport> {
+    validate_with_sample_rows(path, schema, options, DEFAULT_VALIDATE_SAMPLE_ROWS)
+}
+
+/// Dry-run ingestion over the first `sample_rows` data rows.
+///
+/// CSV and Parquet check that every schema column is present and then type-parse the sample
+/// with the same rules as ingestion (enum domains, field validators), collecting every bad cell
+/// rather than stopping at the first. JSON and Excel have no sampled reader yet: the whole file
+/// is ingested and the first error, if any, is the only issue. Observer callbacks are not
+/// invoked.
+///
+/// Problems with the data are returned in [`ValidationReport::issues`]; `Err` is reserved for
+/// failures that prevent validation at all (e.g. the file cannot be opened, unknown format).
+pub fn validate_with_sample_rows(
+    path: impl AsRef<Path>,
+    schema: &Schema,
+    options: &IngestionOptions,
+    sample_rows: usize,
+) -> IngestionResult<ValidationReport> {
+    let path = path.as_ref();
+    let fmt = match options.format {
+        Some(f) => f,
+        None => infer_format_from_path(path)?,
+    };
+    let (checked_rows, issues) = match fmt {
+        IngestionFormat::Csv => csv::validate_csv_sample(path, schema, options, sample_rows)?,
+        IngestionFormat::Parquet => {
+            parquet::validate_parquet_sample(path, schema, options, sample_rows)?
+        }
+        IngestionFormat::Json | IngestionFormat::Excel => {
+            let options = IngestionOptions {
+                observer: None,
+                ..options.clone()
+            };
+            match ingest_from_path(path, schema, &options) {
+                Ok(ds) => (ds.row_count(), Vec::new()),
+                Err(e @ IngestionError::Io(_)) => return Err(e),
+                Err(e) => (0, vec![e]),
+            }
+        }
+    };
+    Ok(ValidationReport {
+        ok: issues.is_empty(),
+        checked_rows,
+        issues,
+    })
+}
+
+/// Stream the rows of a file through `f`, starting from `init`, without building a [`DataSet`].
+///
+/// CSV and Parquet are decoded record by record, so only the current row (plus the reader's own
+/// buffers) is held in memory; use this for sums or counts over files too large to ingest. CSV
+/// always uses the record parser and honors the same options as
+/// [`csv::ingest_csv_from_path_with_options`]; Parquet goes through the record API and honors
+/// `case_insensitive_enums`. JSON and Excel have no row-streaming reader yet, so they are
+/// ingested in full and then folded.
+///
+/// The first parse or validation error aborts the fold and is returned, as with
+/// [`ingest_from_path`]. Observer callbacks are not invoked.
+pub fn fold_rows<A>(
+    path: impl AsRef<Path>,
+    schema: &Schema,
+    options: &IngestionOptions,
+    init: A,
+    mut f: impl FnMut(A, &[Value]) -> A,
+) -> IngestionResult<A> {
+    let path = path.as_ref();
+    let fmt = match options.format {
+        Some(f) => f,
+        None => infer_format_from_path(path)?,
+    };
+    match fmt {
+        IngestionFormat::Csv => {
+            csv::fold_csv_path(path, schema, options, init, |acc, row| f(acc, &row))
+        }
+        IngestionFormat::Parquet => {
+            parquet::fold_parquet_path(path, schema, options, init, |acc, row| f(acc, &row))
+        }
+        IngestionFormat::Json | IngestionFormat::Excel => {
+            let ds = ingest_from_path(path, schema, options)?;
+            Ok(ds.rows.iter().fold(init, |acc, row| f(acc, row)))
+        }
+    }
+}
+
+/// Async wrapper over [`ingest_from_path`] for tokio-based callers (feature `tokio`).
+///
+/// The arguments are cloned up front and the synchronous ingestion runs on tokio's blocking
+/// thread pool via `spawn_blocking`, so the calling runtime thread is never blocked. This is not
+/// async I/O: parsing still occupies a blocking-pool thread for the whole call. Observer
+/// callbacks fire exactly as in [`ingest_from_path`], from that blocking thread.
+///
+/// Must be awaited inside a tokio runtime. A panic during ingestion is resumed on the awaiting
+/// task; if the runtime shuts down first, an [`IngestionError::Io`] is returned.
+#[cfg(feature = "tokio")]
+pub fn ingest_from_path_async(
+    path: impl AsRef<Path>,
+    schema: &Schema,
+    options: &IngestionOptions,
+) -> impl std::future::Future<Output = IngestionResult<DataSet>> + Send + 'static {
+    let path = path.as_ref().to_path_buf();
+    let schema = schema.clone();
+    let options = options.clone();
+    async move {
+        match tokio::task::spawn_blocking(move || ingest_from_path(&path, &schema, &options)).await
+        {
+            Ok(result) => result,
+            Err(e) if e.is_panic() => std::panic::resume_unwind(e.into_panic()),
+            Err(e) => Err(IngestionError::Io(std::io::Error::other(format!(
+                "ingestion task did not complete: {e}"
+            )))),
+        }
+    }
 }
 
 /// Infer a [`Schema`] for an input file.
@@ -302,6 +701,7 @@ pub fn ingest_from_path(
 /// Notes:
 /// - For JSON, nested fields are inferred only at the **top level** (no dot-path expansion).
 /// - For Excel, inference uses a scan-based heuristic.
+/// - On success, [`IngestionObserver::on_schema_inferred`] is called on `options.observer`.
 pub fn infer_schema_from_path(
     path: impl AsRef<Path>,
     options: &IngestionOptions,
@@ -312,6 +712,23 @@ pub fn infer_schema_from_path(
         None => infer_format_from_path(path)?,
     };
 
+    let schema = infer_schema_for_format(path, fmt, options)?;
+    if let Some(obs) = options.observer.as_ref() {
+        let ctx = IngestionContext {
+            path: path.to_path_buf(),
+            format: fmt,
+            sheet: None,
+        };
+        obs.on_schema_inferred(&ctx, &schema);
+    }
+    Ok(schema)
+}
+
+fn infer_schema_for_format(
+    path: &Path,
+    fmt: IngestionFormat,
+    options: &IngestionOptions,
+) -> IngestionResult<Schema> {
     match fmt {
         IngestionFormat::Csv => {
             let df = LazyCsvReader::new(path.to_string_lossy().as_ref().into())
@@ -389,6 +806,8 @@ fn severity_for_error(e: &IngestionError) -> IngestionSeverity {
         }
         IngestionError::SchemaMismatch { .. } => IngestionSeverity::Error,
         IngestionError::ParseError { .. } => IngestionSeverity::Error,
+        IngestionError::RowLimitExceeded { .. } => IngestionSeverity::Error,
+        IngestionError::EmptyInput => IngestionSeverity::Error,
     }
 }
 
@@ -421,24 +840,25 @@ fn infer_format_from_path(path: &Path) -> IngestionResult<IngestionFormat> {
     })
 }
 
+/// `current_sheet` is left holding the sheet being read, for the failure context.
 fn ingest_excel_dispatch(
     path: &Path,
     schema: &Schema,
-    sel: &ExcelSheetSelection,
+    options: &IngestionOptions,
+    current_sheet: &mut Option<String>,
 ) -> IngestionResult<DataSet> {
-    match sel {
-        ExcelSheetSelection::First => excel::ingest_excel_from_path(path, None, schema),
-        ExcelSheetSelection::Sheet(name) => {
-            excel::ingest_excel_from_path(path, Some(name.as_str()), schema)
-        }
-        ExcelSheetSelection::AllSheets => {
-            excel::ingest_excel_workbook_from_path(path, None, schema)
-        }
-        ExcelSheetSelection::Sheets(names) => {
-            let refs: Vec<&str> = names.iter().map(|s| s.as_str()).collect();
-            excel::ingest_excel_workbook_from_path(path, Some(refs.as_slice()), schema)
-        }
-    }
+    let refs: Option<Vec<&str>> = match &options.excel_sheet_selection {
+        ExcelSheetSelection::First | ExcelSheetSelection::AllSheets => None,
+        ExcelSheetSelection::Sheet(name) => Some(vec![name.as_str()]),
+        ExcelSheetSelection::Sheets(names) => Some(names.iter().map(|s| s.as_str()).collect()),
+    };
+    excel::ingest_excel_workbook_tracking_sheet(
+        path,
+        refs.as_deref(),
+        schema,
+        options,
+        current_sheet,
+    )
 }
 
 fn infer_excel_schema_dispatch(path: &Path, sel: &ExcelSheetSelection) -> IngestionResult<Schema> {
@@ -135,6 +135,8 @@
 //!     num_threads: Some(4),
 //!     chunk_size: 1_024,
 //!     max_in_flight_chunks: 4,
+//!     pool: None,
+//!     track_chunk_output: false,
 //! });
 //!
 //! let active_idx = ds.schema.index_of("active").unwrap();
@@ -279,6 +281,8 @@
 //!   use a numerically stable one-pass (Welford) accumulation; mean / sum-of-squares / L2 norm are
 //!   returned as [`types::Value::Float64`]. Sample variance / std dev require at least two values.
 //! - [`processing::ReduceOp::CountDistinctNonNull`]: distinct non-null values (also for UTF-8 and bool).
+//! - [`processing::ReduceOp::First`], [`processing::ReduceOp::Last`]: first / last non-null value in
+//!   row order, for any column type.
 //! - [`pipeline::DataFrame::reduce`] provides the Polars-backed equivalent for whole-frame scalars.
 //! - [`processing::feature_wise_mean_std`]: one scan, mean + std for several numeric columns; [`pipeline::DataFrame::feature_wise_mean_std`] for Polars.
 //! - [`processing::arg_max_row`], [`processing::arg_min_row`], [`processing::top_k_by_frequency`]: row extrema and label top‑k.
@@ -286,6 +290,7 @@
 pub mod cdc;
 pub mod error;
 pub mod execution;
+pub mod export;
 pub mod ingestion;
 pub mod outliers;
 pub mod pipeline;
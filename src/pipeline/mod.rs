@@ -193,6 +193,8 @@ impl DataFrame {
                 Value::Float64(x) => col(&column).eq(lit(x)),
                 Value::Bool(x) => col(&column).eq(lit(x)),
                 Value::Utf8(s) => col(&column).eq(lit(s)),
+                Value::Bytes(b) => col(&column).eq(lit(b)),
+                Value::Timestamp(t) => col(&column).eq(timestamp_lit(t)),
             },
             Predicate::NotNull { column } => col(&column).is_not_null(),
             Predicate::ModEqInt64 {
@@ -374,7 +376,7 @@ impl DataFrame {
             .collect()
             .map_err(|e| polars_error_to_ingestion("failed to collect polars lazy plan", e))?;
         let out_schema = infer_schema_from_dataframe(&df)?;
-        dataframe_to_dataset(&df, &out_schema, "column", 1)
+        dataframe_to_dataset(&df, &out_schema, "column", 1, None)
     }
 
     /// Collect the pipeline into an in-memory [`DataSet`], enforcing an explicit output schema.
@@ -383,7 +385,7 @@ impl DataFrame {
             .lf
             .collect()
             .map_err(|e| polars_error_to_ingestion("failed to collect polars lazy plan", e))?;
-        dataframe_to_dataset(&df, schema, "column", 1)
+        dataframe_to_dataset(&df, schema, "column", 1, None)
     }
 
     /// Reduce a column using a built-in [`ReduceOp`] (Polars-backed).
@@ -544,6 +546,8 @@ fn polars_reduce_expr(column: &str, op: ReduceOp) -> Expr {
         ReduceOp::SumSquares => c.clone().strict_cast(P::Float64).pow(lit(2.0)).sum(),
         ReduceOp::L2Norm => c.clone().strict_cast(P::Float64).pow(lit(2.0)).sum().sqrt(),
         ReduceOp::CountDistinctNonNull => c.drop_nulls().n_unique(),
+        ReduceOp::First => c.drop_nulls().first(),
+        ReduceOp::Last => c.drop_nulls().last(),
     }
 }
 
@@ -610,10 +614,16 @@ fn to_polars_dtype(dt: &DataType) -> polars::datatypes::DataType {
         DataType::Int64 => polars::datatypes::DataType::Int64,
         DataType::Float64 => polars::datatypes::DataType::Float64,
         DataType::Bool => polars::datatypes::DataType::Boolean,
-        DataType::Utf8 => polars::datatypes::DataType::String,
+        DataType::Utf8 | DataType::Enum(_) => polars::datatypes::DataType::String,
+        DataType::Bytes => polars::datatypes::DataType::Binary,
+        DataType::Timestamp => polars::datatypes::DataType::Datetime(TimeUnit::Microseconds, None),
     }
 }
 
+fn timestamp_lit(micros: i64) -> Expr {
+    lit(micros).cast(to_polars_dtype(&DataType::Timestamp))
+}
+
 fn value_to_lit_expr(value: Value) -> IngestionResult<Expr> {
     match value {
         Value::Null => Err(IngestionError::SchemaMismatch {
@@ -623,6 +633,8 @@ fn value_to_lit_expr(value: Value) -> IngestionResult<Expr> {
         Value::Float64(v) => Ok(lit(v)),
         Value::Bool(v) => Ok(lit(v)),
         Value::Utf8(v) => Ok(lit(v)),
+        Value::Bytes(v) => Ok(lit(v)),
+        Value::Timestamp(v) => Ok(timestamp_lit(v)),
     }
 }
 
@@ -641,6 +653,13 @@ fn anyvalue_to_value(av: AnyValue) -> Value {
         AnyValue::Boolean(v) => Value::Bool(v),
         AnyValue::String(v) => Value::Utf8(v.to_string()),
         AnyValue::StringOwned(v) => Value::Utf8(v.to_string()),
+        AnyValue::Datetime(v, unit, _) | AnyValue::DatetimeOwned(v, unit, _) => {
+            Value::Timestamp(match unit {
+                TimeUnit::Nanoseconds => v.div_euclid(1_000),
+                TimeUnit::Microseconds => v,
+                TimeUnit::Milliseconds => v.saturating_mul(1_000),
+            })
+        }
         other => Value::Utf8(other.to_string()),
     }
 }
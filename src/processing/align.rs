@@ -0,0 +1,115 @@
+//! Schema alignment for [`crate::types::DataSet`]s with different but compatible columns.
+
+use crate::error::{IngestionError, IngestionResult};
+use crate::types::{DataSet, Schema, Value};
+
+/// Returns a new [`DataSet`] with `target`'s schema: existing columns are reordered to match
+/// it and columns missing from `dataset` are filled with `Value::Null`.
+///
+/// Columns of `dataset` that are not in `target` are dropped when `drop_extra` is `true`;
+/// otherwise they are an error. Returns [`IngestionError::SchemaMismatch`] for such an extra
+/// column or for a shared column whose type differs from `target`. Aligning several datasets
+/// to their [`Schema::union`] lets them be concatenated.
+pub fn align_to_schema(
+    dataset: &DataSet,
+    target: &Schema,
+    drop_extra: bool,
+) -> IngestionResult<DataSet> {
+    if !drop_extra {
+        if let Some(extra) = dataset
+            .schema
+            .fields
+            .iter()
+            .find(|f| target.index_of(&f.name).is_none())
+        {
+            return Err(IngestionError::SchemaMismatch {
+                message: format!("column '{}' is not in the target schema", extra.name),
+            });
+        }
+    }
+
+    let mut sources = Vec::with_capacity(target.fields.len());
+    for field in &target.fields {
+        let idx = dataset.schema.index_of(&field.name);
+        if let Some(i) = idx {
+            let actual = &dataset.schema.fields[i].data_type;
+            if *actual != field.data_type {
+                return Err(IngestionError::SchemaMismatch {
+                    message: format!(
+                        "field '{}' type differs: {:?} vs {:?}",
+                        field.name, actual, field.data_type
+                    ),
+                });
+            }
+        }
+        sources.push(idx);
+    }
+
+    let rows = dataset
+        .rows
+        .iter()
+        .map(|row| {
+            sources
+                .iter()
+                .map(|src| src.and_then(|i| row.get(i).cloned()).unwrap_or(Value::Null))
+                .collect()
+        })
+        .collect();
+    Ok(DataSet::new(target.clone(), rows))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::align_to_schema;
+    use crate::types::{DataSet, DataType, Schema, Value};
+
+    #[test]
+    fn align_reorders_and_fills_missing_columns_with_null() {
+        let schema = Schema::builder().utf8("name").int64("id").build().unwrap();
+        let ds = DataSet::new(
+            schema,
+            vec![
+                vec![Value::Utf8("a".to_string()), Value::Int64(1)],
+                vec![Value::Null, Value::Int64(2)],
+            ],
+        );
+        let target = Schema::builder()
+            .int64("id")
+            .float64("score")
+            .utf8("name")
+            .build()
+            .unwrap();
+
+        let out = align_to_schema(&ds, &target, false).unwrap();
+        assert_eq!(out.schema, target);
+        assert_eq!(
+            out.rows,
+            vec![
+                vec![Value::Int64(1), Value::Null, Value::Utf8("a".to_string())],
+                vec![Value::Int64(2), Value::Null, Value::Null],
+            ]
+        );
+    }
+
+    #[test]
+    fn align_rejects_or_drops_extra_columns_and_rejects_type_changes() {
+        let ds = DataSet::new(
+            Schema::builder().int64("id").utf8("extra").build().unwrap(),
+            vec![vec![Value::Int64(1), Value::Utf8("x".to_string())]],
+        );
+        let target = Schema::builder().int64("id").build().unwrap();
+
+        let err = align_to_schema(&ds, &target, false).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("column 'extra' is not in the target schema")
+        );
+        let out = align_to_schema(&ds, &target, true).unwrap();
+        assert_eq!(out.rows, vec![vec![Value::Int64(1)]]);
+
+        let retyped = Schema::builder().float64("id").build().unwrap();
+        let err = align_to_schema(&ds, &retyped, true).unwrap_err();
+        assert!(err.to_string().contains("field 'id' type differs"));
+        assert_eq!(out.schema.fields[0].data_type, DataType::Int64);
+    }
+}
@@ -0,0 +1,128 @@
+//! Post-ingestion column type conversion for [`crate::types::DataSet`].
+
+use crate::error::{IngestionError, IngestionResult};
+use crate::ingestion::IngestionOptions;
+use crate::ingestion::common::enum_value;
+use crate::ingestion::csv::parse_typed_value;
+use crate::types::{DataSet, DataType, Value};
+
+/// Returns a new [`DataSet`] with column `name` converted to `to`.
+///
+/// Cells are re-parsed with the same rules as CSV ingestion: Utf8 cells are parsed as text
+/// (`"42"` → `Int64(42)`), Int64 widens to Float64 exactly, and other values are converted
+/// through their text form (so `Float64(1.5)` → Int64 fails). Nulls stay null; cells already of
+/// the target type are kept as-is.
+///
+/// Returns [`IngestionError::SchemaMismatch`] if the column does not exist, or a
+/// [`IngestionError::ParseError`] (1-based row) for the first cell that does not convert.
+pub fn cast_column(dataset: &DataSet, name: &str, to: DataType) -> IngestionResult<DataSet> {
+    let idx = dataset
+        .schema
+        .index_of(name)
+        .ok_or_else(|| IngestionError::SchemaMismatch {
+            message: format!("unknown column '{name}'"),
+        })?;
+
+    let options = IngestionOptions::default();
+    let mut rows = dataset.rows.clone();
+    for (row_idx0, row) in rows.iter_mut().enumerate() {
+        let Some(cell) = row.get_mut(idx) else {
+            continue;
+        };
+        let value = std::mem::replace(cell, Value::Null);
+        *cell = cast_value(row_idx0 + 1, name, value, &to, &options)?;
+    }
+
+    let mut schema = dataset.schema.clone();
+    schema.fields[idx].data_type = to;
+    Ok(DataSet::new(schema, rows))
+}
+
+fn cast_value(
+    row: usize,
+    column: &str,
+    value: Value,
+    to: &DataType,
+    options: &IngestionOptions,
+) -> IngestionResult<Value> {
+    let raw = match (value, to) {
+        (Value::Null, _) => return Ok(Value::Null),
+        (Value::Int64(v), DataType::Int64) => return Ok(Value::Int64(v)),
+        (Value::Int64(v), DataType::Float64) => return Ok(Value::Float64(v as f64)),
+        (Value::Float64(v), DataType::Float64) => return Ok(Value::Float64(v)),
+        (Value::Bool(v), DataType::Bool) => return Ok(Value::Bool(v)),
+        (Value::Utf8(s), DataType::Utf8) => return Ok(Value::Utf8(s)),
+        (Value::Bytes(v), DataType::Bytes) => return Ok(Value::Bytes(v)),
+        (Value::Timestamp(v), DataType::Timestamp) => return Ok(Value::Timestamp(v)),
+        (Value::Utf8(s), _) => s,
+        (Value::Int64(v), _) => v.to_string(),
+        (Value::Float64(v), _) => v.to_string(),
+        (Value::Bool(v), _) => v.to_string(),
+        (v @ Value::Timestamp(_), _) => v.to_string(),
+        (Value::Bytes(v), _) => String::from_utf8(v).map_err(|e| IngestionError::ParseError {
+            row,
+            column: column.to_owned(),
+            raw: format!("{:?}", e.as_bytes()),
+            message: "bytes are not valid UTF-8".to_string(),
+        })?,
+    };
+    let parsed = parse_typed_value(row, column, to, &raw, options)?;
+    enum_value(to, row, column, parsed, options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cast_column;
+    use crate::error::IngestionError;
+    use crate::types::{DataSet, DataType, Field, Schema, Value};
+
+    fn sample_dataset() -> DataSet {
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int64),
+            Field::new("qty", DataType::Utf8),
+        ]);
+        let rows = vec![
+            vec![Value::Int64(1), Value::Utf8("10".to_string())],
+            vec![Value::Int64(2), Value::Null],
+            vec![Value::Int64(3), Value::Utf8(" -7 ".to_string())],
+        ];
+        DataSet::new(schema, rows)
+    }
+
+    #[test]
+    fn cast_utf8_to_int64_parses_cells_and_keeps_nulls() {
+        let out = cast_column(&sample_dataset(), "qty", DataType::Int64).unwrap();
+        assert_eq!(out.schema.fields[1].data_type, DataType::Int64);
+        assert_eq!(
+            out.column_i64("qty").unwrap(),
+            vec![Some(10), None, Some(-7)]
+        );
+    }
+
+    #[test]
+    fn cast_int64_to_float64_widens() {
+        let out = cast_column(&sample_dataset(), "id", DataType::Float64).unwrap();
+        assert_eq!(out.schema.fields[0].data_type, DataType::Float64);
+        assert_eq!(out.rows[2][0], Value::Float64(3.0));
+    }
+
+    #[test]
+    fn cast_reports_unparseable_cell_with_row_number() {
+        let mut ds = sample_dataset();
+        ds.rows[1][1] = Value::Utf8("ten".to_string());
+        match cast_column(&ds, "qty", DataType::Int64) {
+            Err(IngestionError::ParseError {
+                row, column, raw, ..
+            }) => {
+                assert_eq!(row, 2);
+                assert_eq!(column, "qty");
+                assert_eq!(raw, "ten");
+            }
+            other => panic!("expected ParseError, got {other:?}"),
+        }
+        assert!(matches!(
+            cast_column(&ds, "missing", DataType::Int64),
+            Err(IngestionError::SchemaMismatch { .. })
+        ));
+    }
+}
@@ -0,0 +1,236 @@
+//! Running (cumulative) totals and trailing-window aggregates over [`crate::types::DataSet`]
+//! rows.
+
+use std::collections::{HashMap, VecDeque};
+
+use crate::error::{IngestionError, IngestionResult};
+use crate::types::{DataSet, DataType, Field, Value};
+
+use super::multi::group_key_part;
+
+/// Append a `<column>_cumsum` column holding the running total of `column` in row order.
+///
+/// Nulls contribute `0` (the total carries over; the output cell is the total so far, never
+/// null). With `partition_by`, a separate total is kept per distinct value of that column
+/// (null keys form their own partition); rows stay in input order. The output type matches the
+/// input: Int64 or Float64.
+///
+/// Returns [`IngestionError::SchemaMismatch`] if a column is missing, `column` is not numeric,
+/// or `<column>_cumsum` already exists, and a [`IngestionError::ParseError`] (1-based row) if an
+/// Int64 total overflows.
+pub fn cumulative_sum(
+    dataset: &DataSet,
+    column: &str,
+    partition_by: Option<&str>,
+) -> IngestionResult<DataSet> {
+    let lookup = |name: &str| {
+        dataset
+            .schema
+            .index_of(name)
+            .ok_or_else(|| IngestionError::SchemaMismatch {
+                message: format!("unknown column '{name}'"),
+            })
+    };
+    let idx = lookup(column)?;
+    let part_idx = partition_by.map(lookup).transpose()?;
+
+    let data_type = dataset.schema.fields[idx].data_type.clone();
+    if !matches!(data_type, DataType::Int64 | DataType::Float64) {
+        return Err(IngestionError::SchemaMismatch {
+            message: format!(
+                "cumulative_sum requires a numeric column, '{column}' is {data_type:?}"
+            ),
+        });
+    }
+    let out_name = format!("{column}_cumsum");
+    if dataset.schema.index_of(&out_name).is_some() {
+        return Err(IngestionError::SchemaMismatch {
+            message: format!("column '{out_name}' already exists"),
+        });
+    }
+
+    let mut totals: HashMap<String, Value> = HashMap::new();
+    let mut rows = Vec::with_capacity(dataset.row_count());
+    for (row_idx0, row) in dataset.rows.iter().enumerate() {
+        let key = part_idx.map_or_else(String::new, |p| group_key_part(row.get(p)));
+        let total = totals.entry(key).or_insert_with(|| match data_type {
+            DataType::Int64 => Value::Int64(0),
+            _ => Value::Float64(0.0),
+        });
+        match (&mut *total, row.get(idx)) {
+            (Value::Int64(t), Some(Value::Int64(v))) => {
+                *t = t
+                    .checked_add(*v)
+                    .ok_or_else(|| IngestionError::ParseError {
+                        row: row_idx0 + 1,
+                        column: column.to_string(),
+                        raw: v.to_string(),
+                        message: "cumulative sum overflows Int64".to_string(),
+                    })?;
+            }
+            (Value::Float64(t), Some(Value::Float64(v))) => *t += v,
+            _ => {}
+        }
+        let mut out = row.clone();
+        out.push(total.clone());
+        rows.push(out);
+    }
+
+    let mut schema = dataset.schema.clone();
+    schema.fields.push(Field::new(out_name, data_type));
+    Ok(DataSet::new(schema, rows))
+}
+
+/// Append a `<column>_rollmean` Float64 column holding the mean of the last `window` non-null
+/// values of `column` seen so far (the current row included), in row order.
+///
+/// Nulls are skipped rather than counted as values, so a window spans the last `window`
+/// non-null cells however many nulls sit between them; a null row repeats the mean of the
+/// window before it. Until `window` values have been seen the mean covers the ones available,
+/// and rows before the first non-null value are null. `window == 1` copies the column as
+/// Float64.
+///
+/// Returns [`IngestionError::SchemaMismatch`] if the column is missing or not numeric,
+/// `<column>_rollmean` already exists, or `window` is 0.
+pub fn rolling_mean(dataset: &DataSet, column: &str, window: usize) -> IngestionResult<DataSet> {
+    let idx = dataset
+        .schema
+        .index_of(column)
+        .ok_or_else(|| IngestionError::SchemaMismatch {
+            message: format!("unknown column '{column}'"),
+        })?;
+    let data_type = &dataset.schema.fields[idx].data_type;
+    if !matches!(data_type, DataType::Int64 | DataType::Float64) {
+        return Err(IngestionError::SchemaMismatch {
+            message: format!("rolling_mean requires a numeric column, '{column}' is {data_type:?}"),
+        });
+    }
+    if window == 0 {
+        return Err(IngestionError::SchemaMismatch {
+            message: "rolling_mean window must be > 0".to_string(),
+        });
+    }
+    let out_name = format!("{column}_rollmean");
+    if dataset.schema.index_of(&out_name).is_some() {
+        return Err(IngestionError::SchemaMismatch {
+            message: format!("column '{out_name}' already exists"),
+        });
+    }
+
+    let mut recent: VecDeque<f64> = VecDeque::with_capacity(window);
+    let mut rows = Vec::with_capacity(dataset.row_count());
+    for row in &dataset.rows {
+        let x = match row.get(idx) {
+            Some(Value::Int64(v)) => Some(*v as f64),
+            Some(Value::Float64(v)) => Some(*v),
+            _ => None,
+        };
+        if let Some(x) = x {
+            if recent.len() == window {
+                recent.pop_front();
+            }
+            recent.push_back(x);
+        }
+        let mut out = row.clone();
+        out.push(if recent.is_empty() {
+            Value::Null
+        } else {
+            Value::Float64(recent.iter().sum::<f64>() / recent.len() as f64)
+        });
+        rows.push(out);
+    }
+
+    let mut schema = dataset.schema.clone();
+    schema.fields.push(Field::new(out_name, DataType::Float64));
+    Ok(DataSet::new(schema, rows))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{cumulative_sum, rolling_mean};
+    use crate::types::{DataSet, DataType, Field, Schema, Value};
+
+    fn sample_dataset() -> DataSet {
+        let schema = Schema::new(vec![
+            Field::new("region", DataType::Utf8),
+            Field::new("amount", DataType::Int64),
+        ]);
+        let rows = [
+            ("east", Some(5)),
+            ("west", Some(1)),
+            ("east", None),
+            ("west", Some(2)),
+            ("east", Some(3)),
+        ]
+        .into_iter()
+        .map(|(r, a)| {
+            vec![
+                Value::Utf8(r.to_string()),
+                a.map(Value::Int64).unwrap_or(Value::Null),
+            ]
+        })
+        .collect();
+        DataSet::new(schema, rows)
+    }
+
+    #[test]
+    fn cumulative_sum_runs_in_row_order_and_skips_nulls() {
+        let out = cumulative_sum(&sample_dataset(), "amount", None).unwrap();
+        assert_eq!(out.schema.fields[2].name, "amount_cumsum");
+        assert_eq!(out.schema.fields[2].data_type, DataType::Int64);
+        assert_eq!(
+            out.column_i64("amount_cumsum").unwrap(),
+            vec![Some(5), Some(6), Some(6), Some(8), Some(11)]
+        );
+        assert!(cumulative_sum(&out, "amount", None).is_err());
+        assert!(cumulative_sum(&out, "region", None).is_err());
+    }
+
+    #[test]
+    fn cumulative_sum_resets_per_partition() {
+        let out = cumulative_sum(&sample_dataset(), "amount", Some("region")).unwrap();
+        assert_eq!(
+            out.column_i64("amount_cumsum").unwrap(),
+            vec![Some(5), Some(1), Some(5), Some(3), Some(8)]
+        );
+        assert!(cumulative_sum(&sample_dataset(), "amount", Some("missing")).is_err());
+    }
+
+    fn rollmeans(ds: &DataSet) -> Vec<Option<f64>> {
+        ds.column_f64("amount_rollmean").unwrap()
+    }
+
+    #[test]
+    fn rolling_mean_with_window_one_is_identity() {
+        let out = rolling_mean(&sample_dataset(), "amount", 1).unwrap();
+        assert_eq!(out.schema.fields[2].name, "amount_rollmean");
+        assert_eq!(out.schema.fields[2].data_type, DataType::Float64);
+        // The null row repeats the previous value.
+        assert_eq!(
+            rollmeans(&out),
+            vec![Some(5.0), Some(1.0), Some(1.0), Some(2.0), Some(3.0)]
+        );
+        assert!(rolling_mean(&sample_dataset(), "amount", 0).is_err());
+        assert!(rolling_mean(&sample_dataset(), "region", 1).is_err());
+    }
+
+    #[test]
+    fn rolling_mean_averages_the_last_window_non_null_values() {
+        let mut ds = sample_dataset();
+        ds.rows
+            .insert(0, vec![Value::Utf8("east".to_string()), Value::Null]);
+        let out = rolling_mean(&ds, "amount", 3).unwrap();
+        // Values seen: 5, 1, (null), 2, 3.
+        assert_eq!(
+            rollmeans(&out),
+            vec![
+                None,
+                Some(5.0),
+                Some(3.0),
+                Some(3.0),
+                Some(8.0 / 3.0),
+                Some(2.0)
+            ]
+        );
+    }
+}
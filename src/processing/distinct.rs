@@ -0,0 +1,61 @@
+//! Duplicate-row removal for [`crate::types::DataSet`].
+
+use std::collections::HashSet;
+
+use crate::processing::multi::group_key_part;
+use crate::types::{DataSet, Value};
+
+/// Returns a new [`DataSet`] keeping only the first occurrence of each distinct row.
+///
+/// Rows compare cell by cell; `Value::Null` equals `Value::Null`. Floats are hashed by bit
+/// pattern, so `0.0` and `-0.0` are distinct while NaNs with the same bits are equal. Output
+/// keeps input order.
+pub fn distinct(dataset: &DataSet) -> DataSet {
+    let mut seen = HashSet::new();
+    let rows = dataset
+        .rows
+        .iter()
+        .filter(|row| seen.insert(row_key(row)))
+        .cloned()
+        .collect();
+    DataSet::new(dataset.schema.clone(), rows)
+}
+
+/// Hashable key for a whole row (see [`distinct`] for the float treatment).
+pub(crate) fn row_key(row: &[Value]) -> Vec<String> {
+    row.iter().map(|v| group_key_part(Some(v))).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::distinct;
+    use crate::types::{DataSet, DataType, Field, Schema, Value};
+
+    #[test]
+    fn distinct_keeps_first_occurrence_in_order() {
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int64),
+            Field::new("x", DataType::Float64),
+        ]);
+        let ds = DataSet::new(
+            schema,
+            vec![
+                vec![Value::Int64(2), Value::Float64(0.0)],
+                vec![Value::Int64(1), Value::Null],
+                vec![Value::Int64(2), Value::Float64(0.0)],
+                vec![Value::Int64(2), Value::Float64(-0.0)],
+                vec![Value::Int64(1), Value::Null],
+                vec![Value::Int64(1), Value::Float64(f64::NAN)],
+                vec![Value::Int64(1), Value::Float64(f64::NAN)],
+            ],
+        );
+
+        let out = distinct(&ds);
+        assert_eq!(out.schema, ds.schema);
+        assert_eq!(out.row_count(), 4);
+        assert_eq!(out.rows[0], ds.rows[0]);
+        assert_eq!(out.rows[1], ds.rows[1]);
+        assert_eq!(out.rows[2], ds.rows[3]);
+        assert!(matches!(out.rows[3][1], Value::Float64(x) if x.is_nan()));
+    }
+}
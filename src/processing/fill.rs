@@ -0,0 +1,107 @@
+//! Null replacement for [`crate::types::DataSet`] columns.
+
+use std::collections::HashMap;
+
+use crate::error::{IngestionError, IngestionResult};
+use crate::types::{DataSet, Value};
+
+/// Returns a new [`DataSet`] with every `Value::Null` in column `name` replaced by `fill`.
+///
+/// Returns [`IngestionError::SchemaMismatch`] if the column does not exist or `fill` does not
+/// match the column's [`DataType`](crate::types::DataType) (see [`Value::matches_type`]; a null
+/// fill is rejected).
+pub fn fill_null(dataset: &DataSet, name: &str, fill: Value) -> IngestionResult<DataSet> {
+    let mut fills = HashMap::with_capacity(1);
+    fills.insert(name.to_string(), fill);
+    fill_null_all(dataset, &fills)
+}
+
+/// Like [`fill_null`], for several columns at once (`column name → fill value`).
+///
+/// All fills are validated before any row is copied; columns not in `fills` are unchanged.
+pub fn fill_null_all(
+    dataset: &DataSet,
+    fills: &HashMap<String, Value>,
+) -> IngestionResult<DataSet> {
+    let mut targets: Vec<(usize, &Value)> = Vec::with_capacity(fills.len());
+    for (name, fill) in fills {
+        let idx = dataset
+            .schema
+            .index_of(name)
+            .ok_or_else(|| IngestionError::SchemaMismatch {
+                message: format!("unknown column '{name}'"),
+            })?;
+        let data_type = &dataset.schema.fields[idx].data_type;
+        if matches!(fill, Value::Null) || !fill.matches_type(data_type) {
+            return Err(IngestionError::SchemaMismatch {
+                message: format!(
+                    "fill value {fill:?} does not match column '{name}' of type {data_type:?}"
+                ),
+            });
+        }
+        targets.push((idx, fill));
+    }
+
+    let mut rows = dataset.rows.clone();
+    for row in &mut rows {
+        for &(idx, fill) in &targets {
+            if let Some(cell @ Value::Null) = row.get_mut(idx) {
+                *cell = fill.clone();
+            }
+        }
+    }
+    Ok(DataSet::new(dataset.schema.clone(), rows))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{fill_null, fill_null_all};
+    use crate::error::IngestionError;
+    use crate::types::{DataSet, DataType, Field, Schema, Value};
+
+    fn sample_dataset() -> DataSet {
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int64),
+            Field::new("score", DataType::Float64),
+            Field::new("name", DataType::Utf8),
+        ]);
+        let rows = vec![
+            vec![Value::Int64(1), Value::Null, Value::Null],
+            vec![
+                Value::Int64(2),
+                Value::Float64(2.5),
+                Value::Utf8("b".to_string()),
+            ],
+        ];
+        DataSet::new(schema, rows)
+    }
+
+    #[test]
+    fn fill_null_replaces_only_nulls_in_named_column() {
+        let out = fill_null(&sample_dataset(), "score", Value::Float64(0.0)).unwrap();
+        assert_eq!(out.column_f64("score").unwrap(), vec![Some(0.0), Some(2.5)]);
+        assert_eq!(out.rows[0][2], Value::Null);
+
+        let fills = HashMap::from([
+            ("score".to_string(), Value::Float64(-1.0)),
+            ("name".to_string(), Value::Utf8("unknown".to_string())),
+        ]);
+        let out = fill_null_all(&sample_dataset(), &fills).unwrap();
+        assert_eq!(out.rows[0][1], Value::Float64(-1.0));
+        assert_eq!(out.rows[0][2], Value::Utf8("unknown".to_string()));
+        assert_eq!(out.rows[1][2], Value::Utf8("b".to_string()));
+    }
+
+    #[test]
+    fn fill_null_rejects_mismatched_fill_and_unknown_column() {
+        let ds = sample_dataset();
+        for (name, fill) in [("score", Value::Int64(0)), ("missing", Value::Int64(0))] {
+            assert!(matches!(
+                fill_null(&ds, name, fill),
+                Err(IngestionError::SchemaMismatch { .. })
+            ));
+        }
+    }
+}
@@ -0,0 +1,94 @@
+//! Fused row filtering + mapping for [`crate::types::DataSet`].
+
+use crate::types::{DataSet, Value};
+
+/// Returns a new [`DataSet`] by applying `mapper` to every row and keeping only `Some` results.
+///
+/// This is a convenience wrapper around [`DataSet::filter_map_rows`].
+///
+/// # Panics
+///
+/// Panics if `mapper` returns rows with a different length than the schema field count.
+pub fn filter_map<F>(dataset: &DataSet, mapper: F) -> DataSet
+where
+    F: FnMut(&[Value]) -> Option<Vec<Value>>,
+{
+    dataset.filter_map_rows(mapper)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::filter_map;
+    use crate::processing::{filter, map};
+    use crate::types::{DataSet, DataType, Field, Schema, Value};
+
+    fn sample_dataset() -> DataSet {
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int64),
+            Field::new("active", DataType::Bool),
+            Field::new("name", DataType::Utf8),
+        ]);
+
+        let rows = vec![
+            vec![
+                Value::Int64(1),
+                Value::Bool(true),
+                Value::Utf8("a".to_string()),
+            ],
+            vec![
+                Value::Int64(2),
+                Value::Bool(false),
+                Value::Utf8("b".to_string()),
+            ],
+            vec![
+                Value::Int64(3),
+                Value::Bool(true),
+                Value::Utf8("c".to_string()),
+            ],
+        ];
+
+        DataSet::new(schema, rows)
+    }
+
+    fn upper_name(row: &[Value]) -> Vec<Value> {
+        let mut out = row.to_vec();
+        if let Value::Utf8(s) = &row[2] {
+            out[2] = Value::Utf8(s.to_uppercase());
+        }
+        out
+    }
+
+    #[test]
+    fn filter_map_matches_filter_then_map() {
+        let ds = sample_dataset();
+        let is_active = |row: &[Value]| matches!(row[1], Value::Bool(true));
+
+        let fused = filter_map(&ds, |row| is_active(row).then(|| upper_name(row)));
+        let chained = map(&filter(&ds, is_active), upper_name);
+
+        assert_eq!(fused.schema, ds.schema);
+        assert_eq!(fused, chained);
+        assert_eq!(
+            fused.rows,
+            vec![
+                vec![
+                    Value::Int64(1),
+                    Value::Bool(true),
+                    Value::Utf8("A".to_string())
+                ],
+                vec![
+                    Value::Int64(3),
+                    Value::Bool(true),
+                    Value::Utf8("C".to_string())
+                ],
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "mapped row length")]
+    fn filter_map_rows_panics_if_mapper_returns_wrong_arity() {
+        let ds = sample_dataset();
+        let _ = ds.filter_map_rows(|_row| Some(vec![Value::Int64(1)]));
+    }
+}
@@ -1,5 +1,6 @@
 //! Row mapping for [`crate::types::DataSet`].
 
+use crate::error::{IngestionError, IngestionResult};
 use crate::types::{DataSet, Value};
 
 /// Returns a new [`DataSet`] by applying `mapper` to every row.
@@ -16,9 +17,53 @@ where
     dataset.map_rows(mapper)
 }
 
+/// Returns a new [`DataSet`] by applying `mapper` to every row along with its 0-based index.
+///
+/// This is a convenience wrapper around [`DataSet::map_rows_indexed`].
+///
+/// # Panics
+///
+/// Panics if `mapper` returns rows with a different length than the schema field count.
+pub fn map_indexed<F>(dataset: &DataSet, mapper: F) -> DataSet
+where
+    F: FnMut(usize, &[Value]) -> Vec<Value>,
+{
+    dataset.map_rows_indexed(mapper)
+}
+
+/// Returns a new [`DataSet`] with `f` applied to every cell of column `name`; other cells are
+/// copied unchanged.
+///
+/// The schema is kept as-is, so `f` should return values of the column's type (or
+/// `Value::Null`); use [`crate::processing::cast_column`] to change a column's type.
+///
+/// Returns [`IngestionError::SchemaMismatch`] if `name` is not in the schema.
+pub fn map_column<F>(dataset: &DataSet, name: &str, mut f: F) -> IngestionResult<DataSet>
+where
+    F: FnMut(&Value) -> Value,
+{
+    let idx = dataset
+        .schema
+        .index_of(name)
+        .ok_or_else(|| IngestionError::SchemaMismatch {
+            message: format!("unknown column '{name}'"),
+        })?;
+    let rows = dataset
+        .rows
+        .iter()
+        .map(|row| {
+            row.iter()
+                .enumerate()
+                .map(|(i, v)| if i == idx { f(v) } else { v.clone() })
+                .collect()
+        })
+        .collect();
+    Ok(DataSet::new(dataset.schema.clone(), rows))
+}
+
 #[cfg(test)]
 mod tests {
-    use super::map;
+    use super::{map, map_column, map_indexed};
     use crate::types::{DataSet, DataType, Field, Schema, Value};
 
     fn sample_dataset() -> DataSet {
@@ -97,6 +142,49 @@ mod tests {
         assert_eq!(ds.rows[0][2], Value::Utf8("a".to_string()));
     }
 
+    #[test]
+    fn map_indexed_passes_zero_based_row_index() {
+        let mut ds = sample_dataset();
+        ds.rows.iter_mut().for_each(|row| row[0] = Value::Null);
+        let out = map_indexed(&ds, |idx, row| {
+            let mut row = row.to_vec();
+            row[0] = Value::Int64(idx as i64);
+            row
+        });
+
+        assert_eq!(out.schema, ds.schema);
+        assert_eq!(
+            out.column_i64("id").unwrap(),
+            vec![Some(0), Some(1), Some(2)]
+        );
+        assert_eq!(out.rows[2][2], Value::Utf8("c".to_string()));
+    }
+
+    #[test]
+    #[should_panic(expected = "does not match schema length")]
+    fn map_indexed_rejects_wrong_arity() {
+        let ds = sample_dataset();
+        let _ = ds.map_rows_indexed(|idx, _| vec![Value::Int64(idx as i64)]);
+    }
+
+    #[test]
+    fn map_column_changes_only_the_named_column() {
+        let ds = sample_dataset();
+        let out = map_column(&ds, "id", |v| match v {
+            Value::Int64(x) => Value::Int64(x * 2),
+            other => other.clone(),
+        })
+        .unwrap();
+
+        assert_eq!(out.schema, ds.schema);
+        assert_eq!(
+            out.column_i64("id").unwrap(),
+            vec![Some(2), Some(4), Some(6)]
+        );
+        assert_eq!(out.rows[1][1..], ds.rows[1][1..]);
+        assert!(map_column(&ds, "missing", Value::clone).is_err());
+    }
+
     #[test]
     #[should_panic(expected = "mapped row length")]
     fn map_rows_panics_if_mapper_returns_wrong_arity() {
@@ -7,7 +7,29 @@
 //!
 //! - [`filter()`]: row filtering by predicate
 //! - [`map()`]: row mapping by user function
+//! - [`map_indexed()`]: row mapping that also receives the 0-based row index
+//! - [`map_column()`]: transform one column's cells, copying the rest
+//! - [`filter_map()`]: single-pass filter + map (`None` drops the row)
+//! - [`limit()`], [`skip()`]: offset/limit pagination
+//! - [`distinct()`]: drop duplicate rows, keeping first occurrences in order
+//! - [`sample()`]: seeded, reproducible random row sample
 //! - [`reduce()`]: common reductions (count/sum/min/max/mean/variance/std/sum-squares/L2/count-distinct)
+//! - [`reduce_many()`]: several reductions in one pass over the rows
+//! - [`reduce_grouped()`]: one reduction per distinct key of a group column
+//! - [`reduce_concat()`]: join a string column's values with a separator
+//! - [`summarize_numeric()`]: count, overflow-free sum, min/max, and mean of a numeric column
+//! - [`cast_column()`]: convert a column to another [`crate::types::DataType`] with CSV parsing rules
+//! - [`align_to_schema()`]: reorder columns to a target schema, adding null-filled missing ones
+//! - [`fill_null()`], [`fill_null_all()`]: replace nulls with a type-checked default
+//! - [`sort_by_column()`], [`external_sort()`]: single-column sort in memory, or over a row
+//!   stream with sorted runs spilled to disk
+//! - [`sort_by()`]: multi-column sort with per-column direction
+//! - [`cumulative_sum()`]: running total column, optionally per partition key
+//! - [`rolling_mean()`]: trailing-window mean column over non-null values
+//! - [`unpivot()`], [`pivot()`]: wide-to-long reshape into `variable` / `value` rows, and back
+//! - [`profile()`]: per-column null count, distinct count, and numeric min/max in one pass
+//! - [`Pipeline`]: lazy filter/map chain executed row-by-row in one pass, with a terminal
+//!   collect or reduce
 //! - [`feature_wise_mean_std()`], [`arg_max_row()`], [`arg_min_row()`], [`top_k_by_frequency()`]:
 //!   multi-column stats, arg extrema, and label frequency top‑k
 //!
@@ -49,14 +71,40 @@
 //! assert_eq!(sum, Value::Float64(11.0));
 //! ```
 
+pub mod align;
+pub mod cast;
+pub mod cumulative;
+pub mod distinct;
+pub mod fill;
 pub mod filter;
+pub mod filter_map;
 pub mod map;
 pub mod multi;
+pub mod pipeline;
+pub mod profile;
 pub mod reduce;
+pub mod reshape;
+pub mod sample;
+pub mod slice;
+pub mod sort;
+pub mod summary;
 
+pub use align::align_to_schema;
+pub use cast::cast_column;
+pub use cumulative::{cumulative_sum, rolling_mean};
+pub use distinct::distinct;
+pub use fill::{fill_null, fill_null_all};
 pub use filter::filter;
-pub use map::map;
+pub use filter_map::filter_map;
+pub use map::{map, map_column, map_indexed};
 pub use multi::{
     FeatureMeanStd, arg_max_row, arg_min_row, feature_wise_mean_std, top_k_by_frequency,
 };
-pub use reduce::{ReduceOp, VarianceKind, reduce};
+pub use pipeline::{Pipeline, ReducePipeline};
+pub use profile::{ColumnProfile, profile};
+pub use reduce::{ReduceOp, VarianceKind, reduce, reduce_concat, reduce_grouped, reduce_many};
+pub use reshape::{pivot, unpivot};
+pub use sample::sample;
+pub use slice::{limit, skip};
+pub use sort::{ExternalSort, external_sort, external_sort_with_run_rows, sort_by, sort_by_column};
+pub use summary::{NumericSum, NumericSummary, summarize_numeric};
@@ -68,7 +68,7 @@ pub fn feature_wise_mean_std(
     Some(out)
 }
 
-fn cmp_non_null_values(a: &Value, b: &Value) -> Option<Ordering> {
+pub(crate) fn cmp_non_null_values(a: &Value, b: &Value) -> Option<Ordering> {
     match (a, b) {
         (Value::Int64(x), Value::Int64(y)) => Some(x.cmp(y)),
         (Value::Float64(x), Value::Float64(y)) => Some(x.total_cmp(y)),
@@ -133,6 +133,16 @@ fn freq_bucket_key(v: &Value) -> Option<String> {
         Value::Float64(x) => Some(format!("f:{}", x.to_bits())),
         Value::Bool(b) => Some(format!("b:{b}")),
         Value::Utf8(s) => Some(format!("s:{s}")),
+        Value::Bytes(b) => Some(format!("x:{b:?}")),
+        Value::Timestamp(t) => Some(format!("t:{t}")),
+    }
+}
+
+/// Hashable grouping key for one cell (floats by bit pattern; null/missing is its own key).
+pub(crate) fn group_key_part(v: Option<&Value>) -> String {
+    match v {
+        None | Some(Value::Null) => "n".to_string(),
+        Some(v) => freq_bucket_key(v).expect("non-null"),
     }
 }
 
@@ -143,6 +153,8 @@ fn value_sort_key(v: &Value) -> String {
         Value::Float64(x) => format!("f:{:020}", x.to_bits()),
         Value::Bool(b) => format!("b:{b}"),
         Value::Utf8(s) => format!("s:{s}"),
+        Value::Bytes(b) => format!("x:{b:?}"),
+        Value::Timestamp(t) => format!("t:{t:020}"),
     }
 }
 
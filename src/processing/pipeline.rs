@@ -0,0 +1,280 @@
+//! Lazy, fused filter/map chains over [`crate::types::DataSet`].
+//!
+//! A [`Pipeline`] records filter and map stages and executes them row-by-row in a single pass,
+//! so chaining `filter` → `map` → `reduce` does not allocate an intermediate [`DataSet`] per
+//! step. Stages run in enqueue order for each row; a row dropped by a filter is not seen by any
+//! later stage.
+
+use std::borrow::Cow;
+
+use crate::types::{DataSet, Value};
+
+use super::reduce::{ColumnReducer, ReduceOp};
+
+type FilterFn = dyn Fn(&[Value]) -> bool + Send + Sync;
+type MapFn = dyn Fn(&[Value]) -> Vec<Value> + Send + Sync;
+
+enum Stage {
+    Filter(Box<FilterFn>),
+    Map(Box<MapFn>),
+}
+
+/// A lazily-evaluated chain of filter/map stages.
+///
+/// Build with [`Pipeline::new`], enqueue stages with [`Pipeline::filter`] / [`Pipeline::map`],
+/// then either execute directly with [`Pipeline::run`] (equivalently, after [`Pipeline::collect`])
+/// or finish with a terminal [`Pipeline::reduce`].
+///
+/// Closures are `Fn + Send + Sync` so the same pipeline can also be executed in parallel.
+///
+/// # Panics
+///
+/// Running panics if a mapper returns a row whose length differs from the schema field count
+/// (same contract as [`crate::processing::map`]).
+#[derive(Default)]
+pub struct Pipeline {
+    stages: Vec<Stage>,
+}
+
+impl std::fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let stages: Vec<&str> = self
+            .stages
+            .iter()
+            .map(|s| match s {
+                Stage::Filter(_) => "filter",
+                Stage::Map(_) => "map",
+            })
+            .collect();
+        f.debug_struct("Pipeline").field("stages", &stages).finish()
+    }
+}
+
+impl Pipeline {
+    /// Create an empty pipeline (running it returns a copy of the input).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enqueue a filter stage: rows for which `predicate` returns `false` are dropped.
+    pub fn filter<F>(mut self, predicate: F) -> Self
+    where
+        F: Fn(&[Value]) -> bool + Send + Sync + 'static,
+    {
+        self.stages.push(Stage::Filter(Box::new(predicate)));
+        self
+    }
+
+    /// Enqueue a map stage: each surviving row is replaced by `mapper(row)`.
+    pub fn map<F>(mut self, mapper: F) -> Self
+    where
+        F: Fn(&[Value]) -> Vec<Value> + Send + Sync + 'static,
+    {
+        self.stages.push(Stage::Map(Box::new(mapper)));
+        self
+    }
+
+    /// Terminal stage that materializes the surviving rows; execute with [`Pipeline::run`].
+    pub fn collect(self) -> Self {
+        self
+    }
+
+    /// Terminal stage that reduces `column` of the surviving rows with `op`.
+    pub fn reduce(self, column: &str, op: ReduceOp) -> ReducePipeline {
+        ReducePipeline {
+            pipeline: self,
+            column: column.to_string(),
+            op,
+        }
+    }
+
+    /// Execute all stages and return the surviving rows under the input schema.
+    pub fn run(&self, dataset: &DataSet) -> DataSet {
+        let expected_len = dataset.schema.fields.len();
+        let rows = dataset
+            .rows
+            .iter()
+            .filter_map(|row| self.apply_row(row, expected_len))
+            .map(Cow::into_owned)
+            .collect();
+        DataSet::new(dataset.schema.clone(), rows)
+    }
+
+    /// Run every stage against one row; `None` means a filter dropped it.
+    pub(crate) fn apply_row<'a>(
+        &self,
+        row: &'a [Value],
+        expected_len: usize,
+    ) -> Option<Cow<'a, [Value]>> {
+        let mut cur = Cow::Borrowed(row);
+        for stage in &self.stages {
+            match stage {
+                Stage::Filter(predicate) => {
+                    if !predicate(&cur) {
+                        return None;
+                    }
+                }
+                Stage::Map(mapper) => {
+                    let out = mapper(&cur);
+                    assert!(
+                        out.len() == expected_len,
+                        "mapped row length {} does not match schema length {}",
+                        out.len(),
+                        expected_len
+                    );
+                    cur = Cow::Owned(out);
+                }
+            }
+        }
+        Some(cur)
+    }
+}
+
+/// A [`Pipeline`] finished with a terminal reduce (see [`Pipeline::reduce`]).
+#[derive(Debug)]
+pub struct ReducePipeline {
+    pipeline: Pipeline,
+    column: String,
+    op: ReduceOp,
+}
+
+impl ReducePipeline {
+    /// Execute all stages and reduce the surviving rows.
+    ///
+    /// Follows [`crate::processing::reduce`]: `None` if the column does not exist, rejects the
+    /// op, or an Int64 sum overflows.
+    pub fn run(&self, dataset: &DataSet) -> Option<Value> {
+        let (idx, mut acc) = self.reducer(dataset)?;
+        let expected_len = dataset.schema.fields.len();
+        for row in &dataset.rows {
+            if let Some(out) = self.pipeline.apply_row(row, expected_len) {
+                acc.observe(out.get(idx));
+            }
+        }
+        acc.finish()
+    }
+
+    /// Column being reduced.
+    pub fn column(&self) -> &str {
+        &self.column
+    }
+
+    /// Terminal reduce operation.
+    pub fn op(&self) -> ReduceOp {
+        self.op
+    }
+
+    /// Filter/map stages that run before the reduce.
+    pub fn pipeline(&self) -> &Pipeline {
+        &self.pipeline
+    }
+
+    /// Fresh accumulator for the reduced column, or `None` if it does not exist (or rejects
+    /// the op).
+    pub(crate) fn reducer(&self, dataset: &DataSet) -> Option<(usize, ColumnReducer)> {
+        let idx = dataset.schema.index_of(&self.column)?;
+        let field = dataset.schema.fields.get(idx)?;
+        Some((idx, ColumnReducer::new(&field.data_type, self.op)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Pipeline;
+    use crate::processing::{ReduceOp, filter, map, reduce};
+    use crate::types::{DataSet, DataType, Field, Schema, Value};
+
+    fn sample_dataset() -> DataSet {
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int64),
+            Field::new("active", DataType::Bool),
+            Field::new("score", DataType::Float64),
+        ]);
+        let rows = (1..=10)
+            .map(|i| {
+                vec![
+                    Value::Int64(i),
+                    Value::Bool(i % 2 == 0),
+                    if i == 4 {
+                        Value::Null
+                    } else {
+                        Value::Float64(i as f64)
+                    },
+                ]
+            })
+            .collect();
+        DataSet::new(schema, rows)
+    }
+
+    fn is_active(row: &[Value]) -> bool {
+        matches!(row.get(1), Some(Value::Bool(true)))
+    }
+
+    fn double_score(row: &[Value]) -> Vec<Value> {
+        let mut out = row.to_vec();
+        if let Value::Float64(v) = out[2] {
+            out[2] = Value::Float64(v * 2.0);
+        }
+        out
+    }
+
+    #[test]
+    fn pipeline_collect_matches_filter_then_map() {
+        let ds = sample_dataset();
+        let out = Pipeline::new()
+            .filter(is_active)
+            .map(double_score)
+            .collect()
+            .run(&ds);
+
+        let expected = map(&filter(&ds, is_active), double_score);
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn pipeline_reduce_matches_sequential_reduce() {
+        let ds = sample_dataset();
+        let got = Pipeline::new()
+            .filter(is_active)
+            .map(double_score)
+            .reduce("score", ReduceOp::Sum)
+            .run(&ds);
+
+        let expected = reduce(
+            &map(&filter(&ds, is_active), double_score),
+            "score",
+            ReduceOp::Sum,
+        );
+        assert_eq!(got, expected);
+        assert_eq!(got, Some(Value::Float64(2.0 * (2.0 + 6.0 + 8.0 + 10.0))));
+        assert_eq!(
+            Pipeline::new().reduce("missing", ReduceOp::Count).run(&ds),
+            None
+        );
+    }
+
+    #[test]
+    fn pipeline_stages_run_in_enqueue_order() {
+        let ds = sample_dataset();
+        // Map first makes every row active, so the later filter keeps all rows.
+        let out = Pipeline::new()
+            .map(|row| {
+                let mut out = row.to_vec();
+                out[1] = Value::Bool(true);
+                out
+            })
+            .filter(is_active)
+            .run(&ds);
+        assert_eq!(out.row_count(), 10);
+
+        let out = Pipeline::new()
+            .filter(is_active)
+            .map(|row| {
+                let mut out = row.to_vec();
+                out[1] = Value::Bool(true);
+                out
+            })
+            .run(&ds);
+        assert_eq!(out.row_count(), 5);
+    }
+}
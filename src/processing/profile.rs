@@ -0,0 +1,147 @@
+//! Lightweight per-column profile of an in-memory [`crate::types::DataSet`].
+//!
+//! For quantiles and rendered reports, see the Polars-backed [`crate::profiling`] module.
+
+use crate::types::{DataSet, DataType, Value};
+
+use super::reduce::{ColumnReducer, ReduceOp};
+
+/// Summary of one column, as returned by [`profile`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnProfile {
+    pub name: String,
+    pub data_type: DataType,
+    /// Number of `Value::Null` cells.
+    pub null_count: usize,
+    /// Distinct non-null values (currently an exact count).
+    pub distinct_estimate: usize,
+    /// Minimum for Int64/Float64 columns (see [`ReduceOp::Min`]); `Value::Null` otherwise.
+    pub min: Value,
+    /// Maximum for Int64/Float64 columns (see [`ReduceOp::Max`]); `Value::Null` otherwise.
+    pub max: Value,
+}
+
+/// Profile every column of `dataset` in a single pass over the rows.
+///
+/// Profiles are returned in schema order.
+pub fn profile(dataset: &DataSet) -> Vec<ColumnProfile> {
+    let mut accs: Vec<ColumnAcc> = dataset
+        .schema
+        .fields
+        .iter()
+        .map(|f| ColumnAcc::new(&f.data_type))
+        .collect();
+
+    for row in &dataset.rows {
+        for (idx, acc) in accs.iter_mut().enumerate() {
+            acc.observe(row.get(idx));
+        }
+    }
+
+    dataset
+        .schema
+        .fields
+        .iter()
+        .zip(accs)
+        .map(|(field, acc)| ColumnProfile {
+            name: field.name.clone(),
+            data_type: field.data_type.clone(),
+            null_count: acc.null_count,
+            distinct_estimate: match acc.distinct.finish().unwrap_or(Value::Null) {
+                Value::Int64(n) => n as usize,
+                _ => 0,
+            },
+            min: acc
+                .min
+                .and_then(ColumnReducer::finish)
+                .unwrap_or(Value::Null),
+            max: acc
+                .max
+                .and_then(ColumnReducer::finish)
+                .unwrap_or(Value::Null),
+        })
+        .collect()
+}
+
+struct ColumnAcc {
+    null_count: usize,
+    distinct: ColumnReducer,
+    min: Option<ColumnReducer>,
+    max: Option<ColumnReducer>,
+}
+
+impl ColumnAcc {
+    fn new(data_type: &DataType) -> Self {
+        let numeric = matches!(data_type, DataType::Int64 | DataType::Float64);
+        Self {
+            null_count: 0,
+            distinct: ColumnReducer::new(data_type, ReduceOp::CountDistinctNonNull)
+                .expect("every type supports CountDistinctNonNull"),
+            min: numeric
+                .then(|| ColumnReducer::new(data_type, ReduceOp::Min))
+                .flatten(),
+            max: numeric
+                .then(|| ColumnReducer::new(data_type, ReduceOp::Max))
+                .flatten(),
+        }
+    }
+
+    fn observe(&mut self, v: Option<&Value>) {
+        if matches!(v, Some(Value::Null) | None) {
+            self.null_count += 1;
+            return;
+        }
+        self.distinct.observe(v);
+        for acc in [&mut self.min, &mut self.max].into_iter().flatten() {
+            acc.observe(v);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::profile;
+    use crate::types::{DataSet, DataType, Field, Schema, Value};
+
+    #[test]
+    fn profile_counts_nulls_distinct_and_numeric_extrema() {
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int64),
+            Field::new("score", DataType::Float64),
+            Field::new("label", DataType::Utf8),
+        ]);
+        let rows = vec![
+            vec![
+                Value::Int64(3),
+                Value::Float64(1.5),
+                Value::Utf8("a".to_string()),
+            ],
+            vec![Value::Int64(1), Value::Null, Value::Utf8("b".to_string())],
+            vec![
+                Value::Int64(3),
+                Value::Float64(-2.0),
+                Value::Utf8("a".to_string()),
+            ],
+            vec![Value::Null, Value::Null, Value::Null],
+        ];
+        let ds = DataSet::new(schema, rows);
+
+        let p = profile(&ds);
+        assert_eq!(p.len(), 3);
+        assert_eq!(p[0].name, "id");
+        assert_eq!(p[0].null_count, 1);
+        assert_eq!(p[0].distinct_estimate, 2);
+        assert_eq!(p[0].min, Value::Int64(1));
+        assert_eq!(p[0].max, Value::Int64(3));
+
+        assert_eq!(p[1].null_count, 2);
+        assert_eq!(p[1].min, Value::Float64(-2.0));
+        assert_eq!(p[1].max, Value::Float64(1.5));
+
+        assert_eq!(p[2].data_type, DataType::Utf8);
+        assert_eq!(p[2].null_count, 1);
+        assert_eq!(p[2].distinct_estimate, 2);
+        assert_eq!(p[2].min, Value::Null);
+        assert_eq!(p[2].max, Value::Null);
+    }
+}
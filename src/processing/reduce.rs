@@ -1,9 +1,12 @@
 //! Reduction operations for [`crate::types::DataSet`].
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
+use crate::error::{IngestionError, IngestionResult};
 use crate::types::{DataSet, DataType, Value};
 
+use super::multi::group_key_part;
+
 /// Population vs sample variance / standard deviation (`ddof` 0 vs 1).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum VarianceKind {
@@ -19,10 +22,18 @@ pub enum ReduceOp {
     /// Count all rows (including nulls).
     Count,
     /// Sum numeric values, ignoring nulls.
+    ///
+    /// Int64 sums accumulate in `i128`, so intermediate overflow that cancels out stays exact and
+    /// the result is always [`Value::Int64`]. A final total outside `i64` fails the reduction
+    /// instead of wrapping or changing type ([`reduce`] returns `None`); use
+    /// [`crate::processing::summarize_numeric`] for the full `i128` total.
     Sum,
     /// Minimum numeric value, ignoring nulls.
+    ///
+    /// For Float64, `NaN` is skipped like a null and the remaining values are ordered with
+    /// [`f64::total_cmp`] (so `-0.0 < 0.0`); an all-`NaN` column yields `Value::Null`.
     Min,
-    /// Maximum numeric value, ignoring nulls.
+    /// Maximum numeric value, ignoring nulls (and `NaN` for Float64; see [`ReduceOp::Min`]).
     Max,
     /// Arithmetic mean of numeric values as [`Value::Float64`], ignoring nulls.
     Mean,
@@ -36,6 +47,12 @@ pub enum ReduceOp {
     L2Norm,
     /// Count of distinct non-null values (returns [`Value::Int64`]).
     CountDistinctNonNull,
+    /// First non-null value in row order, for any column type (position-ordered, unlike
+    /// [`ReduceOp::Min`]); `Value::Null` if every value is null.
+    First,
+    /// Last non-null value in row order, for any column type; `Value::Null` if every value is
+    /// null.
+    Last,
 }
 
 /// Reduce a column using a built-in [`ReduceOp`].
@@ -44,30 +61,127 @@ pub enum ReduceOp {
 /// - For `Count`, always returns `Some(Value::Int64(row_count))`.
 /// - For numeric aggregates other than `Count` / `CountDistinctNonNull`, returns
 ///   `Some(Value::Null)` if there are no non-null numeric values, or if the column type is not
-///   numeric (for those ops). `CountDistinctNonNull` supports [`DataType::Bool`] and
-///   [`DataType::Utf8`] as well as numeric types.
+///   numeric (for those ops). `CountDistinctNonNull`, `First`, and `Last` support every column
+///   type.
+/// - [`DataType::Bytes`] columns reject every op except `Count` / `CountDistinctNonNull` /
+///   `First` / `Last` (returns `None`).
+/// - [`DataType::Timestamp`] columns additionally allow `Min` / `Max` (the earliest / latest
+///   instant) and reject the other numeric ops (returns `None`).
+/// - An Int64 `Sum` whose total does not fit in `i64` returns `None` (see [`ReduceOp::Sum`]).
 pub fn reduce(dataset: &DataSet, column: &str, op: ReduceOp) -> Option<Value> {
     let idx = dataset.schema.index_of(column)?;
+    let field = dataset.schema.fields.get(idx)?;
+
+    let mut acc = ColumnReducer::new(&field.data_type, op)?;
+    for row in &dataset.rows {
+        acc.observe(row.get(idx));
+    }
+    acc.finish()
+}
 
-    match op {
-        ReduceOp::Count => Some(Value::Int64(dataset.row_count() as i64)),
-        ReduceOp::CountDistinctNonNull => {
+/// Compute several reductions in a single pass over the rows.
+///
+/// Results align positionally with `ops`; each entry follows the same rules as [`reduce`]
+/// (including `None` for a missing column).
+pub fn reduce_many(dataset: &DataSet, ops: &[(&str, ReduceOp)]) -> Vec<Option<Value>> {
+    let mut accs: Vec<Option<(usize, ColumnReducer)>> = ops
+        .iter()
+        .map(|(column, op)| {
+            let idx = dataset.schema.index_of(column)?;
             let field = dataset.schema.fields.get(idx)?;
-            reduce_count_distinct_non_null(dataset, idx, &field.data_type)
+            Some((idx, ColumnReducer::new(&field.data_type, *op)?))
+        })
+        .collect();
+
+    for row in &dataset.rows {
+        for (idx, acc) in accs.iter_mut().flatten() {
+            acc.observe(row.get(*idx));
         }
-        ReduceOp::Sum | ReduceOp::Min | ReduceOp::Max => match dataset.schema.fields.get(idx) {
-            Some(field) => reduce_numeric_typed(dataset, idx, field.data_type.clone(), op),
-            None => None,
-        },
-        ReduceOp::Mean
-        | ReduceOp::Variance(_)
-        | ReduceOp::StdDev(_)
-        | ReduceOp::SumSquares
-        | ReduceOp::L2Norm => match dataset.schema.fields.get(idx) {
-            Some(field) => reduce_numeric_float_stats(dataset, idx, field.data_type.clone(), op),
-            None => None,
-        },
     }
+
+    accs.into_iter()
+        .map(|a| a.and_then(|(_, acc)| acc.finish()))
+        .collect()
+}
+
+/// Join the non-null values of a Utf8 (or Enum) column with `sep`, in row order.
+///
+/// Returns `Some(Value::Utf8(..))`, `Some(Value::Null)` if every value is null (or there are no
+/// rows), and `None` if `column` is missing or not a string column.
+pub fn reduce_concat(dataset: &DataSet, column: &str, sep: &str) -> Option<Value> {
+    let idx = dataset.schema.index_of(column)?;
+    if !matches!(
+        dataset.schema.fields[idx].data_type,
+        DataType::Utf8 | DataType::Enum(_)
+    ) {
+        return None;
+    }
+    let mut out: Option<String> = None;
+    for row in &dataset.rows {
+        if let Some(Value::Utf8(s)) = row.get(idx) {
+            match &mut out {
+                Some(acc) => {
+                    acc.push_str(sep);
+                    acc.push_str(s);
+                }
+                None => out = Some(s.clone()),
+            }
+        }
+    }
+    Some(out.map_or(Value::Null, Value::Utf8))
+}
+
+/// Reduce `value_col` separately for each distinct value of `group_col`.
+///
+/// Returns one `(group key, reduced value)` pair per group, in order of first appearance; null
+/// keys form their own group. Each value follows the same rules as [`reduce`].
+///
+/// Returns [`IngestionError::SchemaMismatch`] if either column is missing, `op` is not
+/// supported for `value_col`'s type, or a group's Int64 `Sum` overflows (wherever [`reduce`]
+/// would return `None`).
+pub fn reduce_grouped(
+    dataset: &DataSet,
+    group_col: &str,
+    value_col: &str,
+    op: ReduceOp,
+) -> IngestionResult<Vec<(Value, Value)>> {
+    let lookup = |name: &str| {
+        dataset
+            .schema
+            .index_of(name)
+            .ok_or_else(|| IngestionError::SchemaMismatch {
+                message: format!("unknown column '{name}'"),
+            })
+    };
+    let key_idx = lookup(group_col)?;
+    let value_idx = lookup(value_col)?;
+    let value_type = &dataset.schema.fields[value_idx].data_type;
+    let unsupported = || IngestionError::SchemaMismatch {
+        message: format!("{op:?} is not supported for column '{value_col}' ({value_type:?})"),
+    };
+    ColumnReducer::new(value_type, op).ok_or_else(unsupported)?;
+
+    let mut index: HashMap<String, usize> = HashMap::new();
+    let mut groups: Vec<(Value, ColumnReducer)> = Vec::new();
+    for row in &dataset.rows {
+        let key = row.get(key_idx);
+        let slot = *index.entry(group_key_part(key)).or_insert_with(|| {
+            let acc = ColumnReducer::new(value_type, op).expect("op checked above");
+            groups.push((key.cloned().unwrap_or(Value::Null), acc));
+            groups.len() - 1
+        });
+        groups[slot].1.observe(row.get(value_idx));
+    }
+
+    groups
+        .into_iter()
+        .map(|(key, acc)| {
+            let value = acc.finish().ok_or_else(|| IngestionError::SchemaMismatch {
+                message: format!("{op:?} of column '{value_col}' overflows Int64 for key {key}"),
+            })?;
+            Ok((key, value))
+        })
+        .collect()
 }
 
 #[derive(Default)]
@@ -86,6 +200,26 @@ impl Welford {
         self.m2 += delta * delta2;
     }
 
+    /// Combine a partial state (Chan et al. parallel update).
+    pub(crate) fn merge(&mut self, other: &Welford) {
+        if other.n == 0 {
+            return;
+        }
+        if self.n == 0 {
+            *self = Welford {
+                n: other.n,
+                mean: other.mean,
+                m2: other.m2,
+            };
+            return;
+        }
+        let n = self.n + other.n;
+        let delta = other.mean - self.mean;
+        self.mean += delta * other.n as f64 / n as f64;
+        self.m2 += other.m2 + delta * delta * (self.n as f64) * (other.n as f64) / n as f64;
+        self.n = n;
+    }
+
     pub(crate) fn mean(&self) -> Option<f64> {
         (self.n > 0).then_some(self.mean)
     }
@@ -111,159 +245,310 @@ impl Welford {
     }
 }
 
-fn reduce_numeric_float_stats(
-    dataset: &DataSet,
-    idx: usize,
-    data_type: DataType,
-    op: ReduceOp,
-) -> Option<Value> {
-    match data_type {
-        dt @ (DataType::Int64 | DataType::Float64) => {
-            let is_int = matches!(dt, DataType::Int64);
-            let mut w = Welford::default();
-            let mut sum_squares = 0.0_f64;
-            let mut any = false;
-
-            for row in &dataset.rows {
-                let x = match row.get(idx) {
-                    Some(Value::Null) | None => None,
-                    Some(Value::Int64(v)) if is_int => Some(*v as f64),
-                    Some(Value::Float64(v)) if !is_int => Some(*v),
-                    Some(_) => None,
-                };
-                if let Some(x) = x {
-                    any = true;
-                    w.observe(x);
-                    sum_squares += x * x;
-                }
-            }
-
-            if !any {
-                return Some(Value::Null);
-            }
+/// Streaming accumulator for one [`ReduceOp`] over one column.
+///
+/// Shared by [`reduce`], [`reduce_many`], and chunked/parallel callers so per-op null/type
+/// handling lives in one place; partial states combine with [`ColumnReducer::merge`].
+pub(crate) enum ColumnReducer {
+    Count(i64),
+    DistinctInt64(HashSet<i64>),
+    DistinctFloat64(HashSet<u64>),
+    DistinctBool(HashSet<bool>),
+    DistinctUtf8(HashSet<String>),
+    DistinctBytes(HashSet<Vec<u8>>),
+    DistinctTimestamp(HashSet<i64>),
+    SumInt64(Option<i128>),
+    First(Option<Value>),
+    Last(Option<Value>),
+    Int64 {
+        op: ReduceOp,
+        acc: Option<i64>,
+    },
+    Float64 {
+        op: ReduceOp,
+        acc: Option<f64>,
+    },
+    Timestamp {
+        op: ReduceOp,
+        acc: Option<i64>,
+    },
+    Stats {
+        op: ReduceOp,
+        is_int: bool,
+        w: Welford,
+        sum_squares: f64,
+    },
+    /// Op is not defined for this column type; always yields `Value::Null`.
+    Unsupported,
+}
 
-            let out = match op {
-                ReduceOp::Mean => Value::Float64(w.mean().expect("n > 0")),
-                ReduceOp::Variance(kind) => match w.variance(kind) {
-                    Some(v) => Value::Float64(v),
-                    None => Value::Null,
-                },
-                ReduceOp::StdDev(kind) => match w.variance(kind) {
-                    Some(v) => Value::Float64(v.sqrt()),
-                    None => Value::Null,
-                },
-                ReduceOp::SumSquares => Value::Float64(sum_squares),
-                ReduceOp::L2Norm => Value::Float64(sum_squares.sqrt()),
-                _ => unreachable!("caller only dispatches float stats ops"),
-            };
-            Some(out)
+impl ColumnReducer {
+    /// `None` if `op` is rejected for `data_type` (see [`reduce`]).
+    pub(crate) fn new(data_type: &DataType, op: ReduceOp) -> Option<Self> {
+        if *data_type == DataType::Bytes
+            && !matches!(
+                op,
+                ReduceOp::Count | ReduceOp::CountDistinctNonNull | ReduceOp::First | ReduceOp::Last
+            )
+        {
+            return None;
         }
-        _ => Some(Value::Null),
+        if *data_type == DataType::Timestamp
+            && !matches!(
+                op,
+                ReduceOp::Count
+                    | ReduceOp::CountDistinctNonNull
+                    | ReduceOp::First
+                    | ReduceOp::Last
+                    | ReduceOp::Min
+                    | ReduceOp::Max
+            )
+        {
+            return None;
+        }
+        Some(match op {
+            ReduceOp::Count => Self::Count(0),
+            ReduceOp::First => Self::First(None),
+            ReduceOp::Last => Self::Last(None),
+            ReduceOp::CountDistinctNonNull => match data_type {
+                DataType::Int64 => Self::DistinctInt64(HashSet::new()),
+                DataType::Float64 => Self::DistinctFloat64(HashSet::new()),
+                DataType::Bool => Self::DistinctBool(HashSet::new()),
+                DataType::Utf8 | DataType::Enum(_) => Self::DistinctUtf8(HashSet::new()),
+                DataType::Bytes => Self::DistinctBytes(HashSet::new()),
+                DataType::Timestamp => Self::DistinctTimestamp(HashSet::new()),
+            },
+            ReduceOp::Sum | ReduceOp::Min | ReduceOp::Max => match data_type {
+                DataType::Int64 if op == ReduceOp::Sum => Self::SumInt64(None),
+                DataType::Int64 => Self::Int64 { op, acc: None },
+                DataType::Float64 => Self::Float64 { op, acc: None },
+                DataType::Timestamp => Self::Timestamp { op, acc: None },
+                _ => Self::Unsupported,
+            },
+            ReduceOp::Mean
+            | ReduceOp::Variance(_)
+            | ReduceOp::StdDev(_)
+            | ReduceOp::SumSquares
+            | ReduceOp::L2Norm => match data_type {
+                DataType::Int64 | DataType::Float64 => Self::Stats {
+                    op,
+                    is_int: matches!(data_type, DataType::Int64),
+                    w: Welford::default(),
+                    sum_squares: 0.0,
+                },
+                _ => Self::Unsupported,
+            },
+        })
     }
-}
 
-fn reduce_count_distinct_non_null(
-    dataset: &DataSet,
-    idx: usize,
-    data_type: &DataType,
-) -> Option<Value> {
-    let n = match data_type {
-        DataType::Int64 => {
-            let mut set = HashSet::new();
-            for row in &dataset.rows {
-                if let Some(Value::Int64(v)) = row.get(idx) {
+    /// Feed one cell (`None` = the row is too short to have this column).
+    pub(crate) fn observe(&mut self, cell: Option<&Value>) {
+        match self {
+            Self::Count(n) => *n += 1,
+            Self::DistinctInt64(set) => {
+                if let Some(Value::Int64(v)) = cell {
                     set.insert(*v);
                 }
             }
-            set.len() as i64
-        }
-        DataType::Float64 => {
-            let mut set = HashSet::new();
-            for row in &dataset.rows {
-                if let Some(Value::Float64(v)) = row.get(idx) {
+            Self::DistinctFloat64(set) => {
+                if let Some(Value::Float64(v)) = cell {
                     set.insert(v.to_bits());
                 }
             }
-            set.len() as i64
-        }
-        DataType::Bool => {
-            let mut set = HashSet::new();
-            for row in &dataset.rows {
-                if let Some(Value::Bool(v)) = row.get(idx) {
+            Self::DistinctBool(set) => {
+                if let Some(Value::Bool(v)) = cell {
                     set.insert(*v);
                 }
             }
-            set.len() as i64
-        }
-        DataType::Utf8 => {
-            let mut set = HashSet::new();
-            for row in &dataset.rows {
-                if let Some(Value::Utf8(s)) = row.get(idx) {
+            Self::DistinctUtf8(set) => {
+                if let Some(Value::Utf8(s)) = cell {
                     set.insert(s.clone());
                 }
             }
-            set.len() as i64
-        }
-    };
-    Some(Value::Int64(n))
-}
-
-fn reduce_numeric_typed(
-    dataset: &DataSet,
-    idx: usize,
-    data_type: DataType,
-    op: ReduceOp,
-) -> Option<Value> {
-    match data_type {
-        DataType::Int64 => {
-            let mut acc: Option<i64> = None;
-            for row in &dataset.rows {
-                match row.get(idx) {
-                    Some(Value::Null) | None => {}
-                    Some(Value::Int64(v)) => {
-                        acc = Some(match (op, acc) {
-                            (ReduceOp::Sum, Some(a)) => a + v,
-                            (ReduceOp::Sum, None) => *v,
-                            (ReduceOp::Min, Some(a)) => a.min(*v),
-                            (ReduceOp::Min, None) => *v,
-                            (ReduceOp::Max, Some(a)) => a.max(*v),
-                            (ReduceOp::Max, None) => *v,
-                            _ => unreachable!("non-numeric op handled earlier"),
-                        });
+            Self::SumInt64(acc) => {
+                if let Some(Value::Int64(v)) = cell {
+                    *acc = Some(acc.unwrap_or(0) + i128::from(*v));
+                }
+            }
+            Self::DistinctBytes(set) => {
+                if let Some(Value::Bytes(b)) = cell {
+                    set.insert(b.clone());
+                }
+            }
+            Self::DistinctTimestamp(set) => {
+                if let Some(Value::Timestamp(t)) = cell {
+                    set.insert(*t);
+                }
+            }
+            Self::First(acc) => {
+                if acc.is_none() {
+                    *acc = cell.filter(|v| !matches!(v, Value::Null)).cloned();
+                }
+            }
+            Self::Last(acc) => {
+                if let Some(v) = cell.filter(|v| !matches!(v, Value::Null)) {
+                    *acc = Some(v.clone());
+                }
+            }
+            Self::Int64 { op, acc } => {
+                if let Some(Value::Int64(v)) = cell {
+                    *acc = Some(combine_i64(*op, *acc, *v));
+                }
+            }
+            Self::Timestamp { op, acc } => {
+                if let Some(Value::Timestamp(t)) = cell {
+                    *acc = Some(combine_i64(*op, *acc, *t));
+                }
+            }
+            Self::Float64 { op, acc } => {
+                if let Some(Value::Float64(v)) = cell {
+                    // Min/Max skip NaN so its position in the column can't change the result.
+                    if v.is_nan() && *op != ReduceOp::Sum {
+                        return;
                     }
-                    Some(_) => {}
+                    *acc = Some(combine_f64(*op, *acc, *v));
+                }
+            }
+            Self::Stats {
+                is_int,
+                w,
+                sum_squares,
+                ..
+            } => {
+                let x = match cell {
+                    Some(Value::Int64(v)) if *is_int => Some(*v as f64),
+                    Some(Value::Float64(v)) if !*is_int => Some(*v),
+                    _ => None,
+                };
+                if let Some(x) = x {
+                    w.observe(x);
+                    *sum_squares += x * x;
                 }
             }
-            Some(acc.map(Value::Int64).unwrap_or(Value::Null))
+            Self::Unsupported => {}
         }
-        DataType::Float64 => {
-            let mut acc: Option<f64> = None;
-            for row in &dataset.rows {
-                match row.get(idx) {
-                    Some(Value::Null) | None => {}
-                    Some(Value::Float64(v)) => {
-                        acc = Some(match (op, acc) {
-                            (ReduceOp::Sum, Some(a)) => a + v,
-                            (ReduceOp::Sum, None) => *v,
-                            (ReduceOp::Min, Some(a)) => a.min(*v),
-                            (ReduceOp::Min, None) => *v,
-                            (ReduceOp::Max, Some(a)) => a.max(*v),
-                            (ReduceOp::Max, None) => *v,
-                            _ => unreachable!("non-numeric op handled earlier"),
-                        });
-                    }
-                    Some(_) => {}
+    }
+
+    /// Fold a partial state computed over a later slice of rows into `self`.
+    pub(crate) fn merge(&mut self, other: ColumnReducer) {
+        match (self, other) {
+            (Self::Count(a), Self::Count(b)) => *a += b,
+            (Self::DistinctInt64(a), Self::DistinctInt64(b)) => a.extend(b),
+            (Self::DistinctFloat64(a), Self::DistinctFloat64(b)) => a.extend(b),
+            (Self::DistinctBool(a), Self::DistinctBool(b)) => a.extend(b),
+            (Self::DistinctUtf8(a), Self::DistinctUtf8(b)) => a.extend(b),
+            (Self::DistinctBytes(a), Self::DistinctBytes(b)) => a.extend(b),
+            (Self::DistinctTimestamp(a), Self::DistinctTimestamp(b)) => a.extend(b),
+            (Self::SumInt64(acc), Self::SumInt64(Some(b))) => {
+                *acc = Some(acc.unwrap_or(0) + b);
+            }
+            (Self::First(acc @ None), Self::First(b)) => *acc = b,
+            (Self::Last(acc), Self::Last(Some(b))) => *acc = Some(b),
+            (Self::Int64 { op, acc }, Self::Int64 { acc: Some(b), .. }) => {
+                *acc = Some(combine_i64(*op, *acc, b));
+            }
+            (Self::Timestamp { op, acc }, Self::Timestamp { acc: Some(b), .. }) => {
+                *acc = Some(combine_i64(*op, *acc, b));
+            }
+            (Self::Float64 { op, acc }, Self::Float64 { acc: Some(b), .. }) => {
+                *acc = Some(combine_f64(*op, *acc, b));
+            }
+            (
+                Self::Stats { w, sum_squares, .. },
+                Self::Stats {
+                    w: w2,
+                    sum_squares: s2,
+                    ..
+                },
+            ) => {
+                w.merge(&w2);
+                *sum_squares += s2;
+            }
+            _ => {}
+        }
+    }
+
+    /// Final value, or `None` if an Int64 `Sum` does not fit in `i64`.
+    pub(crate) fn finish(self) -> Option<Value> {
+        Some(match self {
+            Self::Count(n) => Value::Int64(n),
+            Self::DistinctInt64(set) => Value::Int64(set.len() as i64),
+            Self::DistinctFloat64(set) => Value::Int64(set.len() as i64),
+            Self::DistinctBool(set) => Value::Int64(set.len() as i64),
+            Self::DistinctUtf8(set) => Value::Int64(set.len() as i64),
+            Self::DistinctBytes(set) => Value::Int64(set.len() as i64),
+            Self::DistinctTimestamp(set) => Value::Int64(set.len() as i64),
+            Self::SumInt64(acc) => match acc {
+                Some(total) => Value::Int64(i64::try_from(total).ok()?),
+                None => Value::Null,
+            },
+            Self::First(acc) | Self::Last(acc) => acc.unwrap_or(Value::Null),
+            Self::Int64 { acc, .. } => acc.map(Value::Int64).unwrap_or(Value::Null),
+            Self::Float64 { acc, .. } => acc.map(Value::Float64).unwrap_or(Value::Null),
+            Self::Timestamp { acc, .. } => acc.map(Value::Timestamp).unwrap_or(Value::Null),
+            Self::Stats {
+                op, w, sum_squares, ..
+            } => {
+                if w.observation_count() == 0 {
+                    return Some(Value::Null);
+                }
+                match op {
+                    ReduceOp::Mean => Value::Float64(w.mean().expect("n > 0")),
+                    ReduceOp::Variance(kind) => match w.variance(kind) {
+                        Some(v) => Value::Float64(v),
+                        None => Value::Null,
+                    },
+                    ReduceOp::StdDev(kind) => match w.variance(kind) {
+                        Some(v) => Value::Float64(v.sqrt()),
+                        None => Value::Null,
+                    },
+                    ReduceOp::SumSquares => Value::Float64(sum_squares),
+                    ReduceOp::L2Norm => Value::Float64(sum_squares.sqrt()),
+                    _ => unreachable!("stats reducer only built for float stats ops"),
                 }
             }
-            Some(acc.map(Value::Float64).unwrap_or(Value::Null))
+            Self::Unsupported => Value::Null,
+        })
+    }
+}
+
+fn combine_i64(op: ReduceOp, acc: Option<i64>, v: i64) -> i64 {
+    match (op, acc) {
+        (ReduceOp::Sum, Some(a)) => a + v,
+        (ReduceOp::Min, Some(a)) => a.min(v),
+        (ReduceOp::Max, Some(a)) => a.max(v),
+        (_, None) => v,
+        _ => unreachable!("non-numeric op handled earlier"),
+    }
+}
+
+fn combine_f64(op: ReduceOp, acc: Option<f64>, v: f64) -> f64 {
+    match (op, acc) {
+        (ReduceOp::Sum, Some(a)) => a + v,
+        (ReduceOp::Min, Some(a)) => {
+            if v.total_cmp(&a).is_lt() {
+                v
+            } else {
+                a
+            }
         }
-        _ => Some(Value::Null),
+        (ReduceOp::Max, Some(a)) => {
+            if v.total_cmp(&a).is_gt() {
+                v
+            } else {
+                a
+            }
+        }
+        (_, None) => v,
+        _ => unreachable!("non-numeric op handled earlier"),
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{ReduceOp, VarianceKind, reduce};
+    use super::{
+        ColumnReducer, ReduceOp, VarianceKind, reduce, reduce_concat, reduce_grouped, reduce_many,
+    };
     use crate::types::{DataSet, DataType, Field, Schema, Value};
 
     fn numeric_dataset_with_nulls() -> DataSet {
@@ -281,6 +566,96 @@ mod tests {
         DataSet::new(schema, rows)
     }
 
+    #[test]
+    fn reduce_concat_joins_non_null_strings_in_row_order() {
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int64),
+            Field::new("tag", DataType::Utf8),
+        ]);
+        let tag = |s: Option<&str>| s.map_or(Value::Null, |s| Value::Utf8(s.to_string()));
+        let rows = [None, Some("red"), None, Some("green"), Some("blue"), None]
+            .into_iter()
+            .enumerate()
+            .map(|(i, t)| vec![Value::Int64(i as i64), tag(t)])
+            .collect();
+        let ds = DataSet::new(schema, rows);
+
+        assert_eq!(
+            reduce_concat(&ds, "tag", ", "),
+            Some(Value::Utf8("red, green, blue".to_string()))
+        );
+        let all_null = ds.filter_rows(|row| matches!(row[1], Value::Null));
+        assert_eq!(reduce_concat(&all_null, "tag", ","), Some(Value::Null));
+        assert_eq!(reduce_concat(&ds, "id", ","), None);
+        assert_eq!(reduce_concat(&ds, "missing", ","), None);
+    }
+
+    #[test]
+    fn reduce_first_last_skip_leading_and_trailing_nulls() {
+        let schema = Schema::new(vec![Field::new("tag", DataType::Utf8)]);
+        let tag = |s: Option<&str>| s.map_or(Value::Null, |s| Value::Utf8(s.to_string()));
+        let rows = [None, None, Some("b"), Some("a"), Some("c"), None]
+            .into_iter()
+            .map(|t| vec![tag(t)])
+            .collect();
+        let ds = DataSet::new(schema, rows);
+
+        // Position order, not value order (Min would be "a").
+        assert_eq!(reduce(&ds, "tag", ReduceOp::First), Some(tag(Some("b"))));
+        assert_eq!(reduce(&ds, "tag", ReduceOp::Last), Some(tag(Some("c"))));
+
+        let all_null = ds.filter_rows(|row| matches!(row[0], Value::Null));
+        assert_eq!(reduce(&all_null, "tag", ReduceOp::First), Some(Value::Null));
+        assert_eq!(reduce(&all_null, "tag", ReduceOp::Last), Some(Value::Null));
+        assert_eq!(reduce(&ds, "missing", ReduceOp::First), None);
+
+        // Chunked partials merge in row order.
+        let mut first = ColumnReducer::new(&DataType::Utf8, ReduceOp::First).unwrap();
+        let mut last = ColumnReducer::new(&DataType::Utf8, ReduceOp::Last).unwrap();
+        for chunk in ds.rows.chunks(2) {
+            let mut f = ColumnReducer::new(&DataType::Utf8, ReduceOp::First).unwrap();
+            let mut l = ColumnReducer::new(&DataType::Utf8, ReduceOp::Last).unwrap();
+            for row in chunk {
+                f.observe(row.first());
+                l.observe(row.first());
+            }
+            first.merge(f);
+            last.merge(l);
+        }
+        assert_eq!(first.finish(), Some(tag(Some("b"))));
+        assert_eq!(last.finish(), Some(tag(Some("c"))));
+    }
+
+    #[test]
+    fn reduce_grouped_reduces_per_bool_key_in_first_seen_order() {
+        let schema = Schema::new(vec![
+            Field::new("active", DataType::Bool),
+            Field::new("amount", DataType::Int64),
+        ]);
+        let rows = vec![
+            vec![Value::Bool(false), Value::Int64(4)],
+            vec![Value::Bool(true), Value::Int64(1)],
+            vec![Value::Null, Value::Int64(7)],
+            vec![Value::Bool(false), Value::Null],
+            vec![Value::Bool(true), Value::Int64(2)],
+        ];
+        let ds = DataSet::new(schema, rows);
+
+        assert_eq!(
+            reduce_grouped(&ds, "active", "amount", ReduceOp::Sum).unwrap(),
+            vec![
+                (Value::Bool(false), Value::Int64(4)),
+                (Value::Bool(true), Value::Int64(3)),
+                (Value::Null, Value::Int64(7)),
+            ]
+        );
+        assert_eq!(
+            reduce_grouped(&ds, "active", "amount", ReduceOp::Count).unwrap()[0],
+            (Value::Bool(false), Value::Int64(2))
+        );
+        assert!(reduce_grouped(&ds, "missing", "amount", ReduceOp::Sum).is_err());
+    }
+
     #[test]
     fn reduce_count_counts_rows() {
         let ds = numeric_dataset_with_nulls();
@@ -574,4 +949,102 @@ mod tests {
         };
         assert!((l2 * l2 - ss).abs() < 1e-12);
     }
+
+    #[test]
+    fn reduce_many_matches_single_column_reduce() {
+        let ds = numeric_dataset_with_nulls();
+        let ops = [
+            ("score", ReduceOp::Sum),
+            ("id", ReduceOp::Max),
+            ("missing", ReduceOp::Count),
+            ("score", ReduceOp::Mean),
+            ("id", ReduceOp::Variance(VarianceKind::Sample)),
+            ("score", ReduceOp::CountDistinctNonNull),
+        ];
+        let many = reduce_many(&ds, &ops);
+        let single: Vec<_> = ops.iter().map(|(c, op)| reduce(&ds, c, *op)).collect();
+        assert_eq!(many, single);
+        assert_eq!(many[0], Some(Value::Float64(15.5)));
+        assert_eq!(many[2], None);
+    }
+
+    #[test]
+    fn reduce_float_min_max_skip_nan_regardless_of_position() {
+        let schema = Schema::new(vec![Field::new("x", DataType::Float64)]);
+        for nan_at in 0..4 {
+            let mut vals = vec![3.0, -1.5, 7.25];
+            vals.insert(nan_at, f64::NAN);
+            let rows = vals.into_iter().map(|v| vec![Value::Float64(v)]).collect();
+            let ds = DataSet::new(schema.clone(), rows);
+            assert_eq!(reduce(&ds, "x", ReduceOp::Min), Some(Value::Float64(-1.5)));
+            assert_eq!(reduce(&ds, "x", ReduceOp::Max), Some(Value::Float64(7.25)));
+        }
+
+        let ds = DataSet::new(schema, vec![vec![Value::Float64(f64::NAN)]]);
+        assert_eq!(reduce(&ds, "x", ReduceOp::Max), Some(Value::Null));
+    }
+
+    #[test]
+    fn reduce_int_sum_is_exact_and_fails_on_overflow() {
+        let schema = Schema::new(vec![Field::new("x", DataType::Int64)]);
+        let near_max = DataSet::new(
+            schema.clone(),
+            vec![
+                vec![Value::Int64(i64::MAX - 10)],
+                vec![Value::Int64(5)],
+                vec![Value::Null],
+            ],
+        );
+        assert_eq!(
+            reduce(&near_max, "x", ReduceOp::Sum),
+            Some(Value::Int64(i64::MAX - 5))
+        );
+
+        let overflow = DataSet::new(
+            schema.clone(),
+            vec![vec![Value::Int64(i64::MAX)], vec![Value::Int64(i64::MAX)]],
+        );
+        // A total outside i64 fails instead of changing type.
+        assert_eq!(reduce(&overflow, "x", ReduceOp::Sum), None);
+        assert_eq!(
+            reduce_many(&overflow, &[("x", ReduceOp::Sum), ("x", ReduceOp::Max)]),
+            vec![None, Some(Value::Int64(i64::MAX))]
+        );
+        assert!(reduce_grouped(&overflow, "x", "x", ReduceOp::Sum).is_err());
+
+        // Intermediate overflow that cancels out stays exact.
+        let cancels = DataSet::new(
+            schema,
+            vec![
+                vec![Value::Int64(i64::MAX)],
+                vec![Value::Int64(i64::MAX)],
+                vec![Value::Int64(-i64::MAX)],
+            ],
+        );
+        assert_eq!(
+            reduce(&cancels, "x", ReduceOp::Sum),
+            Some(Value::Int64(i64::MAX))
+        );
+    }
+
+    #[test]
+    fn reduce_rejects_numeric_ops_on_bytes_columns() {
+        let schema = Schema::new(vec![Field::new("b", DataType::Bytes)]);
+        let ds = DataSet::new(
+            schema,
+            vec![
+                vec![Value::Bytes(vec![1, 2])],
+                vec![Value::Bytes(vec![1, 2])],
+                vec![Value::Null],
+            ],
+        );
+        assert_eq!(reduce(&ds, "b", ReduceOp::Sum), None);
+        assert_eq!(reduce(&ds, "b", ReduceOp::Min), None);
+        assert_eq!(reduce(&ds, "b", ReduceOp::Mean), None);
+        assert_eq!(reduce(&ds, "b", ReduceOp::Count), Some(Value::Int64(3)));
+        assert_eq!(
+            reduce(&ds, "b", ReduceOp::CountDistinctNonNull),
+            Some(Value::Int64(1))
+        );
+    }
 }
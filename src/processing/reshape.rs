@@ -0,0 +1,258 @@
+//! Wide ↔ long reshaping of [`crate::types::DataSet`] values.
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use crate::error::{IngestionError, IngestionResult};
+use crate::types::{DataSet, DataType, Field, Schema, Value};
+
+use super::multi::group_key_part;
+
+fn column_index(dataset: &DataSet, name: &str) -> IngestionResult<usize> {
+    dataset
+        .schema
+        .index_of(name)
+        .ok_or_else(|| IngestionError::SchemaMismatch {
+            message: format!("unknown column '{name}'"),
+        })
+}
+
+/// Unpivot (melt) `value_cols` into long format.
+///
+/// The output has the `id_cols`, then a Utf8 `variable` column naming the source column, then
+/// a `value` column. Each input row yields one output row per value column, in `value_cols`
+/// order; nulls are kept.
+///
+/// Returns [`IngestionError::SchemaMismatch`] if a column is missing, `value_cols` is empty or
+/// mixes data types, or an id column is itself named `variable` or `value`.
+pub fn unpivot(
+    dataset: &DataSet,
+    id_cols: &[&str],
+    value_cols: &[&str],
+) -> IngestionResult<DataSet> {
+    let Some(first) = value_cols.first() else {
+        return Err(IngestionError::SchemaMismatch {
+            message: "unpivot requires at least one value column".to_string(),
+        });
+    };
+    let id_idxs = id_cols
+        .iter()
+        .map(|c| column_index(dataset, c))
+        .collect::<IngestionResult<Vec<_>>>()?;
+    let value_idxs = value_cols
+        .iter()
+        .map(|c| column_index(dataset, c))
+        .collect::<IngestionResult<Vec<_>>>()?;
+
+    let value_type = dataset.schema.fields[value_idxs[0]].data_type.clone();
+    for (&idx, name) in value_idxs.iter().zip(value_cols) {
+        let dt = &dataset.schema.fields[idx].data_type;
+        if *dt != value_type {
+            return Err(IngestionError::SchemaMismatch {
+                message: format!(
+                    "unpivot value columns must share a type: '{first}' is {value_type:?}, \
+                     '{name}' is {dt:?}"
+                ),
+            });
+        }
+    }
+    if let Some(clash) = id_cols.iter().find(|c| matches!(**c, "variable" | "value")) {
+        return Err(IngestionError::SchemaMismatch {
+            message: format!("id column '{clash}' clashes with an unpivot output column"),
+        });
+    }
+
+    let mut fields: Vec<Field> = id_idxs
+        .iter()
+        .map(|&i| dataset.schema.fields[i].clone())
+        .collect();
+    fields.push(Field::new("variable", DataType::Utf8));
+    fields.push(Field::new("value", value_type));
+
+    let mut rows = Vec::with_capacity(dataset.row_count() * value_cols.len());
+    for row in &dataset.rows {
+        for (&idx, name) in value_idxs.iter().zip(value_cols) {
+            let mut out: Vec<Value> = id_idxs
+                .iter()
+                .map(|&i| row.get(i).cloned().unwrap_or(Value::Null))
+                .collect();
+            out.push(Value::Utf8((*name).to_string()));
+            out.push(row.get(idx).cloned().unwrap_or(Value::Null));
+            rows.push(out);
+        }
+    }
+    Ok(DataSet::new(Schema::new(fields), rows))
+}
+
+/// Pivot long rows into wide format: one output row per distinct `index` key, one output column
+/// per distinct value of `column`, filled from `value`.
+///
+/// Output columns are the `index` columns, then the generated columns sorted by name
+/// (byte-wise). Index rows keep first-appearance order; combinations that never occur are
+/// `Value::Null`. Rows whose `column` cell is null are skipped. Generated columns take the type
+/// of `value`.
+///
+/// Returns [`IngestionError::SchemaMismatch`] if a column is missing, `column` is not Utf8, or
+/// a generated name collides with an index column, and [`IngestionError::ParseError`] (1-based
+/// row) if an index/column combination appears twice.
+pub fn pivot(
+    dataset: &DataSet,
+    index: &[&str],
+    column: &str,
+    value: &str,
+) -> IngestionResult<DataSet> {
+    let index_idxs = index
+        .iter()
+        .map(|c| column_index(dataset, c))
+        .collect::<IngestionResult<Vec<_>>>()?;
+    let col_idx = column_index(dataset, column)?;
+    let value_idx = column_index(dataset, value)?;
+    let col_type = &dataset.schema.fields[col_idx].data_type;
+    if *col_type != DataType::Utf8 {
+        return Err(IngestionError::SchemaMismatch {
+            message: format!("pivot column '{column}' must be Utf8, got {col_type:?}"),
+        });
+    }
+
+    let names: BTreeSet<&str> = dataset
+        .rows
+        .iter()
+        .filter_map(|row| match row.get(col_idx) {
+            Some(Value::Utf8(s)) => Some(s.as_str()),
+            _ => None,
+        })
+        .collect();
+    if let Some(clash) = names.iter().find(|n| index.contains(n)) {
+        return Err(IngestionError::SchemaMismatch {
+            message: format!("pivoted column '{clash}' clashes with an index column"),
+        });
+    }
+    let slot: HashMap<&str, usize> = names
+        .iter()
+        .enumerate()
+        .map(|(i, n)| (*n, index.len() + i))
+        .collect();
+
+    let mut groups: HashMap<Vec<String>, usize> = HashMap::new();
+    let mut rows: Vec<Vec<Value>> = Vec::new();
+    let mut filled: HashSet<(usize, usize)> = HashSet::new();
+    for (row_idx0, row) in dataset.rows.iter().enumerate() {
+        let Some(Value::Utf8(name)) = row.get(col_idx) else {
+            continue;
+        };
+        let key: Vec<String> = index_idxs
+            .iter()
+            .map(|&i| group_key_part(row.get(i)))
+            .collect();
+        let out = *groups.entry(key).or_insert_with(|| {
+            let mut out: Vec<Value> = index_idxs
+                .iter()
+                .map(|&i| row.get(i).cloned().unwrap_or(Value::Null))
+                .collect();
+            out.resize(index.len() + names.len(), Value::Null);
+            rows.push(out);
+            rows.len() - 1
+        });
+        let target = slot[name.as_str()];
+        if !filled.insert((out, target)) {
+            return Err(IngestionError::ParseError {
+                row: row_idx0 + 1,
+                column: column.to_string(),
+                raw: name.clone(),
+                message: "duplicate index/column combination in pivot".to_string(),
+            });
+        }
+        rows[out][target] = row.get(value_idx).cloned().unwrap_or(Value::Null);
+    }
+
+    let value_type = dataset.schema.fields[value_idx].data_type.clone();
+    let mut fields: Vec<Field> = index_idxs
+        .iter()
+        .map(|&i| dataset.schema.fields[i].clone())
+        .collect();
+    fields.extend(names.iter().map(|n| Field::new(*n, value_type.clone())));
+    Ok(DataSet::new(Schema::new(fields), rows))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{pivot, unpivot};
+    use crate::types::{DataSet, DataType, Field, Schema, Value};
+
+    fn wide() -> DataSet {
+        let schema = Schema::new(vec![
+            Field::new("city", DataType::Utf8),
+            Field::new("q1", DataType::Float64),
+            Field::new("q2", DataType::Float64),
+            Field::new("label", DataType::Utf8),
+        ]);
+        let rows = vec![
+            vec![
+                Value::Utf8("oslo".to_string()),
+                Value::Float64(1.0),
+                Value::Float64(2.0),
+                Value::Utf8("x".to_string()),
+            ],
+            vec![
+                Value::Utf8("rome".to_string()),
+                Value::Null,
+                Value::Float64(4.0),
+                Value::Utf8("y".to_string()),
+            ],
+        ];
+        DataSet::new(schema, rows)
+    }
+
+    #[test]
+    fn unpivot_two_value_columns_into_long_rows() {
+        let long = unpivot(&wide(), &["city"], &["q1", "q2"]).unwrap();
+        assert_eq!(
+            long.schema.field_names().collect::<Vec<_>>(),
+            vec!["city", "variable", "value"]
+        );
+        assert_eq!(long.schema.fields[2].data_type, DataType::Float64);
+        let s = |v: &str| Value::Utf8(v.to_string());
+        assert_eq!(
+            long.rows,
+            vec![
+                vec![s("oslo"), s("q1"), Value::Float64(1.0)],
+                vec![s("oslo"), s("q2"), Value::Float64(2.0)],
+                vec![s("rome"), s("q1"), Value::Null],
+                vec![s("rome"), s("q2"), Value::Float64(4.0)],
+            ]
+        );
+    }
+
+    #[test]
+    fn pivot_inverts_unpivot_with_sorted_columns_and_null_gaps() {
+        let long = unpivot(&wide(), &["city"], &["q2", "q1"]).unwrap();
+        let mut partial = long.clone();
+        partial.rows.pop(); // drop (rome, q1)
+        let back = pivot(&partial, &["city"], "variable", "value").unwrap();
+        assert_eq!(
+            back.schema.field_names().collect::<Vec<_>>(),
+            vec!["city", "q1", "q2"]
+        );
+        assert_eq!(back.schema.fields[1].data_type, DataType::Float64);
+        let s = |v: &str| Value::Utf8(v.to_string());
+        assert_eq!(
+            back.rows,
+            vec![
+                vec![s("oslo"), Value::Float64(1.0), Value::Float64(2.0)],
+                vec![s("rome"), Value::Null, Value::Float64(4.0)],
+            ]
+        );
+
+        let mut dup = long.clone();
+        dup.rows.push(dup.rows[0].clone());
+        assert!(pivot(&dup, &["city"], "variable", "value").is_err());
+        assert!(pivot(&long, &["variable"], "value", "city").is_err());
+    }
+
+    #[test]
+    fn unpivot_rejects_mixed_value_types_and_unknown_columns() {
+        let err = unpivot(&wide(), &["city"], &["q1", "label"]).unwrap_err();
+        assert!(err.to_string().contains("must share a type"));
+        assert!(unpivot(&wide(), &["city"], &["missing"]).is_err());
+        assert!(unpivot(&wide(), &["city"], &[]).is_err());
+    }
+}
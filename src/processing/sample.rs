@@ -0,0 +1,109 @@
+//! Deterministic random row sampling for [`crate::types::DataSet`].
+
+use crate::types::DataSet;
+
+/// Returns a new [`DataSet`] with up to `n` rows chosen uniformly at random without replacement.
+///
+/// - The same `seed` always selects the same rows (a small built-in PRNG is used, so results
+///   are stable across platforms and releases of external crates).
+/// - Sampled rows keep their original relative order.
+/// - If `n >= dataset.row_count()`, returns a clone of the dataset.
+pub fn sample(dataset: &DataSet, n: usize, seed: u64) -> DataSet {
+    let total = dataset.row_count();
+    if n >= total {
+        return dataset.clone();
+    }
+
+    // Selection sampling (Knuth, Algorithm S): one pass, preserves input order.
+    let mut rng = SplitMix64::new(seed);
+    let mut rows = Vec::with_capacity(n);
+    let mut needed = n;
+    for (i, row) in dataset.rows.iter().enumerate() {
+        if needed == 0 {
+            break;
+        }
+        let remaining = (total - i) as u64;
+        if rng.next_below(remaining) < needed as u64 {
+            rows.push(row.clone());
+            needed -= 1;
+        }
+    }
+
+    DataSet::new(dataset.schema.clone(), rows)
+}
+
+/// SplitMix64: tiny, fast, well-distributed PRNG (any seed, including 0, is valid).
+struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform integer in `0..bound` (`bound > 0`), using rejection to avoid modulo bias.
+    fn next_below(&mut self, bound: u64) -> u64 {
+        let zone = u64::MAX - (u64::MAX % bound);
+        loop {
+            let x = self.next_u64();
+            if x < zone {
+                return x % bound;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::sample;
+    use crate::types::{DataSet, DataType, Field, Schema, Value};
+
+    fn dataset_of_n(n: i64) -> DataSet {
+        let schema = Schema::new(vec![Field::new("id", DataType::Int64)]);
+        let rows = (0..n).map(|i| vec![Value::Int64(i)]).collect();
+        DataSet::new(schema, rows)
+    }
+
+    fn ids(ds: &DataSet) -> Vec<i64> {
+        ds.rows
+            .iter()
+            .map(|r| match r[0] {
+                Value::Int64(v) => v,
+                _ => unreachable!(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn sample_is_deterministic_and_preserves_order() {
+        let ds = dataset_of_n(1_000);
+        let a = sample(&ds, 25, 42);
+        let b = sample(&ds, 25, 42);
+        let c = sample(&ds, 25, 7);
+
+        assert_eq!(a.row_count(), 25);
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_eq!(a.schema, ds.schema);
+
+        let picked = ids(&a);
+        assert!(picked.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn sample_returns_clone_when_n_covers_all_rows() {
+        let ds = dataset_of_n(5);
+        assert_eq!(sample(&ds, 5, 1), ds);
+        assert_eq!(sample(&ds, 50, 1), ds);
+        assert_eq!(sample(&ds, 0, 1).row_count(), 0);
+    }
+}
@@ -0,0 +1,61 @@
+//! Row slicing (offset/limit pagination) for [`crate::types::DataSet`].
+
+use crate::types::DataSet;
+
+/// Returns a new [`DataSet`] with at most the first `n` rows.
+///
+/// `n` larger than the row count returns all rows.
+pub fn limit(dataset: &DataSet, n: usize) -> DataSet {
+    let end = n.min(dataset.row_count());
+    DataSet::new(dataset.schema.clone(), dataset.rows[..end].to_vec())
+}
+
+/// Returns a new [`DataSet`] without the first `n` rows.
+///
+/// `n` larger than the row count returns an empty dataset. Combine with [`limit`] for
+/// offset/limit pagination: `limit(&skip(&ds, offset), page_size)`.
+pub fn skip(dataset: &DataSet, n: usize) -> DataSet {
+    let start = n.min(dataset.row_count());
+    DataSet::new(dataset.schema.clone(), dataset.rows[start..].to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{limit, skip};
+    use crate::types::{DataSet, DataType, Field, Schema, Value};
+
+    fn sample_dataset() -> DataSet {
+        let schema = Schema::new(vec![Field::new("id", DataType::Int64)]);
+        let rows = (1..=5).map(|i| vec![Value::Int64(i)]).collect();
+        DataSet::new(schema, rows)
+    }
+
+    fn ids(ds: &DataSet) -> Vec<i64> {
+        ds.rows
+            .iter()
+            .map(|r| match r[0] {
+                Value::Int64(v) => v,
+                _ => unreachable!(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn limit_and_skip_paginate_rows() {
+        let ds = sample_dataset();
+        assert_eq!(ids(&limit(&ds, 2)), vec![1, 2]);
+        assert_eq!(ids(&skip(&ds, 3)), vec![4, 5]);
+        assert_eq!(ids(&limit(&skip(&ds, 2), 2)), vec![3, 4]);
+        assert_eq!(limit(&ds, 2).schema, ds.schema);
+    }
+
+    #[test]
+    fn limit_and_skip_saturate_at_bounds() {
+        let ds = sample_dataset();
+        assert_eq!(limit(&ds, 0).row_count(), 0);
+        assert_eq!(limit(&ds, 100), ds);
+        assert_eq!(skip(&ds, 0), ds);
+        assert_eq!(skip(&ds, 100).row_count(), 0);
+        assert_eq!(skip(&ds, 100).schema, ds.schema);
+    }
+}
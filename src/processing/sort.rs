@@ -0,0 +1,501 @@
+//! Sorting for [`crate::types::DataSet`]: by one column in memory ([`sort_by_column`]) or
+//! with bounded memory over a row stream ([`external_sort`]), and by several columns with
+//! per-column direction ([`sort_by`]).
+//!
+//! All use the same cell ordering: ascending by value (Float64 via [`f64::total_cmp`]), nulls
+//! last, and stable for ties (input order kept).
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, ErrorKind, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
+
+use crate::error::{IngestionError, IngestionResult};
+use crate::types::{DataSet, Schema, Value};
+
+use super::multi::cmp_non_null_values;
+
+/// Rows held in memory per sorted run by [`external_sort`].
+const DEFAULT_RUN_ROWS: usize = 100_000;
+
+/// Returns a new [`DataSet`] with rows sorted by `column` (ascending, nulls last, stable).
+///
+/// Values of mismatched variants compare as equal and keep their input order.
+///
+/// Returns [`IngestionError::SchemaMismatch`] if the column does not exist.
+pub fn sort_by_column(dataset: &DataSet, column: &str) -> IngestionResult<DataSet> {
+    let idx = column_index(&dataset.schema, column)?;
+    let mut rows = dataset.rows.clone();
+    rows.sort_by(|a, b| cmp_cells(a.get(idx), b.get(idx)));
+    Ok(DataSet::new(dataset.schema.clone(), rows))
+}
+
+/// Returns a new [`DataSet`] sorted by several `(column, ascending)` keys, compared
+/// lexicographically: later keys only break ties in earlier ones.
+///
+/// Each key uses the [`sort_by_column`] ordering, reversed for `ascending == false`; nulls sort
+/// last in both directions. The sort is stable, and empty `keys` leave the rows unchanged.
+///
+/// Returns [`IngestionError::SchemaMismatch`] if any key column does not exist.
+pub fn sort_by(dataset: &DataSet, keys: &[(&str, bool)]) -> IngestionResult<DataSet> {
+    let keys = keys
+        .iter()
+        .map(|&(column, ascending)| Ok((column_index(&dataset.schema, column)?, ascending)))
+        .collect::<IngestionResult<Vec<_>>>()?;
+    let mut rows = dataset.rows.clone();
+    rows.sort_by(|a, b| {
+        keys.iter()
+            .map(|&(idx, ascending)| {
+                let (x, y) = (a.get(idx), b.get(idx));
+                match (is_null_cell(x), is_null_cell(y), ascending) {
+                    (false, false, false) => cmp_cells(y, x),
+                    _ => cmp_cells(x, y),
+                }
+            })
+            .find(|o| o.is_ne())
+            .unwrap_or(Ordering::Equal)
+    });
+    Ok(DataSet::new(dataset.schema.clone(), rows))
+}
+
+/// Sort a stream of rows by `column` without holding them all in memory.
+///
+/// Rows are buffered into runs of up to 100,000 rows (see [`external_sort_with_run_rows`]); each
+/// run is sorted in memory and spilled to a temporary file under `tmp_dir`, and the returned
+/// iterator k-way merges the runs. Ordering matches [`sort_by_column`].
+///
+/// Disk usage: every row is written once, so peak usage is roughly the size of the input in a
+/// compact binary encoding (about 9 bytes per numeric cell, plus payload length for Utf8/Bytes).
+/// Memory holds one run while spilling, then one buffered row per run while merging. Temporary
+/// files are removed when the iterator is dropped. If the input fits in a single run, nothing
+/// is written to disk.
+///
+/// Returns [`IngestionError::SchemaMismatch`] for an unknown column or a row whose length
+/// differs from the schema, and [`IngestionError::Io`] if spilling fails; read errors while
+/// merging are yielded as `Err` items.
+pub fn external_sort<I>(
+    rows: I,
+    schema: &Schema,
+    column: &str,
+    tmp_dir: impl AsRef<Path>,
+) -> IngestionResult<ExternalSort>
+where
+    I: IntoIterator<Item = Vec<Value>>,
+{
+    external_sort_with_run_rows(rows, schema, column, tmp_dir, DEFAULT_RUN_ROWS)
+}
+
+/// Like [`external_sort`], buffering at most `run_rows` rows per sorted run.
+///
+/// # Panics
+///
+/// Panics if `run_rows == 0`.
+pub fn external_sort_with_run_rows<I>(
+    rows: I,
+    schema: &Schema,
+    column: &str,
+    tmp_dir: impl AsRef<Path>,
+    run_rows: usize,
+) -> IngestionResult<ExternalSort>
+where
+    I: IntoIterator<Item = Vec<Value>>,
+{
+    assert!(run_rows > 0, "run_rows must be > 0");
+    let idx = column_index(schema, column)?;
+    let width = schema.fields.len();
+
+    let mut sorter = ExternalSort {
+        idx,
+        width,
+        state: MergeState::Memory(Vec::new().into_iter()),
+        runs: Vec::new(),
+        heap: BinaryHeap::new(),
+    };
+
+    let mut buf: Vec<Vec<Value>> = Vec::with_capacity(run_rows.min(1024));
+    for (row_idx0, row) in rows.into_iter().enumerate() {
+        if row.len() != width {
+            return Err(IngestionError::SchemaMismatch {
+                message: format!(
+                    "row {} has {} values but schema has {width} fields",
+                    row_idx0 + 1,
+                    row.len()
+                ),
+            });
+        }
+        buf.push(row);
+        // Zero-width rows carry no data to spill; keep them in memory.
+        if buf.len() == run_rows && width > 0 {
+            sorter.spill(tmp_dir.as_ref(), &mut buf)?;
+        }
+    }
+
+    if sorter.runs.is_empty() {
+        buf.sort_by(|a, b| cmp_cells(a.get(idx), b.get(idx)));
+        sorter.state = MergeState::Memory(buf.into_iter());
+        return Ok(sorter);
+    }
+    if !buf.is_empty() {
+        sorter.spill(tmp_dir.as_ref(), &mut buf)?;
+    }
+    sorter.state = MergeState::Spilled;
+    for run in 0..sorter.runs.len() {
+        sorter.refill(run)?;
+    }
+    Ok(sorter)
+}
+
+/// Iterator over rows sorted by [`external_sort`]; removes its temporary files on drop.
+pub struct ExternalSort {
+    idx: usize,
+    width: usize,
+    state: MergeState,
+    runs: Vec<Run>,
+    heap: BinaryHeap<HeadRow>,
+}
+
+impl std::fmt::Debug for ExternalSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ExternalSort")
+            .field("column_index", &self.idx)
+            .field("spilled_runs", &self.runs.len())
+            .finish()
+    }
+}
+
+enum MergeState {
+    Memory(std::vec::IntoIter<Vec<Value>>),
+    Spilled,
+}
+
+struct Run {
+    path: PathBuf,
+    reader: Option<BufReader<File>>,
+}
+
+/// Current head row of one run; the heap pops the smallest (then lowest run for stability).
+struct HeadRow {
+    row: Vec<Value>,
+    run: usize,
+    idx: usize,
+}
+
+impl Ord for HeadRow {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_cells(self.row.get(self.idx), other.row.get(other.idx))
+            .then(self.run.cmp(&other.run))
+            .reverse()
+    }
+}
+
+impl PartialOrd for HeadRow {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for HeadRow {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for HeadRow {}
+
+impl ExternalSort {
+    fn spill(&mut self, tmp_dir: &Path, buf: &mut Vec<Vec<Value>>) -> IngestionResult<()> {
+        buf.sort_by(|a, b| cmp_cells(a.get(self.idx), b.get(self.idx)));
+        let path = spill_path(tmp_dir);
+        // Track the file before writing so a failed write is still cleaned up on drop.
+        self.runs.push(Run {
+            path: path.clone(),
+            reader: None,
+        });
+        let mut w = BufWriter::new(File::create(&path)?);
+        for row in buf.drain(..) {
+            for v in &row {
+                write_value(&mut w, v)?;
+            }
+        }
+        w.flush()?;
+        Ok(())
+    }
+
+    fn refill(&mut self, run: usize) -> IngestionResult<()> {
+        let r = &mut self.runs[run];
+        if r.reader.is_none() {
+            r.reader = Some(BufReader::new(File::open(&r.path)?));
+        }
+        let reader = r.reader.as_mut().expect("opened above");
+        if let Some(row) = read_row(reader, self.width)? {
+            self.heap.push(HeadRow {
+                row,
+                run,
+                idx: self.idx,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Iterator for ExternalSort {
+    type Item = IngestionResult<Vec<Value>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let MergeState::Memory(rows) = &mut self.state {
+            return rows.next().map(Ok);
+        }
+        let head = self.heap.pop()?;
+        if let Err(e) = self.refill(head.run) {
+            return Some(Err(e));
+        }
+        Some(Ok(head.row))
+    }
+}
+
+impl Drop for ExternalSort {
+    fn drop(&mut self) {
+        for run in self.runs.drain(..) {
+            drop(run.reader);
+            let _ = std::fs::remove_file(&run.path);
+        }
+    }
+}
+
+/// Sort-order comparison of two cells: nulls last, mismatched variants equal.
+pub(crate) fn cmp_cells(a: Option<&Value>, b: Option<&Value>) -> Ordering {
+    match (is_null_cell(a), is_null_cell(b)) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => match (a, b) {
+            (Some(Value::Bytes(x)), Some(Value::Bytes(y))) => x.cmp(y),
+            (Some(x), Some(y)) => cmp_non_null_values(x, y).unwrap_or(Ordering::Equal),
+            _ => unreachable!("non-null cells are present"),
+        },
+    }
+}
+
+fn is_null_cell(v: Option<&Value>) -> bool {
+    matches!(v, Some(Value::Null) | None)
+}
+
+fn column_index(schema: &Schema, column: &str) -> IngestionResult<usize> {
+    schema
+        .index_of(column)
+        .ok_or_else(|| IngestionError::SchemaMismatch {
+            message: format!("unknown column '{column}'"),
+        })
+}
+
+fn spill_path(tmp_dir: &Path) -> PathBuf {
+    static NEXT: AtomicU64 = AtomicU64::new(0);
+    let n = NEXT.fetch_add(1, AtomicOrdering::Relaxed);
+    tmp_dir.join(format!("rdp-sort-{}-{n}.run", std::process::id()))
+}
+
+fn write_value(w: &mut impl Write, v: &Value) -> std::io::Result<()> {
+    match v {
+        Value::Null => w.write_all(&[0]),
+        Value::Int64(x) => {
+            w.write_all(&[1])?;
+            w.write_all(&x.to_le_bytes())
+        }
+        Value::Float64(x) => {
+            w.write_all(&[2])?;
+            w.write_all(&x.to_bits().to_le_bytes())
+        }
+        Value::Bool(b) => w.write_all(&[3, u8::from(*b)]),
+        Value::Utf8(s) => {
+            w.write_all(&[4])?;
+            w.write_all(&(s.len() as u64).to_le_bytes())?;
+            w.write_all(s.as_bytes())
+        }
+        Value::Bytes(b) => {
+            w.write_all(&[5])?;
+            w.write_all(&(b.len() as u64).to_le_bytes())?;
+            w.write_all(b)
+        }
+        Value::Timestamp(t) => {
+            w.write_all(&[6])?;
+            w.write_all(&t.to_le_bytes())
+        }
+    }
+}
+
+/// Read one row of `width` cells, or `None` at a clean end of file.
+fn read_row(r: &mut impl Read, width: usize) -> IngestionResult<Option<Vec<Value>>> {
+    let mut row = Vec::with_capacity(width);
+    for i in 0..width {
+        let mut tag = [0u8; 1];
+        match r.read_exact(&mut tag) {
+            Ok(()) => {}
+            Err(e) if i == 0 && e.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+            Err(e) => return Err(e.into()),
+        }
+        row.push(read_value(r, tag[0])?);
+    }
+    Ok(Some(row))
+}
+
+fn read_value(r: &mut impl Read, tag: u8) -> IngestionResult<Value> {
+    let mut word = [0u8; 8];
+    Ok(match tag {
+        0 => Value::Null,
+        1 => {
+            r.read_exact(&mut word)?;
+            Value::Int64(i64::from_le_bytes(word))
+        }
+        2 => {
+            r.read_exact(&mut word)?;
+            Value::Float64(f64::from_bits(u64::from_le_bytes(word)))
+        }
+        3 => {
+            let mut b = [0u8; 1];
+            r.read_exact(&mut b)?;
+            Value::Bool(b[0] != 0)
+        }
+        4 | 5 => {
+            r.read_exact(&mut word)?;
+            let mut payload = vec![0u8; u64::from_le_bytes(word) as usize];
+            r.read_exact(&mut payload)?;
+            if tag == 5 {
+                Value::Bytes(payload)
+            } else {
+                Value::Utf8(
+                    String::from_utf8(payload)
+                        .map_err(|e| std::io::Error::new(ErrorKind::InvalidData, e.to_string()))?,
+                )
+            }
+        }
+        6 => {
+            r.read_exact(&mut word)?;
+            Value::Timestamp(i64::from_le_bytes(word))
+        }
+        other => {
+            return Err(std::io::Error::new(
+                ErrorKind::InvalidData,
+                format!("corrupt sort run: unknown value tag {other}"),
+            )
+            .into());
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{external_sort, external_sort_with_run_rows, sort_by, sort_by_column};
+    use crate::types::{DataSet, DataType, Field, Schema, Value};
+
+    fn sample_dataset() -> DataSet {
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int64),
+            Field::new("score", DataType::Float64),
+        ]);
+        let rows = (0..50i64)
+            .map(|i| {
+                let score = if i % 9 == 0 {
+                    Value::Null
+                } else {
+                    Value::Float64(((i * 37) % 11) as f64)
+                };
+                vec![Value::Int64(i), score]
+            })
+            .collect();
+        DataSet::new(schema, rows)
+    }
+
+    fn scratch_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("rdp-sort-test-{name}-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn sort_by_column_is_stable_with_nulls_last() {
+        let ds = sample_dataset();
+        let out = sort_by_column(&ds, "score").unwrap();
+        let scores = out.column_f64("score").unwrap();
+        let non_null: Vec<f64> = scores.iter().flatten().copied().collect();
+        assert!(non_null.windows(2).all(|w| w[0] <= w[1]));
+        assert!(scores[non_null.len()..].iter().all(Option::is_none));
+
+        // Ties keep input order.
+        let ids = out.column_i64("id").unwrap();
+        for (w, s) in ids.windows(2).zip(scores.windows(2)) {
+            if s[0] == s[1] {
+                assert!(w[0] < w[1]);
+            }
+        }
+        assert!(sort_by_column(&ds, "missing").is_err());
+    }
+
+    #[test]
+    fn sort_by_orders_by_several_keys_with_mixed_directions() {
+        let schema = Schema::new(vec![
+            Field::new("region", DataType::Utf8),
+            Field::new("score", DataType::Int64),
+        ]);
+        let cell = |s: Option<i64>| s.map(Value::Int64).unwrap_or(Value::Null);
+        let rows = [
+            ("west", Some(3)),
+            ("east", Some(1)),
+            ("west", None),
+            ("east", Some(5)),
+            ("west", Some(8)),
+            ("east", Some(5)),
+        ]
+        .into_iter()
+        .map(|(r, s)| vec![Value::Utf8(r.to_string()), cell(s)])
+        .collect();
+        let ds = DataSet::new(schema, rows);
+
+        let out = sort_by(&ds, &[("region", true), ("score", false)]).unwrap();
+        let got: Vec<(&str, Option<i64>)> = out
+            .rows
+            .iter()
+            .map(|r| match (&r[0], &r[1]) {
+                (Value::Utf8(s), Value::Int64(x)) => (s.as_str(), Some(*x)),
+                (Value::Utf8(s), _) => (s.as_str(), None),
+                _ => unreachable!(),
+            })
+            .collect();
+        assert_eq!(
+            got,
+            vec![
+                ("east", Some(5)),
+                ("east", Some(5)),
+                ("east", Some(1)),
+                ("west", Some(8)),
+                ("west", Some(3)),
+                ("west", None),
+            ]
+        );
+        assert_eq!(out.rows[0], ds.rows[3], "ties keep input order");
+        assert_eq!(sort_by(&ds, &[]).unwrap(), ds);
+        assert!(sort_by(&ds, &[("region", true), ("missing", true)]).is_err());
+    }
+
+    #[test]
+    fn external_sort_merges_spilled_runs_and_cleans_up() {
+        let ds = sample_dataset();
+        let dir = scratch_dir("merge");
+
+        let expected = sort_by_column(&ds, "score").unwrap().rows;
+        let sorted =
+            external_sort_with_run_rows(ds.rows.clone(), &ds.schema, "score", &dir, 8).unwrap();
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 7);
+        let rows: Vec<Vec<Value>> = sorted.map(Result::unwrap).collect();
+        assert_eq!(rows, expected);
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 0);
+
+        // Fits in one run: sorted in memory, nothing spilled.
+        let sorted = external_sort(ds.rows.clone(), &ds.schema, "score", &dir).unwrap();
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 0);
+        assert_eq!(sorted.map(Result::unwrap).collect::<Vec<_>>(), expected);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
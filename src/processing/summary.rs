@@ -0,0 +1,125 @@
+//! Single-pass numeric summary (count, sum, extrema, mean) of one column.
+
+use crate::types::{DataSet, DataType, Value};
+
+use super::reduce::{ColumnReducer, ReduceOp};
+
+/// Exact-width sum returned in [`NumericSummary::sum`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NumericSum {
+    /// Sum of an Int64 column, accumulated in `i128` so it cannot overflow.
+    Int(i128),
+    /// Sum of a Float64 column.
+    Float(f64),
+}
+
+/// Aggregates returned by [`summarize_numeric`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumericSummary {
+    /// Number of rows, including nulls.
+    pub count: usize,
+    /// Number of non-null cells.
+    pub non_null_count: usize,
+    /// Sum of the non-null cells (`0` when there are none).
+    pub sum: NumericSum,
+    /// Minimum, with the same rules as [`ReduceOp::Min`] (`Value::Null` if no values).
+    pub min: Value,
+    /// Maximum, with the same rules as [`ReduceOp::Max`] (`Value::Null` if no values).
+    pub max: Value,
+    /// `sum / non_null_count`, or `None` when every cell is null.
+    pub mean: Option<f64>,
+}
+
+/// Compute count, non-null count, sum, min, max, and mean of `column` in one pass.
+///
+/// Returns `None` if the column is missing or is not Int64/Float64.
+pub fn summarize_numeric(dataset: &DataSet, column: &str) -> Option<NumericSummary> {
+    let idx = dataset.schema.index_of(column)?;
+    let data_type = &dataset.schema.fields[idx].data_type;
+    let mut sum = match data_type {
+        DataType::Int64 => NumericSum::Int(0),
+        DataType::Float64 => NumericSum::Float(0.0),
+        _ => return None,
+    };
+    let mut min = ColumnReducer::new(data_type, ReduceOp::Min)?;
+    let mut max = ColumnReducer::new(data_type, ReduceOp::Max)?;
+
+    let mut non_null_count = 0usize;
+    for row in &dataset.rows {
+        let v = row.get(idx);
+        match (&mut sum, v) {
+            (NumericSum::Int(s), Some(Value::Int64(x))) => *s += i128::from(*x),
+            (NumericSum::Float(s), Some(Value::Float64(x))) => *s += x,
+            _ => continue,
+        }
+        non_null_count += 1;
+        min.observe(v);
+        max.observe(v);
+    }
+
+    let mean = (non_null_count > 0).then(|| {
+        let total = match sum {
+            NumericSum::Int(s) => s as f64,
+            NumericSum::Float(s) => s,
+        };
+        total / non_null_count as f64
+    });
+
+    Some(NumericSummary {
+        count: dataset.row_count(),
+        non_null_count,
+        sum,
+        min: min.finish().unwrap_or(Value::Null),
+        max: max.finish().unwrap_or(Value::Null),
+        mean,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{NumericSum, summarize_numeric};
+    use crate::types::{DataSet, DataType, Field, Schema, Value};
+
+    #[test]
+    fn summarize_numeric_computes_all_aggregates_without_overflow() {
+        let schema = Schema::new(vec![
+            Field::new("n", DataType::Int64),
+            Field::new("x", DataType::Float64),
+            Field::new("label", DataType::Utf8),
+        ]);
+        let rows = vec![
+            vec![
+                Value::Int64(i64::MAX),
+                Value::Float64(1.5),
+                Value::Utf8("a".to_string()),
+            ],
+            vec![Value::Int64(i64::MAX), Value::Null, Value::Null],
+            vec![Value::Null, Value::Float64(-0.5), Value::Null],
+        ];
+        let ds = DataSet::new(schema, rows);
+
+        let n = summarize_numeric(&ds, "n").unwrap();
+        assert_eq!(n.count, 3);
+        assert_eq!(n.non_null_count, 2);
+        assert_eq!(n.sum, NumericSum::Int(2 * i128::from(i64::MAX)));
+        assert_eq!(n.min, Value::Int64(i64::MAX));
+        assert_eq!(n.max, Value::Int64(i64::MAX));
+        assert_eq!(n.mean, Some(i64::MAX as f64));
+
+        let x = summarize_numeric(&ds, "x").unwrap();
+        assert_eq!(x.sum, NumericSum::Float(1.0));
+        assert_eq!(x.min, Value::Float64(-0.5));
+        assert_eq!(x.max, Value::Float64(1.5));
+        assert_eq!(x.mean, Some(0.5));
+
+        assert!(summarize_numeric(&ds, "label").is_none());
+        assert!(summarize_numeric(&ds, "missing").is_none());
+
+        let empty = DataSet::new(ds.schema.clone(), Vec::new());
+        let e = summarize_numeric(&empty, "n").unwrap();
+        assert_eq!(
+            (e.count, e.sum, e.min, e.mean),
+            (0, NumericSum::Int(0), Value::Null, None)
+        );
+    }
+}
@@ -108,6 +108,9 @@ pub fn render_profile_report_json(report: &ProfileReport) -> IngestionResult<Str
                 DataType::Float64 => "float64",
                 DataType::Bool => "bool",
                 DataType::Utf8 => "utf8",
+                DataType::Bytes => "bytes",
+                DataType::Timestamp => "timestamp",
+                DataType::Enum(_) => "enum",
             };
             let numeric = c.numeric.as_ref().map(|n| {
                 serde_json::json!({
@@ -169,6 +172,9 @@ fn render_columns_markdown(cols: &[ColumnProfile]) -> String {
             DataType::Float64 => "Float64",
             DataType::Bool => "Bool",
             DataType::Utf8 => "Utf8",
+            DataType::Bytes => "Bytes",
+            DataType::Timestamp => "Timestamp",
+            DataType::Enum(_) => "Enum",
         };
         let (min, max, mean) = match &c.numeric {
             Some(n) => (
@@ -173,8 +173,11 @@ impl TransformSpec {
 pub mod arrow {
     use std::sync::Arc;
 
-    use arrow::array::{Array, ArrayRef, BooleanArray, Float64Array, Int64Array, StringArray};
-    use arrow::datatypes::{DataType as ArrowDataType, Field, Schema as ArrowSchema};
+    use arrow::array::{
+        Array, ArrayRef, BinaryArray, BooleanArray, Float64Array, Int64Array, StringArray,
+        TimestampMicrosecondArray,
+    };
+    use arrow::datatypes::{DataType as ArrowDataType, Field, Schema as ArrowSchema, TimeUnit};
     use arrow::record_batch::RecordBatch;
 
     use crate::error::{IngestionError, IngestionResult};
@@ -188,6 +191,8 @@ pub mod arrow {
                 ArrowDataType::Float64 => DataType::Float64,
                 ArrowDataType::Boolean => DataType::Bool,
                 ArrowDataType::Utf8 | ArrowDataType::LargeUtf8 => DataType::Utf8,
+                ArrowDataType::Binary | ArrowDataType::LargeBinary => DataType::Bytes,
+                ArrowDataType::Timestamp(TimeUnit::Microsecond, _) => DataType::Timestamp,
                 other => {
                     return Err(IngestionError::SchemaMismatch {
                         message: format!("unsupported Arrow dtype in schema: {other:?}"),
@@ -262,7 +267,7 @@ pub mod arrow {
                     cols.push(Arc::new(BooleanArray::from(v)) as ArrayRef);
                     arrow_fields.push(Field::new(&field.name, ArrowDataType::Boolean, true));
                 }
-                DataType::Utf8 => {
+                DataType::Utf8 | DataType::Enum(_) => {
                     let mut v = Vec::with_capacity(ds.row_count());
                     for row in &ds.rows {
                         match row.get(col_idx) {
@@ -281,6 +286,49 @@ pub mod arrow {
                     cols.push(Arc::new(StringArray::from(v)) as ArrayRef);
                     arrow_fields.push(Field::new(&field.name, ArrowDataType::Utf8, true));
                 }
+                DataType::Bytes => {
+                    let mut v = Vec::with_capacity(ds.row_count());
+                    for row in &ds.rows {
+                        match row.get(col_idx) {
+                            Some(Value::Null) | None => v.push(None),
+                            Some(Value::Bytes(x)) => v.push(Some(x.as_slice())),
+                            Some(other) => {
+                                return Err(IngestionError::ParseError {
+                                    row: 1,
+                                    column: field.name.clone(),
+                                    raw: format!("{other:?}"),
+                                    message: "value does not match schema type Bytes".to_string(),
+                                });
+                            }
+                        }
+                    }
+                    cols.push(Arc::new(BinaryArray::from(v)) as ArrayRef);
+                    arrow_fields.push(Field::new(&field.name, ArrowDataType::Binary, true));
+                }
+                DataType::Timestamp => {
+                    let mut v = Vec::with_capacity(ds.row_count());
+                    for row in &ds.rows {
+                        match row.get(col_idx) {
+                            Some(Value::Null) | None => v.push(None),
+                            Some(Value::Timestamp(x)) => v.push(Some(*x)),
+                            Some(other) => {
+                                return Err(IngestionError::ParseError {
+                                    row: 1,
+                                    column: field.name.clone(),
+                                    raw: format!("{other:?}"),
+                                    message: "value does not match schema type Timestamp"
+                                        .to_string(),
+                                });
+                            }
+                        }
+                    }
+                    cols.push(Arc::new(TimestampMicrosecondArray::from(v)) as ArrayRef);
+                    arrow_fields.push(Field::new(
+                        &field.name,
+                        ArrowDataType::Timestamp(TimeUnit::Microsecond, None),
+                        true,
+                    ));
+                }
             }
         }
 
@@ -351,7 +399,7 @@ pub mod arrow {
                             Value::Bool(a.value(row_i))
                         }
                     }
-                    DataType::Utf8 => {
+                    DataType::Utf8 | DataType::Enum(_) => {
                         // Accept both Utf8 and LargeUtf8 arrays.
                         if let Some(a) = arr.as_any().downcast_ref::<StringArray>() {
                             if a.is_null(row_i) {
@@ -365,6 +413,34 @@ pub mod arrow {
                             });
                         }
                     }
+                    DataType::Bytes => {
+                        let a = arr.as_any().downcast_ref::<BinaryArray>().ok_or_else(|| {
+                            IngestionError::SchemaMismatch {
+                                message: format!("arrow column '{}' is not Binary", field.name),
+                            }
+                        })?;
+                        if a.is_null(row_i) {
+                            Value::Null
+                        } else {
+                            Value::Bytes(a.value(row_i).to_vec())
+                        }
+                    }
+                    DataType::Timestamp => {
+                        let a = arr
+                            .as_any()
+                            .downcast_ref::<TimestampMicrosecondArray>()
+                            .ok_or_else(|| IngestionError::SchemaMismatch {
+                                message: format!(
+                                    "arrow column '{}' is not Timestamp(Microsecond)",
+                                    field.name
+                                ),
+                            })?;
+                        if a.is_null(row_i) {
+                            Value::Null
+                        } else {
+                            Value::Timestamp(a.value(row_i))
+                        }
+                    }
                 };
                 row.push(v);
             }
@@ -3,28 +3,118 @@
 //! This crate ingests supported formats into an in-memory [`DataSet`], using a user-provided
 //! [`Schema`] (a list of typed [`Field`]s).
 
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
 use serde::{Deserialize, Serialize};
 
+use crate::error::{IngestionError, IngestionResult};
+
 /// Logical data type for a schema field.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// Serializes as the lowercase name from [`DataType::as_str`] (`"int64"`, ...; `Enum` as
+/// `{"enum": [...]}`). The capitalized variant names (`"Int64"`, ...) are still accepted when
+/// deserializing.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum DataType {
     /// 64-bit signed integer.
+    #[serde(alias = "Int64")]
     Int64,
     /// 64-bit floating point number.
+    #[serde(alias = "Float64")]
     Float64,
     /// Boolean.
+    #[serde(alias = "Bool")]
     Bool,
     /// UTF-8 string.
+    #[serde(alias = "Utf8")]
     Utf8,
+    /// Opaque binary data (e.g. Parquet `BINARY`, base64 blobs in JSON).
+    #[serde(alias = "Bytes")]
+    Bytes,
+    /// Point in time with microsecond precision, stored as [`Value::Timestamp`] (UTC).
+    #[serde(alias = "Timestamp")]
+    Timestamp,
+    /// Categorical string restricted to the listed values; stored as [`Value::Utf8`].
+    ///
+    /// Ingestion rejects cells outside the set with a `ParseError` (see
+    /// `IngestionOptions::case_insensitive_enums` for case handling).
+    #[serde(alias = "Enum")]
+    Enum(Vec<String>),
+}
+
+impl DataType {
+    /// Lowercase type name, as accepted by [`DataType::from_str`](std::str::FromStr).
+    ///
+    /// [`DataType::Enum`] reports `"enum"`, which does not parse back (its values are not
+    /// part of the name).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DataType::Int64 => "int64",
+            DataType::Float64 => "float64",
+            DataType::Bool => "bool",
+            DataType::Utf8 => "utf8",
+            DataType::Bytes => "bytes",
+            DataType::Timestamp => "timestamp",
+            DataType::Enum(_) => "enum",
+        }
+    }
 }
 
+/// Parse a type name from config (`"int64"`, `"float64"`, `"bool"`, `"utf8"`, `"bytes"`,
+/// `"timestamp"`; case-insensitive, surrounding whitespace ignored).
+///
+/// Unknown names, including `"enum"`, return [`IngestionError::SchemaMismatch`].
+impl std::str::FromStr for DataType {
+    type Err = IngestionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "int64" => Ok(DataType::Int64),
+            "float64" => Ok(DataType::Float64),
+            "bool" => Ok(DataType::Bool),
+            "utf8" => Ok(DataType::Utf8),
+            "bytes" => Ok(DataType::Bytes),
+            "timestamp" => Ok(DataType::Timestamp),
+            _ => Err(IngestionError::SchemaMismatch {
+                message: format!(
+                    "unknown data type '{s}' (expected int64, float64, bool, utf8, bytes, or \
+                     timestamp)"
+                ),
+            }),
+        }
+    }
+}
+
+/// Validation hook for [`DataType::Utf8`] cells; `Err(message)` rejects the value.
+pub type FieldValidator = Arc<dyn Fn(&str) -> Result<(), String> + Send + Sync>;
+
+/// Rewrite hook applied to a raw text cell before it is typed (see [`Field::pre_transform`]).
+pub type FieldPreTransform = Arc<dyn Fn(&str) -> String + Send + Sync>;
+
 /// A single named, typed field in a [`Schema`].
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Field {
     /// Field/column name.
     pub name: String,
     /// Field data type.
     pub data_type: DataType,
+    /// Optional check run on every non-null Utf8 cell at ingest time (CSV, JSON, Parquet,
+    /// Excel). A returned `Err(message)` becomes an [`IngestionError::ParseError`] carrying
+    /// that message. Not serialized.
+    #[serde(skip)]
+    pub validator: Option<FieldValidator>,
+    /// Optional inclusive `(min, max)` bounds checked on every non-null Int64 cell at ingest
+    /// time (all formats). Out-of-range values become an [`IngestionError::ParseError`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub int_range: Option<(i64, i64)>,
+    /// Optional rewrite of the raw cell text before type conversion, e.g. stripping a `"kg"`
+    /// unit so `"12kg"` parses as Int64. Applied to every CSV cell and to Excel text cells;
+    /// JSON and Parquet values are already typed and are not affected. Not serialized.
+    #[serde(skip)]
+    pub pre_transform: Option<FieldPreTransform>,
 }
 
 impl Field {
@@ -33,10 +123,71 @@ impl Field {
         Self {
             name: name.into(),
             data_type,
+            validator: None,
+            int_range: None,
+            pre_transform: None,
         }
     }
+
+    /// Attach a Utf8 validator (see [`Field::validator`]).
+    pub fn with_validator<F>(mut self, validator: F) -> Self
+    where
+        F: Fn(&str) -> Result<(), String> + Send + Sync + 'static,
+    {
+        self.validator = Some(Arc::new(validator));
+        self
+    }
+
+    /// Rewrite raw text cells before typing (see [`Field::pre_transform`]).
+    pub fn with_pre_transform<F>(mut self, transform: F) -> Self
+    where
+        F: Fn(&str) -> String + Send + Sync + 'static,
+    {
+        self.pre_transform = Some(Arc::new(transform));
+        self
+    }
+
+    /// Restrict Int64 cells to `range` (inclusive; see [`Field::int_range`]).
+    pub fn with_int_range(mut self, range: std::ops::RangeInclusive<i64>) -> Self {
+        self.int_range = Some((*range.start(), *range.end()));
+        self
+    }
 }
 
+impl fmt::Debug for Field {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Field")
+            .field("name", &self.name)
+            .field("data_type", &self.data_type)
+            .field("validator_set", &self.validator.is_some())
+            .field("int_range", &self.int_range)
+            .field("pre_transform_set", &self.pre_transform.is_some())
+            .finish()
+    }
+}
+
+/// Fields are equal when name, type, and `int_range` match and they share the same validator
+/// and pre-transform (or neither has one).
+impl PartialEq for Field {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.data_type == other.data_type
+            && self.int_range == other.int_range
+            && match (&self.validator, &other.validator) {
+                (None, None) => true,
+                (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+                _ => false,
+            }
+            && match (&self.pre_transform, &other.pre_transform) {
+                (None, None) => true,
+                (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+                _ => false,
+            }
+    }
+}
+
+impl Eq for Field {}
+
 /// A list of fields describing the expected shape of incoming data.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Schema {
@@ -59,10 +210,159 @@ impl Schema {
     pub fn index_of(&self, name: &str) -> Option<usize> {
         self.fields.iter().position(|f| f.name == name)
     }
+
+    /// Returns the declared type of a field by name, if present.
+    pub fn data_type_of(&self, name: &str) -> Option<&DataType> {
+        self.index_of(name).map(|i| &self.fields[i].data_type)
+    }
+
+    /// Check that `other` has the same fields (names and types) in the same order.
+    ///
+    /// Returns [`IngestionError::SchemaMismatch`] describing the first divergence.
+    pub fn is_compatible_with(&self, other: &Schema) -> IngestionResult<()> {
+        self.check_fields(other, true)
+    }
+
+    /// Returns `true` if `other` has the same field names in the same order (types ignored).
+    pub fn matches_names(&self, other: &Schema) -> bool {
+        self.check_fields(other, false).is_ok()
+    }
+
+    /// Superset of both schemas: `self`'s fields in order, then `other`'s fields whose names
+    /// `self` lacks, in `other`'s order.
+    ///
+    /// Shared fields keep `self`'s definition. Returns [`IngestionError::SchemaMismatch`] if a
+    /// shared name has different types in the two schemas. Useful for concatenating inputs with
+    /// different columns once each is aligned to the union with
+    /// [`crate::processing::align_to_schema`].
+    pub fn union(&self, other: &Schema) -> IngestionResult<Schema> {
+        let mut fields = self.fields.clone();
+        for field in &other.fields {
+            match self.data_type_of(&field.name) {
+                None => fields.push(field.clone()),
+                Some(t) if *t == field.data_type => {}
+                Some(t) => {
+                    return Err(IngestionError::SchemaMismatch {
+                        message: format!(
+                            "field '{}' type differs: {:?} vs {:?}",
+                            field.name, t, field.data_type
+                        ),
+                    });
+                }
+            }
+        }
+        Ok(Schema::new(fields))
+    }
+
+    fn check_fields(&self, other: &Schema, compare_types: bool) -> IngestionResult<()> {
+        if self.fields.len() != other.fields.len() {
+            return Err(IngestionError::SchemaMismatch {
+                message: format!(
+                    "field count differs: {} vs {}",
+                    self.fields.len(),
+                    other.fields.len()
+                ),
+            });
+        }
+        for (idx, (a, b)) in self.fields.iter().zip(other.fields.iter()).enumerate() {
+            if a.name != b.name {
+                return Err(IngestionError::SchemaMismatch {
+                    message: format!("field {idx} name differs: '{}' vs '{}'", a.name, b.name),
+                });
+            }
+            if compare_types && a.data_type != b.data_type {
+                return Err(IngestionError::SchemaMismatch {
+                    message: format!(
+                        "field '{}' type differs: {:?} vs {:?}",
+                        a.name, a.data_type, b.data_type
+                    ),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Start building a schema with fluent field addition.
+    pub fn builder() -> SchemaBuilder {
+        SchemaBuilder::new()
+    }
+}
+
+/// Fluent builder for [`Schema`].
+///
+/// ```rust
+/// use rust_data_processing::types::{DataType, Schema};
+///
+/// let schema = Schema::builder()
+///     .int64("id")
+///     .utf8("name")
+///     .float64("score")
+///     .bool("active")
+///     .build()
+///     .unwrap();
+/// assert_eq!(schema.fields[2].data_type, DataType::Float64);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SchemaBuilder {
+    fields: Vec<Field>,
+}
+
+impl SchemaBuilder {
+    /// Create an empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a field with an explicit data type.
+    pub fn field(mut self, name: impl Into<String>, data_type: DataType) -> Self {
+        self.fields.push(Field::new(name, data_type));
+        self
+    }
+
+    /// Append an [`DataType::Int64`] field.
+    pub fn int64(self, name: impl Into<String>) -> Self {
+        self.field(name, DataType::Int64)
+    }
+
+    /// Append a [`DataType::Float64`] field.
+    pub fn float64(self, name: impl Into<String>) -> Self {
+        self.field(name, DataType::Float64)
+    }
+
+    /// Append a [`DataType::Bool`] field.
+    pub fn bool(self, name: impl Into<String>) -> Self {
+        self.field(name, DataType::Bool)
+    }
+
+    /// Append a [`DataType::Utf8`] field.
+    pub fn utf8(self, name: impl Into<String>) -> Self {
+        self.field(name, DataType::Utf8)
+    }
+
+    /// Build the schema.
+    ///
+    /// Returns [`IngestionError::SchemaMismatch`] if a field name appears more than once.
+    pub fn build(self) -> IngestionResult<Schema> {
+        for (i, f) in self.fields.iter().enumerate() {
+            if self.fields[..i].iter().any(|prev| prev.name == f.name) {
+                return Err(IngestionError::SchemaMismatch {
+                    message: format!("duplicate field name '{}'", f.name),
+                });
+            }
+        }
+        Ok(Schema::new(self.fields))
+    }
 }
 
 /// A single typed value in a [`DataSet`].
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+///
+/// Serializes as a plain self-describing value: `Null` as `null`, numbers, bools, and strings
+/// as themselves, `Bytes` as the format's byte encoding (an array of numbers in JSON), and
+/// `Timestamp` as its RFC 3339 text (deserializing back as `Utf8`).
+/// Deserialization maps integers to `Int64` and other numbers to `Float64`, so floats follow JSON
+/// number semantics: serde_json writes NaN/infinity as `null`. The older externally tagged form
+/// (`{"Int64": 1}`) is still accepted on input.
+#[derive(Debug, Clone, PartialEq)]
 pub enum Value {
     /// Missing/empty value.
     Null,
@@ -74,12 +374,431 @@ pub enum Value {
     Bool(bool),
     /// UTF-8 string.
     Utf8(String),
+    /// Binary data.
+    Bytes(Vec<u8>),
+    /// Microseconds since the Unix epoch, UTC.
+    Timestamp(i64),
+}
+
+impl Value {
+    /// The [`DataType`] this value holds, or `None` for [`Value::Null`] (which fits any type).
+    ///
+    /// A `Utf8` value reports [`DataType::Utf8`] even when it belongs to an `Enum` column.
+    pub fn data_type(&self) -> Option<DataType> {
+        match self {
+            Value::Null => None,
+            Value::Int64(_) => Some(DataType::Int64),
+            Value::Float64(_) => Some(DataType::Float64),
+            Value::Bool(_) => Some(DataType::Bool),
+            Value::Utf8(_) => Some(DataType::Utf8),
+            Value::Bytes(_) => Some(DataType::Bytes),
+            Value::Timestamp(_) => Some(DataType::Timestamp),
+        }
+    }
+
+    /// Whether this value can be stored in a column of type `data_type`.
+    ///
+    /// [`Value::Null`] matches every type; a `Utf8` value matches [`DataType::Enum`] only if it
+    /// is one of the allowed values (exact case).
+    pub fn matches_type(&self, data_type: &DataType) -> bool {
+        match (self, data_type) {
+            (Value::Null, _)
+            | (Value::Int64(_), DataType::Int64)
+            | (Value::Float64(_), DataType::Float64)
+            | (Value::Bool(_), DataType::Bool)
+            | (Value::Utf8(_), DataType::Utf8)
+            | (Value::Bytes(_), DataType::Bytes)
+            | (Value::Timestamp(_), DataType::Timestamp) => true,
+            (Value::Utf8(s), DataType::Enum(allowed)) => allowed.contains(s),
+            _ => false,
+        }
+    }
+
+    /// Convert this value to `data_type`, or explain why it cannot be converted.
+    ///
+    /// - `Null` stays `Null`; a value already of the target type is returned as-is.
+    /// - Int64 widens to Float64 exactly (Float64 never narrows to Int64).
+    /// - Utf8 is trimmed and parsed as Int64, Float64, or Bool (default tokens
+    ///   `true/t/1/yes/y`, `false/f/0/no/n`); an `Enum` target accepts only allowed values.
+    /// - Utf8 parses as Timestamp from ISO 8601 text (`2021-01-01`, `2021-01-01 12:00:00`,
+    ///   `2021-01-01T12:00:00.5+02:00`; no offset means UTC); Int64 and Timestamp convert both
+    ///   ways as epoch microseconds.
+    /// - Int64, Float64, Bool, and Timestamp convert to Utf8 via their `Display` form.
+    ///
+    /// Any other pair (e.g. Bool to Int64, anything to Bytes) is an error. See
+    /// [`crate::processing::cast_column`] for the column-level, ingestion-rule conversion.
+    pub fn coerce_to(&self, data_type: &DataType) -> Result<Value, String> {
+        if self.matches_type(data_type) {
+            return Ok(self.clone());
+        }
+        let parse_err = |s: &str, e: &dyn fmt::Display| {
+            format!("cannot parse '{s}' as {}: {e}", data_type.as_str())
+        };
+        match (self, data_type) {
+            (Value::Int64(v), DataType::Float64) => Ok(Value::Float64(*v as f64)),
+            (Value::Utf8(s), DataType::Int64) => s
+                .trim()
+                .parse::<i64>()
+                .map(Value::Int64)
+                .map_err(|e| parse_err(s, &e)),
+            (Value::Utf8(s), DataType::Float64) => s
+                .trim()
+                .parse::<f64>()
+                .map(Value::Float64)
+                .map_err(|e| parse_err(s, &e)),
+            (Value::Utf8(s), DataType::Bool) => {
+                crate::ingestion::common::parse_bool(s.trim(), &[], &[])
+                    .map(Value::Bool)
+                    .map_err(|e| parse_err(s, &e))
+            }
+            (Value::Utf8(s), DataType::Timestamp) => parse_timestamp(s.trim())
+                .map(Value::Timestamp)
+                .map_err(|e| parse_err(s, &e)),
+            (Value::Int64(v), DataType::Timestamp) => Ok(Value::Timestamp(*v)),
+            (Value::Timestamp(v), DataType::Int64) => Ok(Value::Int64(*v)),
+            (Value::Utf8(s), DataType::Enum(_)) => Err(format!("'{s}' is not an allowed value")),
+            (
+                Value::Int64(_) | Value::Float64(_) | Value::Bool(_) | Value::Timestamp(_),
+                DataType::Utf8,
+            ) => Ok(Value::Utf8(self.to_string())),
+            _ => Err(format!(
+                "cannot coerce {} to {}",
+                self.data_type().map_or("null", |t| t.as_str()),
+                data_type.as_str()
+            )),
+        }
+    }
+
+    /// Type-aware ordering of two values, or `None` if they are not comparable.
+    ///
+    /// - Same variants compare naturally (Float64 via [`f64::total_cmp`], `false < true`, Utf8
+    ///   and Bytes lexicographically, Timestamp chronologically).
+    /// - Int64 vs Float64 compares the integer as `f64` (exact up to 2^53).
+    /// - `Null` is least: equal to `Null`, less than every other value.
+    /// - Any other mix (e.g. Int64 vs Utf8) is `None`.
+    pub fn cmp_typed(&self, other: &Value) -> Option<std::cmp::Ordering> {
+        use std::cmp::Ordering;
+
+        match (self, other) {
+            (Value::Null, Value::Null) => Some(Ordering::Equal),
+            (Value::Null, _) => Some(Ordering::Less),
+            (_, Value::Null) => Some(Ordering::Greater),
+            (Value::Int64(x), Value::Int64(y)) => Some(x.cmp(y)),
+            (Value::Float64(x), Value::Float64(y)) => Some(x.total_cmp(y)),
+            (Value::Int64(x), Value::Float64(y)) => Some((*x as f64).total_cmp(y)),
+            (Value::Float64(x), Value::Int64(y)) => Some(x.total_cmp(&(*y as f64))),
+            (Value::Bool(x), Value::Bool(y)) => Some(x.cmp(y)),
+            (Value::Utf8(x), Value::Utf8(y)) => Some(x.cmp(y)),
+            (Value::Bytes(x), Value::Bytes(y)) => Some(x.cmp(y)),
+            (Value::Timestamp(x), Value::Timestamp(y)) => Some(x.cmp(y)),
+            _ => None,
+        }
+    }
+}
+
+impl Serialize for Value {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Value::Null => serializer.serialize_none(),
+            Value::Int64(v) => serializer.serialize_i64(*v),
+            Value::Float64(v) => serializer.serialize_f64(*v),
+            Value::Bool(v) => serializer.serialize_bool(*v),
+            Value::Utf8(s) => serializer.serialize_str(s),
+            Value::Bytes(b) => serializer.serialize_bytes(b),
+            Value::Timestamp(_) => serializer.collect_str(self),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Value {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ValueVisitor)
+    }
+}
+
+struct ValueVisitor;
+
+impl<'de> serde::de::Visitor<'de> for ValueVisitor {
+    type Value = Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("null, a number, a bool, a string, or bytes")
+    }
+
+    fn visit_unit<E>(self) -> Result<Value, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_none<E>(self) -> Result<Value, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_some<D: serde::Deserializer<'de>>(self, d: D) -> Result<Value, D::Error> {
+        Value::deserialize(d)
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Value, E> {
+        Ok(Value::Int64(v))
+    }
+
+    fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Value, E> {
+        i64::try_from(v)
+            .map(Value::Int64)
+            .map_err(|_| E::custom(format!("integer {v} does not fit in Int64")))
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Value, E> {
+        Ok(Value::Float64(v))
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<Value, E> {
+        Ok(Value::Bool(v))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Value, E> {
+        Ok(Value::Utf8(v.to_string()))
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Value, E> {
+        Ok(Value::Utf8(v))
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Value, E> {
+        Ok(Value::Bytes(v.to_vec()))
+    }
+
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Value, E> {
+        Ok(Value::Bytes(v))
+    }
+
+    fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Value, A::Error> {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(b) = seq.next_element::<u8>()? {
+            bytes.push(b);
+        }
+        Ok(Value::Bytes(bytes))
+    }
+
+    /// Legacy externally tagged form, e.g. `{"Float64": 0.5}`.
+    fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<Value, A::Error> {
+        use serde::de::Error;
+
+        let Some(tag) = map.next_key::<String>()? else {
+            return Err(A::Error::custom("expected a single-key tagged value"));
+        };
+        let value = match tag.as_str() {
+            "Null" => {
+                map.next_value::<()>()?;
+                Value::Null
+            }
+            "Int64" => Value::Int64(map.next_value()?),
+            "Float64" => Value::Float64(map.next_value()?),
+            "Bool" => Value::Bool(map.next_value()?),
+            "Utf8" => Value::Utf8(map.next_value()?),
+            "Bytes" => Value::Bytes(map.next_value()?),
+            "Timestamp" => Value::Timestamp(map.next_value()?),
+            other => return Err(A::Error::unknown_variant(other, VALUE_TAGS)),
+        };
+        if map.next_key::<String>()?.is_some() {
+            return Err(A::Error::custom("expected a single-key tagged value"));
+        }
+        Ok(value)
+    }
+}
+
+const VALUE_TAGS: &[&str] = &[
+    "Null",
+    "Int64",
+    "Float64",
+    "Bool",
+    "Utf8",
+    "Bytes",
+    "Timestamp",
+];
+
+/// Human-readable rendering: `null`, numbers and bools as usual, strings unquoted, bytes as
+/// lowercase hex with a `0x` prefix, and timestamps as RFC 3339 UTC
+/// (`2021-01-01T00:00:00.5Z`; fractional seconds only when non-zero, trailing zeros dropped).
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Null => f.write_str("null"),
+            Value::Int64(v) => write!(f, "{v}"),
+            Value::Float64(v) => write!(f, "{v}"),
+            Value::Bool(v) => write!(f, "{v}"),
+            Value::Utf8(s) => f.write_str(s),
+            Value::Bytes(b) => {
+                f.write_str("0x")?;
+                b.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+            }
+            Value::Timestamp(us) => {
+                let secs = us.div_euclid(1_000_000);
+                let frac = us.rem_euclid(1_000_000);
+                let (y, m, d) = civil_from_days(secs.div_euclid(86_400));
+                let tod = secs.rem_euclid(86_400);
+                write!(
+                    f,
+                    "{y:04}-{m:02}-{d:02}T{:02}:{:02}:{:02}",
+                    tod / 3600,
+                    tod % 3600 / 60,
+                    tod % 60
+                )?;
+                if frac != 0 {
+                    let digits = format!("{frac:06}");
+                    write!(f, ".{}", digits.trim_end_matches('0'))?;
+                }
+                f.write_str("Z")
+            }
+        }
+    }
+}
+
+/// Days since 1970-01-01 for a proleptic Gregorian date (H. Hinnant's `days_from_civil`).
+pub(crate) fn days_from_civil(y: i64, m: i64, d: i64) -> i64 {
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * (m + if m > 2 { -3 } else { 9 }) + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Inverse of [`days_from_civil`]: `(year, month, day)` for days since 1970-01-01.
+fn civil_from_days(z: i64) -> (i64, i64, i64) {
+    let z = z + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    (yoe + era * 400 + i64::from(m <= 2), m, d)
+}
+
+/// Parse ISO 8601 text into microseconds since the Unix epoch.
+///
+/// Accepts `YYYY-MM-DD`, optionally followed by `T` or a space and `HH:MM[:SS[.fraction]]`,
+/// then an optional `Z` or `±HH:MM` offset (no offset means UTC). Fractions beyond
+/// microseconds are truncated.
+pub(crate) fn parse_timestamp(s: &str) -> Result<i64, String> {
+    let bad = || "expected an ISO 8601 date or date-time".to_string();
+    let num = |t: &str| -> Result<i64, String> {
+        if t.is_empty() || !t.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(bad());
+        }
+        t.parse::<i64>().map_err(|_| bad())
+    };
+
+    let (Some(date), Some(rest)) = (s.get(..10), s.get(10..)) else {
+        return Err(bad());
+    };
+    let mut parts = date.splitn(3, '-');
+    let (Some(y), Some(mo), Some(d)) = (parts.next(), parts.next(), parts.next()) else {
+        return Err(bad());
+    };
+    let (y, mo, d) = (num(y)?, num(mo)?, num(d)?);
+    let month_days = match mo {
+        2 if y % 4 == 0 && (y % 100 != 0 || y % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        1..=12 => 31,
+        _ => return Err(format!("month {mo} out of range")),
+    };
+    if !(1..=month_days).contains(&d) {
+        return Err(format!("day {d} out of range"));
+    }
+    let mut micros = days_from_civil(y, mo, d) * 86_400_000_000;
+    if rest.is_empty() {
+        return Ok(micros);
+    }
+
+    let rest = rest.strip_prefix(['T', 't', ' ']).ok_or_else(bad)?;
+    let (time, offset) = match rest.find(['Z', 'z', '+', '-']) {
+        Some(i) => rest.split_at(i),
+        None => (rest, ""),
+    };
+    let (hms, fraction) = time.split_once('.').unwrap_or((time, ""));
+    let mut fields = hms.split(':');
+    let h = num(fields.next().unwrap_or_default())?;
+    let mi = num(fields.next().ok_or_else(bad)?)?;
+    let sec = fields.next().map_or(Ok(0), num)?;
+    if fields.next().is_some() || h > 23 || mi > 59 || sec > 59 {
+        return Err(bad());
+    }
+    micros += ((h * 60 + mi) * 60 + sec) * 1_000_000;
+    if time.contains('.') {
+        num(fraction)?;
+        let digits: String = fraction
+            .chars()
+            .chain(std::iter::repeat('0'))
+            .take(6)
+            .collect();
+        micros += num(&digits)?;
+    }
+
+    match offset {
+        "" | "Z" | "z" => {}
+        _ => {
+            let sign = if offset.starts_with('-') { -1 } else { 1 };
+            let (oh, om) = offset[1..].split_once(':').ok_or_else(bad)?;
+            let (oh, om) = (num(oh)?, num(om)?);
+            if oh > 23 || om > 59 {
+                return Err(bad());
+            }
+            micros -= sign * (oh * 60 + om) * 60_000_000;
+        }
+    }
+    Ok(micros)
+}
+
+/// Check that every row has one value per schema field; errors name the first bad row as
+/// `offset + index + 1`.
+fn check_arity(schema: &Schema, offset: usize, rows: &[Vec<Value>]) -> IngestionResult<()> {
+    let expected = schema.fields.len();
+    match rows.iter().position(|r| r.len() != expected) {
+        Some(idx) => Err(IngestionError::SchemaMismatch {
+            message: format!(
+                "row {} has {} values but schema has {expected} fields",
+                offset + idx + 1,
+                rows[idx].len()
+            ),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Hashable view of a [`Value`] (floats by bit pattern) for [`DataSet::equals_unordered`].
+#[derive(PartialEq, Eq, Hash)]
+enum CellKey<'a> {
+    Null,
+    Int64(i64),
+    Float64(u64),
+    Bool(bool),
+    Utf8(&'a str),
+    Bytes(&'a [u8]),
+    Timestamp(i64),
+}
+
+impl<'a> From<&'a Value> for CellKey<'a> {
+    fn from(v: &'a Value) -> Self {
+        match v {
+            Value::Null => Self::Null,
+            Value::Int64(x) => Self::Int64(*x),
+            Value::Float64(x) => Self::Float64(x.to_bits()),
+            Value::Bool(b) => Self::Bool(*b),
+            Value::Utf8(s) => Self::Utf8(s),
+            Value::Bytes(b) => Self::Bytes(b),
+            Value::Timestamp(t) => Self::Timestamp(*t),
+        }
+    }
 }
 
 /// In-memory tabular dataset.
 ///
 /// Rows are stored as `Vec<Vec<Value>>` in the same order as the [`Schema`] fields.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "DataSetCells")]
 pub struct DataSet {
     /// Schema describing row shape.
     pub schema: Schema,
@@ -87,17 +806,332 @@ pub struct DataSet {
     pub rows: Vec<Vec<Value>>,
 }
 
+/// Serialized form of a [`DataSet`], before cells are restored to their column types.
+#[derive(Deserialize)]
+struct DataSetCells {
+    schema: Schema,
+    rows: Vec<Vec<Value>>,
+}
+
+impl TryFrom<DataSetCells> for DataSet {
+    type Error = String;
+
+    /// Timestamps are written as RFC 3339 text, which reads back as `Utf8`; parse those cells
+    /// again under Timestamp columns so a dataset round-trips.
+    fn try_from(mut cells: DataSetCells) -> Result<Self, String> {
+        for row in &mut cells.rows {
+            for (cell, field) in row.iter_mut().zip(&cells.schema.fields) {
+                if field.data_type == DataType::Timestamp && matches!(cell, Value::Utf8(_)) {
+                    *cell = cell
+                        .coerce_to(&DataType::Timestamp)
+                        .map_err(|e| format!("column '{}': {e}", field.name))?;
+                }
+            }
+        }
+        Ok(DataSet::new(cells.schema, cells.rows))
+    }
+}
+
 impl DataSet {
     /// Create a dataset from schema and rows.
+    ///
+    /// Row lengths are not checked; use [`DataSet::try_new`] for untrusted rows.
     pub fn new(schema: Schema, rows: Vec<Vec<Value>>) -> Self {
         Self { schema, rows }
     }
 
+    /// Like [`DataSet::new`], but checks every row's length against the schema field count.
+    ///
+    /// Returns [`IngestionError::SchemaMismatch`] naming the first bad row (1-based, as in
+    /// [`DataSet::push_row`]) and its length. Cell types are not checked.
+    pub fn try_new(schema: Schema, rows: Vec<Vec<Value>>) -> IngestionResult<Self> {
+        check_arity(&schema, 0, &rows)?;
+        Ok(Self { schema, rows })
+    }
+
+    /// Create an empty dataset with room for `rows` rows; append with [`DataSet::push_row`].
+    pub fn with_capacity(schema: Schema, rows: usize) -> Self {
+        Self {
+            schema,
+            rows: Vec::with_capacity(rows),
+        }
+    }
+
+    /// Check that every cell's variant matches its column's [`DataType`] (see
+    /// [`Value::matches_type`]; nulls always pass).
+    ///
+    /// Returns the first mismatch, scanning row by row, as an [`IngestionError::ParseError`]
+    /// with the 1-based row, the column name, and the cell's `Display` form. Row lengths are
+    /// not checked (see [`DataSet::try_new`]); cells beyond the schema are ignored.
+    pub fn validate_types(&self) -> IngestionResult<()> {
+        for (row_idx, row) in self.rows.iter().enumerate() {
+            for (field, cell) in self.schema.fields.iter().zip(row) {
+                if !cell.matches_type(&field.data_type) {
+                    return Err(IngestionError::ParseError {
+                        row: row_idx + 1,
+                        column: field.name.clone(),
+                        raw: cell.to_string(),
+                        message: format!(
+                            "expected {}, found {}",
+                            field.data_type.as_str(),
+                            cell.data_type().map_or("null", |t| t.as_str())
+                        ),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Append `row`, checking its length against the schema field count.
+    ///
+    /// Returns [`IngestionError::SchemaMismatch`] (leaving the dataset unchanged) if the arity
+    /// differs. Cell types are not checked.
+    pub fn push_row(&mut self, row: Vec<Value>) -> IngestionResult<()> {
+        check_arity(&self.schema, self.rows.len(), std::slice::from_ref(&row))?;
+        self.rows.push(row);
+        Ok(())
+    }
+
+    /// Append a batch of rows, checking every row's length against the schema first.
+    ///
+    /// Returns [`IngestionError::SchemaMismatch`] naming the first bad row by the 1-based
+    /// position it would have taken (as in [`DataSet::push_row`]); on error no row is appended.
+    pub fn append_rows(&mut self, rows: Vec<Vec<Value>>) -> IngestionResult<()> {
+        check_arity(&self.schema, self.rows.len(), &rows)?;
+        self.rows.extend(rows);
+        Ok(())
+    }
+
+    /// Reorder the columns (schema fields and every row's cells) to match `order`.
+    ///
+    /// `order` must name every existing column exactly once; a missing, unknown, or duplicate
+    /// name returns [`IngestionError::SchemaMismatch`] and leaves the dataset unchanged.
+    pub fn reorder_columns(&mut self, order: &[&str]) -> IngestionResult<()> {
+        let mut perm = Vec::with_capacity(order.len());
+        for name in order {
+            let idx = self
+                .schema
+                .index_of(name)
+                .ok_or_else(|| IngestionError::SchemaMismatch {
+                    message: format!("unknown column '{name}'"),
+                })?;
+            if perm.contains(&idx) {
+                return Err(IngestionError::SchemaMismatch {
+                    message: format!("column '{name}' is listed more than once"),
+                });
+            }
+            perm.push(idx);
+        }
+        if let Some(missing) = self
+            .schema
+            .fields
+            .iter()
+            .enumerate()
+            .find(|(i, _)| !perm.contains(i))
+        {
+            return Err(IngestionError::SchemaMismatch {
+                message: format!("column '{}' is missing from the new order", missing.1.name),
+            });
+        }
+
+        self.schema.fields = perm
+            .iter()
+            .map(|&i| self.schema.fields[i].clone())
+            .collect();
+        for row in &mut self.rows {
+            let mut old = std::mem::take(row);
+            *row = perm
+                .iter()
+                .map(|&i| {
+                    old.get_mut(i)
+                        .map_or(Value::Null, |v| std::mem::replace(v, Value::Null))
+                })
+                .collect();
+        }
+        Ok(())
+    }
+
     /// Number of rows in the dataset.
     pub fn row_count(&self) -> usize {
         self.rows.len()
     }
 
+    /// Whether the dataset has no rows (it may still have a schema).
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Number of columns, i.e. schema fields.
+    pub fn column_count(&self) -> usize {
+        self.schema.fields.len()
+    }
+
+    /// `(rows, columns)`, e.g. for logging.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.row_count(), self.column_count())
+    }
+
+    /// Declared type of column `name`, if present (see [`Schema::data_type_of`]).
+    pub fn column_type(&self, name: &str) -> Option<&DataType> {
+        self.schema.data_type_of(name)
+    }
+
+    /// Render the header and up to `max_rows` rows as an aligned plain-text table, for debugging.
+    ///
+    /// Cells use [`Value`]'s `Display` form and are left-aligned under their header. Only the
+    /// previewed rows are formatted; a `… N more rows` footer reports the rest.
+    pub fn to_pretty_string(&self, max_rows: usize) -> String {
+        let shown = self.rows.len().min(max_rows);
+        let cells: Vec<Vec<String>> = self.rows[..shown]
+            .iter()
+            .map(|row| row.iter().map(ToString::to_string).collect())
+            .collect();
+
+        let mut widths: Vec<usize> = self
+            .schema
+            .fields
+            .iter()
+            .map(|f| f.name.chars().count())
+            .collect();
+        for row in &cells {
+            for (w, cell) in widths.iter_mut().zip(row) {
+                *w = (*w).max(cell.chars().count());
+            }
+        }
+
+        let line = |values: &mut dyn Iterator<Item = &str>| {
+            let mut line = values
+                .zip(&widths)
+                .map(|(v, &w)| format!("{v:<w$}"))
+                .collect::<Vec<_>>()
+                .join(" | ");
+            line.truncate(line.trim_end().len());
+            line.push('\n');
+            line
+        };
+        let mut out = line(&mut self.schema.fields.iter().map(|f| f.name.as_str()));
+        let rule: Vec<String> = widths.iter().map(|&w| "-".repeat(w)).collect();
+        out.push_str(&rule.join("-+-"));
+        out.push('\n');
+        for row in &cells {
+            out.push_str(&line(&mut row.iter().map(String::as_str)));
+        }
+
+        let hidden = self.rows.len() - shown;
+        if hidden > 0 {
+            let noun = if hidden == 1 { "row" } else { "rows" };
+            out.push_str(&format!("… {hidden} more {noun}\n"));
+        }
+        out
+    }
+
+    /// Rough heap footprint in bytes, e.g. for deciding when to spill or chunk.
+    ///
+    /// Counts `size_of::<Value>()` per cell, the payload length of `Utf8`/`Bytes` cells, one
+    /// `Vec` header per row, and the schema's fields and names. Spare `Vec` capacity and
+    /// allocator overhead are ignored, so treat the result as a lower-bound heuristic.
+    pub fn estimated_bytes(&self) -> usize {
+        let schema_bytes: usize = self
+            .schema
+            .fields
+            .iter()
+            .map(|f| {
+                let variants = match &f.data_type {
+                    DataType::Enum(values) => values.iter().map(String::len).sum(),
+                    _ => 0,
+                };
+                std::mem::size_of::<Field>() + f.name.len() + variants
+            })
+            .sum();
+        let row_bytes: usize = self
+            .rows
+            .iter()
+            .map(|row| {
+                let payload: usize = row
+                    .iter()
+                    .map(|v| match v {
+                        Value::Utf8(s) => s.len(),
+                        Value::Bytes(b) => b.len(),
+                        _ => 0,
+                    })
+                    .sum();
+                std::mem::size_of::<Vec<Value>>()
+                    + row.len() * std::mem::size_of::<Value>()
+                    + payload
+            })
+            .sum();
+        schema_bytes + row_bytes
+    }
+
+    /// Compare with `other` ignoring row order: schemas must be equal and the rows must match
+    /// as multisets (duplicates are counted).
+    ///
+    /// Unlike `==`, `Float64` cells compare by bit pattern, so `NaN` equals an identical `NaN`
+    /// and `0.0` differs from `-0.0`. Useful for testing operations whose output order is
+    /// unspecified (e.g. parallel group-by or joins).
+    pub fn equals_unordered(&self, other: &DataSet) -> bool {
+        if self.schema != other.schema || self.rows.len() != other.rows.len() {
+            return false;
+        }
+        let mut counts: HashMap<Vec<CellKey<'_>>, isize> = HashMap::new();
+        for row in &self.rows {
+            *counts
+                .entry(row.iter().map(CellKey::from).collect())
+                .or_default() += 1;
+        }
+        for row in &other.rows {
+            let key: Vec<CellKey<'_>> = row.iter().map(CellKey::from).collect();
+            match counts.get_mut(&key) {
+                Some(n) if *n > 0 => *n -= 1,
+                _ => return false,
+            }
+        }
+        true
+    }
+
+    /// Returns the value at (`row`, `col`), or `None` if either index is out of range.
+    pub fn get(&self, row: usize, col: usize) -> Option<&Value> {
+        self.rows.get(row)?.get(col)
+    }
+
+    /// Returns the value at `row` in column `col`, or `None` if the row or column does not exist.
+    pub fn get_named(&self, row: usize, col: &str) -> Option<&Value> {
+        self.get(row, self.schema.index_of(col)?)
+    }
+
+    /// Iterate rows as `(field_name, value)` pairs in schema order.
+    ///
+    /// Names and values are borrowed from the dataset; no cells are cloned.
+    pub fn iter_named_rows(&self) -> impl Iterator<Item = Vec<(&str, &Value)>> {
+        self.rows
+            .iter()
+            .map(|row| self.schema.field_names().zip(row.iter()).collect())
+    }
+
+    /// Iterate rows in consecutive windows of `size` (the last window holds the remainder).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size == 0`, like [`crate::execution::ExecutionOptions::chunk_size`].
+    pub fn chunks(&self, size: usize) -> impl Iterator<Item = &[Vec<Value>]> {
+        assert!(size > 0, "chunk size must be > 0");
+        self.rows.chunks(size)
+    }
+
+    /// Indices (0-based, ascending) of the rows that match `predicate`, without copying rows.
+    pub fn filter_indices<F>(&self, mut predicate: F) -> Vec<usize>
+    where
+        F: FnMut(&[Value]) -> bool,
+    {
+        self.rows
+            .iter()
+            .enumerate()
+            .filter(|(_, row)| predicate(row.as_slice()))
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+
     /// Create a new dataset containing only rows that match `predicate`.
     ///
     /// The returned dataset preserves the original schema.
@@ -127,13 +1161,27 @@ impl DataSet {
     pub fn map_rows<F>(&self, mut mapper: F) -> Self
     where
         F: FnMut(&[Value]) -> Vec<Value>,
+    {
+        self.map_rows_indexed(|_, row| mapper(row))
+    }
+
+    /// Like [`DataSet::map_rows`], but `mapper` also receives the 0-based row index (e.g. to
+    /// assign sequence IDs).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mapper` returns a row with a different length than the schema field count.
+    pub fn map_rows_indexed<F>(&self, mut mapper: F) -> Self
+    where
+        F: FnMut(usize, &[Value]) -> Vec<Value>,
     {
         let expected_len = self.schema.fields.len();
         let rows = self
             .rows
             .iter()
-            .map(|row| {
-                let out = mapper(row.as_slice());
+            .enumerate()
+            .map(|(idx, row)| {
+                let out = mapper(idx, row.as_slice());
                 assert!(
                     out.len() == expected_len,
                     "mapped row length {} does not match schema length {}",
@@ -150,6 +1198,40 @@ impl DataSet {
         }
     }
 
+    /// Create a new dataset by applying `mapper` to every row, keeping only `Some` results.
+    ///
+    /// This is a single-pass alternative to [`DataSet::filter_rows`] followed by
+    /// [`DataSet::map_rows`]. The returned dataset preserves the original schema.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mapper` returns a row with a different length than the schema field count.
+    pub fn filter_map_rows<F>(&self, mut mapper: F) -> Self
+    where
+        F: FnMut(&[Value]) -> Option<Vec<Value>>,
+    {
+        let expected_len = self.schema.fields.len();
+        let rows = self
+            .rows
+            .iter()
+            .filter_map(|row| {
+                let out = mapper(row.as_slice())?;
+                assert!(
+                    out.len() == expected_len,
+                    "mapped row length {} does not match schema length {}",
+                    out.len(),
+                    expected_len
+                );
+                Some(out)
+            })
+            .collect();
+
+        Self {
+            schema: self.schema.clone(),
+            rows,
+        }
+    }
+
     /// Reduce (fold) all rows into an accumulator value.
     ///
     /// This is similar to `Iterator::fold`, but provides each row as `&[Value]`.
@@ -161,4 +1243,750 @@ impl DataSet {
             .iter()
             .fold(init, |acc, row| reducer(acc, row.as_slice()))
     }
+
+    /// Transpose into one `Vec<Value>` per schema field (column-major), consuming the dataset.
+    ///
+    /// Each inner vector has `row_count()` cells in row order. Cells missing from short rows
+    /// read as [`Value::Null`]; cells beyond the schema are dropped. This is a one-time
+    /// O(rows × cols) conversion for column-at-a-time algorithms; values are moved, not cloned.
+    pub fn into_columns(self) -> Vec<Vec<Value>> {
+        let width = self.schema.fields.len();
+        let mut columns: Vec<Vec<Value>> = (0..width)
+            .map(|_| Vec::with_capacity(self.rows.len()))
+            .collect();
+        for row in self.rows {
+            let mut cells = row.into_iter();
+            for column in &mut columns {
+                column.push(cells.next().unwrap_or(Value::Null));
+            }
+        }
+        columns
+    }
+
+    /// Borrowing form of [`DataSet::into_columns`]: one `Vec<&Value>` per schema field, same
+    /// O(rows × cols) cost but no cells are cloned.
+    pub fn columns(&self) -> Vec<Vec<&Value>> {
+        static NULL: Value = Value::Null;
+        (0..self.schema.fields.len())
+            .map(|idx| {
+                self.rows
+                    .iter()
+                    .map(|row| row.get(idx).unwrap_or(&NULL))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Project a numeric column into `Vec<Option<f64>>`.
+    ///
+    /// `Value::Null` maps to `None` and `Int64` values are widened to `f64`.
+    ///
+    /// Returns [`IngestionError::SchemaMismatch`] if the column is missing, or
+    /// [`IngestionError::ParseError`] (with a 1-based row number) if a cell is not numeric.
+    pub fn column_f64(&self, name: &str) -> IngestionResult<Vec<Option<f64>>> {
+        self.column_typed(name, "Float64", |v| match v {
+            Value::Float64(x) => Some(*x),
+            Value::Int64(x) => Some(*x as f64),
+            _ => None,
+        })
+    }
+
+    /// Project an `Int64` column into `Vec<Option<i64>>` (`Value::Null` maps to `None`).
+    ///
+    /// Errors are reported the same way as [`DataSet::column_f64`].
+    pub fn column_i64(&self, name: &str) -> IngestionResult<Vec<Option<i64>>> {
+        self.column_typed(name, "Int64", |v| match v {
+            Value::Int64(x) => Some(*x),
+            _ => None,
+        })
+    }
+
+    /// Project a `Utf8` column into `Vec<Option<&str>>` (`Value::Null` maps to `None`).
+    ///
+    /// Errors are reported the same way as [`DataSet::column_f64`].
+    pub fn column_str(&self, name: &str) -> IngestionResult<Vec<Option<&str>>> {
+        self.column_typed(name, "Utf8", |v| match v {
+            Value::Utf8(s) => Some(s.as_str()),
+            _ => None,
+        })
+    }
+
+    fn column_typed<'a, T>(
+        &'a self,
+        name: &str,
+        expected: &str,
+        extract: impl Fn(&'a Value) -> Option<T>,
+    ) -> IngestionResult<Vec<Option<T>>> {
+        let idx = self
+            .schema
+            .index_of(name)
+            .ok_or_else(|| IngestionError::SchemaMismatch {
+                message: format!("missing required column '{name}'"),
+            })?;
+
+        let mut out = Vec::with_capacity(self.rows.len());
+        for (row_idx0, row) in self.rows.iter().enumerate() {
+            match row.get(idx) {
+                Some(Value::Null) | None => out.push(None),
+                Some(v) => match extract(v) {
+                    Some(x) => out.push(Some(x)),
+                    None => {
+                        return Err(IngestionError::ParseError {
+                            row: row_idx0 + 1,
+                            column: name.to_string(),
+                            raw: format!("{v:?}"),
+                            message: format!("value does not match requested type {expected}"),
+                        });
+                    }
+                },
+            }
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::IngestionError;
+    use crate::types::{DataSet, DataType, Field, Schema, Value};
+
+    fn sample_dataset() -> DataSet {
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int64),
+            Field::new("score", DataType::Float64),
+            Field::new("name", DataType::Utf8),
+        ]);
+        let rows = vec![
+            vec![
+                Value::Int64(1),
+                Value::Float64(1.5),
+                Value::Utf8("a".to_string()),
+            ],
+            vec![Value::Int64(2), Value::Null, Value::Null],
+        ];
+        DataSet::new(schema, rows)
+    }
+
+    #[test]
+    fn value_data_type_reports_each_variant() {
+        assert_eq!(Value::Null.data_type(), None);
+        assert_eq!(Value::Int64(1).data_type(), Some(DataType::Int64));
+        assert_eq!(Value::Float64(1.0).data_type(), Some(DataType::Float64));
+        assert_eq!(Value::Bool(true).data_type(), Some(DataType::Bool));
+        assert_eq!(
+            Value::Utf8("a".to_string()).data_type(),
+            Some(DataType::Utf8)
+        );
+        assert_eq!(Value::Bytes(vec![1]).data_type(), Some(DataType::Bytes));
+    }
+
+    #[test]
+    fn value_cmp_typed_orders_same_and_numeric_cross_types() {
+        use std::cmp::Ordering;
+
+        assert_eq!(
+            Value::Int64(2).cmp_typed(&Value::Int64(3)),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            Value::Utf8("b".to_string()).cmp_typed(&Value::Utf8("a".to_string())),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            Value::Bool(false).cmp_typed(&Value::Bool(true)),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            Value::Int64(2).cmp_typed(&Value::Float64(1.5)),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            Value::Float64(2.0).cmp_typed(&Value::Int64(2)),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(Value::Null.cmp_typed(&Value::Null), Some(Ordering::Equal));
+        assert_eq!(
+            Value::Null.cmp_typed(&Value::Utf8("a".to_string())),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            Value::Int64(1).cmp_typed(&Value::Null),
+            Some(Ordering::Greater)
+        );
+
+        assert_eq!(
+            Value::Int64(1).cmp_typed(&Value::Utf8("1".to_string())),
+            None
+        );
+        assert_eq!(Value::Bool(true).cmp_typed(&Value::Int64(1)), None);
+        assert_eq!(
+            Value::Bytes(vec![1]).cmp_typed(&Value::Utf8("a".to_string())),
+            None
+        );
+    }
+
+    #[test]
+    fn value_coerce_to_widens_parses_and_formats() {
+        let s = |v: &str| Value::Utf8(v.to_string());
+        assert_eq!(
+            Value::Int64(3).coerce_to(&DataType::Float64),
+            Ok(Value::Float64(3.0))
+        );
+        assert_eq!(s(" 42 ").coerce_to(&DataType::Int64), Ok(Value::Int64(42)));
+        assert_eq!(
+            s("1.5").coerce_to(&DataType::Float64),
+            Ok(Value::Float64(1.5))
+        );
+        assert_eq!(s("Yes").coerce_to(&DataType::Bool), Ok(Value::Bool(true)));
+        assert_eq!(s("0").coerce_to(&DataType::Bool), Ok(Value::Bool(false)));
+        assert_eq!(Value::Int64(7).coerce_to(&DataType::Utf8), Ok(s("7")));
+        assert_eq!(Value::Float64(2.5).coerce_to(&DataType::Utf8), Ok(s("2.5")));
+        assert_eq!(Value::Bool(true).coerce_to(&DataType::Utf8), Ok(s("true")));
+        assert_eq!(Value::Null.coerce_to(&DataType::Int64), Ok(Value::Null));
+        assert_eq!(s("a").coerce_to(&DataType::Utf8), Ok(s("a")));
+
+        let level = DataType::Enum(vec!["low".to_string(), "high".to_string()]);
+        assert_eq!(s("low").coerce_to(&level), Ok(s("low")));
+        assert!(s("mid").coerce_to(&level).is_err());
+    }
+
+    #[test]
+    fn value_coerce_to_reports_unsupported_or_unparseable() {
+        let err = Value::Utf8("ten".to_string())
+            .coerce_to(&DataType::Int64)
+            .unwrap_err();
+        assert!(err.starts_with("cannot parse 'ten' as int64"), "{err}");
+        assert_eq!(
+            Value::Float64(1.5).coerce_to(&DataType::Int64),
+            Err("cannot coerce float64 to int64".to_string())
+        );
+        assert!(Value::Bool(true).coerce_to(&DataType::Bytes).is_err());
+    }
+
+    #[test]
+    fn timestamp_parses_iso_8601_and_displays_rfc_3339() {
+        let ts = |s: &str| Value::Utf8(s.to_string()).coerce_to(&DataType::Timestamp);
+        let noon = 1_609_502_400_000_000;
+        assert_eq!(ts("2021-01-01T12:00:00Z"), Ok(Value::Timestamp(noon)));
+        assert_eq!(ts("2021-01-01 12:00"), Ok(Value::Timestamp(noon)));
+        assert_eq!(ts("2021-01-01T14:00:00+02:00"), Ok(Value::Timestamp(noon)));
+        assert_eq!(
+            ts("2021-01-01T12:00:00.000123456Z"),
+            Ok(Value::Timestamp(noon + 123))
+        );
+        assert_eq!(
+            ts("2021-01-01"),
+            Ok(Value::Timestamp(noon - 43_200_000_000))
+        );
+        assert_eq!(ts("1969-12-31T23:59:59.5Z"), Ok(Value::Timestamp(-500_000)));
+        for bad in ["2021-02-29", "2021-01-01T25:00", "2021-1-1", "noon", ""] {
+            assert!(ts(bad).is_err(), "{bad}");
+        }
+
+        assert_eq!(
+            Value::Timestamp(noon + 500_000).to_string(),
+            "2021-01-01T12:00:00.5Z"
+        );
+        assert_eq!(
+            Value::Timestamp(-500_000).to_string(),
+            "1969-12-31T23:59:59.5Z"
+        );
+        assert_eq!(Value::Timestamp(0).to_string(), "1970-01-01T00:00:00Z");
+        assert_eq!(
+            Value::Timestamp(noon).coerce_to(&DataType::Int64),
+            Ok(Value::Int64(noon))
+        );
+        let text = Value::Timestamp(noon + 7)
+            .coerce_to(&DataType::Utf8)
+            .unwrap();
+        assert_eq!(
+            text.coerce_to(&DataType::Timestamp),
+            Ok(Value::Timestamp(noon + 7))
+        );
+    }
+
+    #[test]
+    fn value_matches_type_accepts_null_and_own_type_only() {
+        let all = [
+            DataType::Int64,
+            DataType::Float64,
+            DataType::Bool,
+            DataType::Utf8,
+            DataType::Bytes,
+            DataType::Timestamp,
+        ];
+        let values = [
+            Value::Int64(1),
+            Value::Float64(1.0),
+            Value::Bool(true),
+            Value::Utf8("a".to_string()),
+            Value::Bytes(vec![1]),
+            Value::Timestamp(1),
+        ];
+        for v in &values {
+            for dt in &all {
+                assert_eq!(v.matches_type(dt), v.data_type().as_ref() == Some(dt));
+            }
+            assert!(Value::Null.matches_type(v.data_type().as_ref().unwrap()));
+        }
+
+        let status = DataType::Enum(vec!["open".to_string(), "closed".to_string()]);
+        assert!(Value::Utf8("open".to_string()).matches_type(&status));
+        assert!(!Value::Utf8("OPEN".to_string()).matches_type(&status));
+        assert!(Value::Null.matches_type(&status));
+        assert!(!Value::Int64(1).matches_type(&status));
+    }
+
+    #[test]
+    fn schema_builder_matches_manual_schema_and_rejects_duplicates() {
+        let built = Schema::builder()
+            .int64("id")
+            .utf8("name")
+            .float64("score")
+            .bool("active")
+            .field("extra", DataType::Utf8)
+            .build()
+            .unwrap();
+        assert_eq!(
+            built,
+            Schema::new(vec![
+                Field::new("id", DataType::Int64),
+                Field::new("name", DataType::Utf8),
+                Field::new("score", DataType::Float64),
+                Field::new("active", DataType::Bool),
+                Field::new("extra", DataType::Utf8),
+            ])
+        );
+
+        let err = Schema::builder()
+            .int64("id")
+            .utf8("id")
+            .build()
+            .unwrap_err();
+        assert!(err.to_string().contains("duplicate field name 'id'"));
+    }
+
+    #[test]
+    fn schema_compatibility_reports_first_divergent_field() {
+        let a = Schema::builder().int64("id").utf8("name").build().unwrap();
+        let same = a.clone();
+        let retyped = Schema::builder().int64("id").int64("name").build().unwrap();
+        let renamed = Schema::builder().int64("id").utf8("label").build().unwrap();
+        let shorter = Schema::builder().int64("id").build().unwrap();
+
+        assert!(a.is_compatible_with(&same).is_ok());
+        assert!(a.matches_names(&retyped));
+        assert!(!a.matches_names(&renamed));
+
+        let err = a.is_compatible_with(&retyped).unwrap_err().to_string();
+        assert!(err.contains("field 'name' type differs"));
+        let err = a.is_compatible_with(&renamed).unwrap_err().to_string();
+        assert!(err.contains("'name' vs 'label'"));
+        let err = a.is_compatible_with(&shorter).unwrap_err().to_string();
+        assert!(err.contains("field count differs: 2 vs 1"));
+    }
+
+    #[test]
+    fn schema_union_appends_new_fields_and_rejects_type_conflicts() {
+        let a = Schema::builder().int64("id").utf8("name").build().unwrap();
+        let b = Schema::builder()
+            .float64("score")
+            .int64("id")
+            .bool("active")
+            .build()
+            .unwrap();
+
+        let u = a.union(&b).unwrap();
+        assert_eq!(
+            u.field_names().collect::<Vec<_>>(),
+            vec!["id", "name", "score", "active"]
+        );
+        assert_eq!(u.data_type_of("score"), Some(&DataType::Float64));
+        assert_eq!(a.union(&a).unwrap(), a);
+
+        let conflicting = Schema::builder().utf8("id").build().unwrap();
+        let err = a.union(&conflicting).unwrap_err().to_string();
+        assert!(err.contains("field 'id' type differs"));
+    }
+
+    #[test]
+    fn validate_types_reports_the_first_wrong_typed_cell() {
+        let mut ds = sample_dataset();
+        ds.validate_types().unwrap();
+
+        ds.rows[1][1] = Value::Utf8("oops".to_string());
+        match ds.validate_types().unwrap_err() {
+            IngestionError::ParseError {
+                row,
+                column,
+                raw,
+                message,
+            } => {
+                assert_eq!(row, 2);
+                assert_eq!(column, "score");
+                assert_eq!(raw, "oops");
+                assert_eq!(message, "expected float64, found utf8");
+            }
+            other => panic!("expected ParseError, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn filter_indices_returns_matching_positions_in_order() {
+        let mut ds = sample_dataset();
+        ds.rows
+            .push(vec![Value::Int64(3), Value::Float64(0.5), Value::Null]);
+        assert_eq!(
+            ds.filter_indices(|row| matches!(row[2], Value::Null)),
+            vec![1, 2]
+        );
+        assert_eq!(ds.filter_indices(|_| true), vec![0, 1, 2]);
+        assert!(ds.filter_indices(|_| false).is_empty());
+    }
+
+    #[test]
+    fn shape_reports_rows_and_columns() {
+        let ds = sample_dataset();
+        assert_eq!(ds.shape(), (2, 3));
+        assert_eq!(ds.column_count(), 3);
+        assert!(!ds.is_empty());
+
+        let empty = DataSet::new(ds.schema.clone(), Vec::new());
+        assert!(empty.is_empty());
+        assert_eq!(empty.shape(), (0, 3));
+    }
+
+    #[test]
+    fn column_type_looks_up_declared_type() {
+        let ds = sample_dataset();
+        assert_eq!(ds.column_type("score"), Some(&DataType::Float64));
+        assert_eq!(ds.schema.data_type_of("name"), Some(&DataType::Utf8));
+        assert_eq!(ds.column_type("missing"), None);
+    }
+
+    #[test]
+    fn into_columns_transposes_and_round_trips() {
+        let ds = sample_dataset();
+        let borrowed = ds.columns();
+        assert_eq!(borrowed.len(), 3);
+        assert_eq!(borrowed[1], vec![&Value::Float64(1.5), &Value::Null]);
+
+        let columns = ds.clone().into_columns();
+        assert_eq!(
+            columns[0],
+            borrowed[0].iter().map(|v| (*v).clone()).collect::<Vec<_>>()
+        );
+        let rows: Vec<Vec<Value>> = (0..ds.row_count())
+            .map(|r| columns.iter().map(|c| c[r].clone()).collect())
+            .collect();
+        assert_eq!(DataSet::new(ds.schema.clone(), rows), ds);
+
+        let mut short = ds.clone();
+        short.rows[1].truncate(1);
+        assert_eq!(
+            short.into_columns()[2],
+            vec![Value::Utf8("a".to_string()), Value::Null]
+        );
+    }
+
+    #[test]
+    fn try_new_rejects_a_short_row() {
+        let ds = sample_dataset();
+        assert_eq!(
+            DataSet::try_new(ds.schema.clone(), ds.rows.clone()).unwrap(),
+            ds
+        );
+
+        let mut rows = ds.rows.clone();
+        rows.push(vec![Value::Int64(3)]);
+        let err = DataSet::try_new(ds.schema.clone(), rows).unwrap_err();
+        assert!(matches!(err, IngestionError::SchemaMismatch { .. }));
+        assert!(
+            err.to_string()
+                .contains("row 3 has 1 values but schema has 3 fields")
+        );
+    }
+
+    #[test]
+    fn append_rows_rejects_a_bad_batch_without_partial_writes() {
+        let mut ds = sample_dataset();
+        let batch = vec![
+            vec![Value::Int64(3), Value::Null, Value::Null],
+            vec![Value::Int64(4), Value::Null],
+        ];
+        let err = ds.append_rows(batch).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("row 4 has 2 values but schema has 3 fields")
+        );
+        assert_eq!(ds, sample_dataset());
+
+        ds.append_rows(vec![vec![Value::Int64(3), Value::Null, Value::Null]])
+            .unwrap();
+        assert_eq!(ds.row_count(), 3);
+    }
+
+    #[test]
+    fn reorder_columns_permutes_schema_and_rows() {
+        let mut ds = sample_dataset();
+        ds.reorder_columns(&["name", "id", "score"]).unwrap();
+        assert_eq!(
+            ds.schema.field_names().collect::<Vec<_>>(),
+            vec!["name", "id", "score"]
+        );
+        assert_eq!(ds.schema.fields[1].data_type, DataType::Int64);
+        assert_eq!(
+            ds.rows[0],
+            vec![
+                Value::Utf8("a".to_string()),
+                Value::Int64(1),
+                Value::Float64(1.5)
+            ]
+        );
+
+        let before = ds.clone();
+        for bad in [
+            &["name", "id"][..],
+            &["name", "id", "id"],
+            &["name", "id", "score", "extra"],
+        ] {
+            let err = ds.reorder_columns(bad).unwrap_err();
+            assert!(matches!(err, IngestionError::SchemaMismatch { .. }));
+        }
+        assert_eq!(ds, before);
+    }
+
+    #[test]
+    fn push_row_enforces_schema_arity() {
+        let schema = sample_dataset().schema;
+        let mut ds = DataSet::with_capacity(schema, 4);
+        assert!(ds.rows.capacity() >= 4);
+        ds.push_row(vec![Value::Int64(1), Value::Null, Value::Null])
+            .unwrap();
+
+        let err = ds.push_row(vec![Value::Int64(2)]).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("row 2 has 1 values but schema has 3 fields")
+        );
+        assert_eq!(ds.row_count(), 1);
+    }
+
+    #[test]
+    fn to_pretty_string_aligns_columns_and_reports_hidden_rows() {
+        let ds = sample_dataset();
+        assert_eq!(
+            ds.to_pretty_string(1),
+            "id | score | name\n---+-------+-----\n1  | 1.5   | a\n… 1 more row\n"
+        );
+        assert_eq!(
+            ds.to_pretty_string(10),
+            "id | score | name\n---+-------+-----\n1  | 1.5   | a\n2  | null  | null\n"
+        );
+        assert_eq!(Value::Bytes(vec![0, 255]).to_string(), "0x00ff");
+    }
+
+    #[test]
+    fn estimated_bytes_grows_with_rows_and_string_payloads() {
+        let ds = sample_dataset();
+        let empty = DataSet::new(ds.schema.clone(), Vec::new());
+        let cell = std::mem::size_of::<Value>();
+        let row = std::mem::size_of::<Vec<Value>>() + 3 * cell;
+        // Row 0 carries a one-byte string ("a"); row 1 has no payload.
+        assert_eq!(ds.estimated_bytes(), empty.estimated_bytes() + 2 * row + 1);
+        assert!(empty.estimated_bytes() >= "idscorename".len());
+    }
+
+    #[test]
+    fn equals_unordered_treats_rows_as_a_multiset() {
+        let ds = sample_dataset();
+        let mut reversed = ds.clone();
+        reversed.rows.reverse();
+        assert!(ds.equals_unordered(&reversed));
+
+        // Same length, but duplicates must be counted.
+        let mut dup = ds.clone();
+        dup.rows[1] = dup.rows[0].clone();
+        assert!(!ds.equals_unordered(&dup));
+
+        let mut nan = ds.clone();
+        nan.rows[0][1] = Value::Float64(f64::NAN);
+        assert!(nan.equals_unordered(&nan.clone()));
+        assert_ne!(nan, nan.clone());
+
+        let renamed = DataSet::new(
+            Schema::new(vec![Field::new("other", DataType::Int64)]),
+            Vec::new(),
+        );
+        assert!(!ds.equals_unordered(&renamed));
+    }
+
+    #[test]
+    fn chunks_yields_fixed_windows_with_remainder() {
+        let schema = Schema::new(vec![Field::new("id", DataType::Int64)]);
+        let ds = DataSet::new(schema, (0..10).map(|i| vec![Value::Int64(i)]).collect());
+        let sizes: Vec<usize> = ds.chunks(3).map(<[_]>::len).collect();
+        assert_eq!(sizes, vec![3, 3, 3, 1]);
+        let last = ds.chunks(3).last().unwrap();
+        assert_eq!(last, &[vec![Value::Int64(9)]]);
+        assert_eq!(ds.chunks(20).count(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be > 0")]
+    fn chunks_rejects_zero_size() {
+        let _ = sample_dataset().chunks(0);
+    }
+
+    #[test]
+    fn get_and_get_named_return_none_when_out_of_range() {
+        let ds = sample_dataset();
+        assert_eq!(ds.get(0, 1), Some(&Value::Float64(1.5)));
+        assert_eq!(ds.get_named(1, "id"), Some(&Value::Int64(2)));
+        assert_eq!(ds.get(2, 0), None);
+        assert_eq!(ds.get(0, 3), None);
+        assert_eq!(ds.get_named(0, "missing"), None);
+    }
+
+    #[test]
+    fn iter_named_rows_zips_field_names_with_values() {
+        let ds = sample_dataset();
+        let rows: Vec<_> = ds.iter_named_rows().collect();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(
+            rows[1],
+            vec![
+                ("id", &Value::Int64(2)),
+                ("score", &Value::Null),
+                ("name", &Value::Null),
+            ]
+        );
+    }
+
+    #[test]
+    fn typed_column_extraction_maps_nulls_and_widens_ints() {
+        let ds = sample_dataset();
+        assert_eq!(ds.column_f64("score").unwrap(), vec![Some(1.5), None]);
+        assert_eq!(ds.column_f64("id").unwrap(), vec![Some(1.0), Some(2.0)]);
+        assert_eq!(ds.column_i64("id").unwrap(), vec![Some(1), Some(2)]);
+        assert_eq!(ds.column_str("name").unwrap(), vec![Some("a"), None]);
+    }
+
+    #[test]
+    fn data_type_parses_names_case_insensitively_and_round_trips() {
+        for (name, dt) in [
+            ("int64", DataType::Int64),
+            ("Float64", DataType::Float64),
+            ("BOOL", DataType::Bool),
+            (" utf8 ", DataType::Utf8),
+            ("bytes", DataType::Bytes),
+        ] {
+            let parsed: DataType = name.parse().unwrap();
+            assert_eq!(parsed, dt);
+            assert_eq!(dt.as_str().parse::<DataType>().unwrap(), dt);
+        }
+        assert_eq!(DataType::Enum(vec!["a".to_string()]).as_str(), "enum");
+        for bad in ["int", "enum", ""] {
+            assert!(matches!(
+                bad.parse::<DataType>(),
+                Err(IngestionError::SchemaMismatch { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn schema_round_trips_through_json_with_lowercase_types() {
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int64).with_int_range(0..=100),
+            Field::new("score", DataType::Float64),
+            Field::new("active", DataType::Bool),
+            Field::new(
+                "tier",
+                DataType::Enum(vec!["gold".to_string(), "silver".to_string()]),
+            ),
+        ]);
+        let json = serde_json::to_string(&schema).unwrap();
+        assert!(json.contains(r#""data_type":"int64""#), "{json}");
+        assert!(
+            json.contains(r#""data_type":{"enum":["gold","silver"]}"#),
+            "{json}"
+        );
+        assert_eq!(serde_json::from_str::<Schema>(&json).unwrap(), schema);
+
+        let legacy = r#"{"fields":[{"name":"id","data_type":"Int64"}]}"#;
+        assert_eq!(
+            serde_json::from_str::<Schema>(legacy).unwrap(),
+            Schema::new(vec![Field::new("id", DataType::Int64)])
+        );
+    }
+
+    #[test]
+    fn dataset_round_trips_through_json_with_plain_cells() {
+        let ds = sample_dataset();
+        let json = serde_json::to_string(&ds).unwrap();
+        assert!(
+            json.contains(r#""rows":[[1,1.5,"a"],[2,null,null]]"#),
+            "{json}"
+        );
+        assert_eq!(serde_json::from_str::<DataSet>(&json).unwrap(), ds);
+
+        let cells = vec![
+            Value::Float64(2.0),
+            Value::Bool(true),
+            Value::Bytes(vec![0, 255]),
+        ];
+        let json = serde_json::to_string(&cells).unwrap();
+        assert_eq!(json, "[2.0,true,[0,255]]");
+        assert_eq!(serde_json::from_str::<Vec<Value>>(&json).unwrap(), cells);
+
+        let legacy = r#"[{"Int64":7},{"Float64":0.5},{"Null":null},{"Utf8":"x"}]"#;
+        assert_eq!(
+            serde_json::from_str::<Vec<Value>>(legacy).unwrap(),
+            vec![
+                Value::Int64(7),
+                Value::Float64(0.5),
+                Value::Null,
+                Value::Utf8("x".to_string())
+            ]
+        );
+        assert!(serde_json::from_str::<Value>(r#"{"Decimal":1}"#).is_err());
+
+        // Timestamp cells are RFC 3339 text and read back as Timestamp under their column.
+        let ds = DataSet::new(
+            Schema::new(vec![
+                Field::new("at", DataType::Timestamp),
+                Field::new("note", DataType::Utf8),
+            ]),
+            vec![
+                vec![
+                    Value::Timestamp(1_609_459_200_500_000),
+                    Value::Utf8("2021-01-01T00:00:00Z".to_string()),
+                ],
+                vec![Value::Null, Value::Null],
+            ],
+        );
+        let json = serde_json::to_string(&ds).unwrap();
+        assert!(json.contains(r#"["2021-01-01T00:00:00.5Z","#), "{json}");
+        let back = serde_json::from_str::<DataSet>(&json).unwrap();
+        assert_eq!(back, ds);
+        assert!(back.validate_types().is_ok());
+        let bad = json.replace("2021-01-01T00:00:00.5Z", "soon");
+        assert!(serde_json::from_str::<DataSet>(&bad).is_err());
+    }
+
+    #[test]
+    fn typed_column_extraction_reports_missing_and_mismatched_columns() {
+        let ds = sample_dataset();
+        assert!(matches!(
+            ds.column_f64("missing"),
+            Err(IngestionError::SchemaMismatch { .. })
+        ));
+        match ds.column_i64("score") {
+            Err(IngestionError::ParseError { row, column, .. }) => {
+                assert_eq!(row, 1);
+                assert_eq!(column, "score");
+            }
+            other => panic!("expected ParseError, got {other:?}"),
+        }
+    }
 }
@@ -30,6 +30,9 @@
 //! # }
 //! ```
 
+use base64::Engine as _;
+use base64::engine::general_purpose::STANDARD as BASE64;
+
 use crate::error::{IngestionError, IngestionResult};
 use crate::pipeline::DataFrame;
 use crate::types::{DataSet, Value};
@@ -338,7 +341,23 @@ fn values_to_series(values: &[Value]) -> Series {
             }
             Series::new("set".into(), v)
         }
-        Value::Float64(_) | Value::Null => Series::new("set".into(), Vec::<String>::new()),
+        Value::Timestamp(_) => {
+            let mut v: Vec<i64> = Vec::with_capacity(values.len());
+            for x in values {
+                if let Value::Timestamp(t) = x {
+                    v.push(*t);
+                }
+            }
+            let s = Series::new("set".into(), v);
+            s.cast(&polars::datatypes::DataType::Datetime(
+                TimeUnit::Microseconds,
+                None,
+            ))
+            .unwrap_or(s)
+        }
+        Value::Float64(_) | Value::Bytes(_) | Value::Null => {
+            Series::new("set".into(), Vec::<String>::new())
+        }
     }
 }
 
@@ -438,6 +457,8 @@ fn any_to_value(v: AnyValue) -> Value {
         AnyValue::Float64(x) => Value::Float64(x),
         AnyValue::String(s) => Value::Utf8(s.to_string()),
         AnyValue::StringOwned(s) => Value::Utf8(s.to_string()),
+        // Timestamp columns are built as microseconds by `dataset_to_dataframe`.
+        AnyValue::Datetime(t, TimeUnit::Microseconds, _) => Value::Timestamp(t),
         other => Value::Utf8(other.to_string()),
     }
 }
@@ -449,6 +470,8 @@ fn value_to_json(v: &Value) -> serde_json::Value {
         Value::Float64(x) => serde_json::json!(x),
         Value::Bool(b) => serde_json::json!(b),
         Value::Utf8(s) => serde_json::json!(s),
+        Value::Bytes(b) => serde_json::json!(BASE64.encode(b)),
+        Value::Timestamp(_) => serde_json::json!(v.to_string()),
     }
 }
 
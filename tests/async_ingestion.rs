@@ -0,0 +1,59 @@
+#![cfg(feature = "tokio")]
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use rust_data_processing::ingestion::{
+    IngestionContext, IngestionObserver, IngestionOptions, IngestionStats, ingest_from_path,
+    ingest_from_path_async,
+};
+use rust_data_processing::types::{DataType, Field, Schema};
+
+#[derive(Default)]
+struct SuccessCounter(AtomicUsize);
+
+impl IngestionObserver for SuccessCounter {
+    fn on_success(&self, _ctx: &IngestionContext, stats: IngestionStats) {
+        self.0.fetch_add(stats.rows, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn async_ingestion_matches_sync_and_forwards_observer() {
+    let schema = Schema::new(vec![
+        Field::new("id", DataType::Int64),
+        Field::new("name", DataType::Utf8),
+    ]);
+    let obs = Arc::new(SuccessCounter::default());
+    let opts = IngestionOptions {
+        observer: Some(obs.clone()),
+        ..Default::default()
+    };
+
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    let ds = rt
+        .block_on(ingest_from_path_async(
+            "tests/fixtures/people.csv",
+            &schema,
+            &opts,
+        ))
+        .unwrap();
+
+    let expected = ingest_from_path(
+        "tests/fixtures/people.csv",
+        &schema,
+        &IngestionOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(ds, expected);
+    assert_eq!(obs.0.load(Ordering::SeqCst), ds.row_count());
+
+    let missing = rt.block_on(ingest_from_path_async(
+        "tests/fixtures/does_not_exist.csv",
+        &schema,
+        &opts,
+    ));
+    assert!(missing.is_err());
+}
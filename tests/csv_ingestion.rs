@@ -1,6 +1,22 @@
-use rust_data_processing::ingestion::csv::{ingest_csv_from_path, ingest_csv_from_reader};
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rust_data_processing::ingestion::csv::{
+    infer_schema, ingest_csv_collect_errors, ingest_csv_from_path,
+    ingest_csv_from_path_with_options, ingest_csv_from_reader, ingest_csv_from_reader_with_options,
+    ingest_csv_parallel, ingest_csv_since, ingest_csv_since_with_options,
+};
+use rust_data_processing::ingestion::{CsvEncoding, FloatPolicy, IngestionOptions, NumericLocale};
 use rust_data_processing::types::{DataType, Field, Schema, Value};
 
+fn tmp_file(tag: &str) -> PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    std::env::temp_dir().join(format!("rust-data-processing-csv-{tag}-{nanos}.csv"))
+}
+
 fn people_schema() -> Schema {
     Schema::new(vec![
         Field::new("id", DataType::Int64),
@@ -68,3 +84,846 @@ fn ingest_csv_errors_on_type_parse() {
     assert!(msg.contains("failed to parse value"));
     assert!(msg.contains("column 'id'"));
 }
+
+#[test]
+fn ingest_csv_parses_iso_8601_timestamps() {
+    let schema = Schema::new(vec![
+        Field::new("id", DataType::Int64),
+        Field::new("at", DataType::Timestamp),
+    ]);
+    let input = "id,at\n1,2021-01-01T00:00:00.5Z\n2,\n3,1970-01-01 00:00:01+01:00\n4,soon\n";
+    let mut rdr = csv::Reader::from_reader(input.as_bytes());
+    let err = ingest_csv_from_reader(&mut rdr, &schema).unwrap_err();
+    assert!(err.to_string().contains("column 'at'"), "{err}");
+
+    let mut rdr = csv::Reader::from_reader(&input.as_bytes()[..input.len() - 7]);
+    let ds = ingest_csv_from_reader(&mut rdr, &schema).unwrap();
+    let at: Vec<Value> = ds.rows.iter().map(|r| r[1].clone()).collect();
+    assert_eq!(
+        at,
+        vec![
+            Value::Timestamp(1_609_459_200_500_000),
+            Value::Null,
+            Value::Timestamp(-3_599_000_000),
+        ]
+    );
+}
+
+#[test]
+fn infer_schema_types_columns_from_sample() {
+    let schema = infer_schema("tests/fixtures/infer_sample.csv", 100).unwrap();
+    assert_eq!(
+        schema,
+        Schema::new(vec![
+            Field::new("id", DataType::Int64),
+            Field::new("amount", DataType::Float64),
+            Field::new("label", DataType::Utf8),
+            Field::new("flag", DataType::Bool),
+            Field::new("code", DataType::Utf8),
+        ])
+    );
+
+    // Inferred schema is usable for ingestion.
+    let mut rdr = csv::Reader::from_path("tests/fixtures/infer_sample.csv").unwrap();
+    let ds = ingest_csv_from_reader(&mut rdr, &schema).unwrap();
+    assert_eq!(ds.row_count(), 4);
+}
+
+#[test]
+fn infer_schema_only_inspects_sample_rows() {
+    let schema = infer_schema("tests/fixtures/infer_sample.csv", 3).unwrap();
+    assert_eq!(schema.fields[4], Field::new("code", DataType::Int64));
+}
+
+#[test]
+fn ingest_csv_case_insensitive_headers_is_opt_in() {
+    let schema = people_schema();
+    let input = "ID,Name,SCORE,Active\n1,Ada,98.5,true\n";
+
+    let mut rdr = csv::Reader::from_reader(input.as_bytes());
+    let err = ingest_csv_from_reader(&mut rdr, &schema).unwrap_err();
+    assert!(err.to_string().contains("missing required column 'id'"));
+
+    let opts = IngestionOptions {
+        case_insensitive_headers: true,
+        ..Default::default()
+    };
+    let mut rdr = csv::Reader::from_reader(input.as_bytes());
+    let ds = ingest_csv_from_reader_with_options(&mut rdr, &schema, &opts).unwrap();
+    assert_eq!(ds.rows[0][0], Value::Int64(1));
+    assert_eq!(ds.rows[0][3], Value::Bool(true));
+
+    // Genuine misses still error.
+    let mut rdr = csv::Reader::from_reader("ID,Name,SCORE\n1,Ada,98.5\n".as_bytes());
+    let err = ingest_csv_from_reader_with_options(&mut rdr, &schema, &opts).unwrap_err();
+    assert!(err.to_string().contains("missing required column 'active'"));
+}
+
+#[test]
+fn ingest_csv_aliases_match_alternative_header_names() {
+    let schema = people_schema();
+    let options = IngestionOptions::builder()
+        .alias("Customer ID", "id")
+        .alias("full_name", "name")
+        .build();
+
+    let path = tmp_file("aliases");
+    std::fs::write(
+        &path,
+        "Customer ID,full_name,age,active,score\n7,Ada,36,true,9.5\n",
+    )
+    .unwrap();
+    let ds = ingest_csv_from_path_with_options(&path, &schema, &options).unwrap();
+    let _ = std::fs::remove_file(&path);
+    assert_eq!(ds.schema, schema);
+    assert_eq!(ds.rows[0][0], Value::Int64(7));
+    assert_eq!(ds.rows[0][1], Value::Utf8("Ada".to_string()));
+
+    // An exact header match wins over an alias.
+    let mut rdr = csv::Reader::from_reader(
+        "Customer ID,id,name,age,active,score\n1,2,Bo,3,false,1.0\n".as_bytes(),
+    );
+    let ds = ingest_csv_from_reader_with_options(&mut rdr, &schema, &options).unwrap();
+    assert_eq!(ds.rows[0][0], Value::Int64(2));
+
+    // Without the alias the renamed header is missing.
+    let mut rdr = csv::Reader::from_reader("Customer ID,name\n1,Bo\n".as_bytes());
+    assert!(ingest_csv_from_reader(&mut rdr, &schema).is_err());
+}
+
+#[test]
+fn ingest_csv_custom_parser_replaces_builtin_bool_parsing() {
+    let schema = Schema::new(vec![
+        Field::new("id", DataType::Int64),
+        Field::new("active", DataType::Bool),
+    ]);
+    let options = IngestionOptions::builder()
+        .parser(DataType::Bool, |s| match s.trim() {
+            "on" => Ok(Value::Bool(true)),
+            "off" => Ok(Value::Bool(false)),
+            other => Err(format!("expected on/off, got '{other}'")),
+        })
+        .build();
+
+    let mut rdr = csv::Reader::from_reader("id,active\n1,on\n2, off\n3,\n".as_bytes());
+    let ds = ingest_csv_from_reader_with_options(&mut rdr, &schema, &options).unwrap();
+    let active: Vec<&Value> = ds.rows.iter().map(|r| &r[1]).collect();
+    assert_eq!(
+        active,
+        vec![&Value::Bool(true), &Value::Bool(false), &Value::Null]
+    );
+
+    // The custom parser replaces the built-in spellings entirely.
+    let mut rdr = csv::Reader::from_reader("id,active\n1,true\n".as_bytes());
+    let err = ingest_csv_from_reader_with_options(&mut rdr, &schema, &options).unwrap_err();
+    assert!(err.to_string().contains("expected on/off, got 'true'"));
+
+    // Other types keep the built-in parsers.
+    let mut rdr = csv::Reader::from_reader("id,active\n1,on\n".as_bytes());
+    assert!(ingest_csv_from_reader(&mut rdr, &schema).is_err());
+}
+
+#[test]
+fn ingest_csv_trim_strings_false_preserves_utf8_whitespace() {
+    let schema = people_schema();
+    let input = "id,name,score,active\n 1 ,  Ada  , 98.5 , true \n2,   ,1.0,false\n3,,2.0,true\n";
+
+    let mut rdr = csv::Reader::from_reader(input.as_bytes());
+    let trimmed = ingest_csv_from_reader(&mut rdr, &schema).unwrap();
+    assert_eq!(trimmed.rows[0][1], Value::Utf8("Ada".to_string()));
+    assert_eq!(trimmed.rows[1][1], Value::Null);
+
+    let opts = IngestionOptions {
+        trim_strings: false,
+        ..Default::default()
+    };
+    let mut rdr = csv::Reader::from_reader(input.as_bytes());
+    let ds = ingest_csv_from_reader_with_options(&mut rdr, &schema, &opts).unwrap();
+    // Numeric/bool cells are still trimmed.
+    assert_eq!(ds.rows[0][0], Value::Int64(1));
+    assert_eq!(ds.rows[0][3], Value::Bool(true));
+    assert_eq!(ds.rows[0][1], Value::Utf8("  Ada  ".to_string()));
+    // Whitespace-only is a value; only truly empty cells are null.
+    assert_eq!(ds.rows[1][1], Value::Utf8("   ".to_string()));
+    assert_eq!(ds.rows[2][1], Value::Null);
+}
+
+#[test]
+fn ingest_csv_custom_bool_tokens_replace_defaults() {
+    let schema = Schema::new(vec![
+        Field::new("id", DataType::Int64),
+        Field::new("active", DataType::Bool),
+    ]);
+    let opts = IngestionOptions {
+        bool_true_tokens: vec!["Y".to_string()],
+        bool_false_tokens: vec!["N".to_string()],
+        ..Default::default()
+    };
+
+    let mut rdr = csv::Reader::from_reader("id,active\n1,Y\n2,N\n3,\n".as_bytes());
+    let ds = ingest_csv_from_reader_with_options(&mut rdr, &schema, &opts).unwrap();
+    assert_eq!(ds.rows[0][1], Value::Bool(true));
+    assert_eq!(ds.rows[1][1], Value::Bool(false));
+    assert_eq!(ds.rows[2][1], Value::Null);
+
+    // Defaults no longer apply once custom tokens are set.
+    let mut rdr = csv::Reader::from_reader("id,active\n1,true\n".as_bytes());
+    let err = ingest_csv_from_reader_with_options(&mut rdr, &schema, &opts).unwrap_err();
+    match err {
+        rust_data_processing::IngestionError::ParseError { row, raw, .. } => {
+            assert_eq!(row, 2);
+            assert_eq!(raw, "true");
+        }
+        other => panic!("expected ParseError, got {other:?}"),
+    }
+}
+
+#[test]
+fn ingest_csv_collect_errors_skips_bad_rows_and_reports_them() {
+    let schema = Schema::new(vec![
+        Field::new("id", DataType::Int64),
+        Field::new("name", DataType::Utf8),
+    ]);
+    let path = tmp_file("collect-errors");
+    std::fs::write(&path, "id,name\n1,Ada\nnope,Grace\n3,Linus\n").unwrap();
+
+    let (ds, errors) = ingest_csv_collect_errors(&path, &schema).unwrap();
+    assert_eq!(ds.column_i64("id").unwrap(), vec![Some(1), Some(3)]);
+    assert_eq!(errors.len(), 1);
+    match &errors[0] {
+        rust_data_processing::IngestionError::ParseError {
+            row, column, raw, ..
+        } => {
+            assert_eq!(*row, 3);
+            assert_eq!(column, "id");
+            assert_eq!(raw, "nope");
+        }
+        other => panic!("expected ParseError, got {other:?}"),
+    }
+
+    // Structural errors still fail fast.
+    let missing = Schema::new(vec![Field::new("email", DataType::Utf8)]);
+    let err = ingest_csv_collect_errors(&path, &missing).unwrap_err();
+    std::fs::remove_file(&path).ok();
+    assert!(matches!(
+        err,
+        rust_data_processing::IngestionError::SchemaMismatch { .. }
+    ));
+}
+
+#[test]
+fn ingest_csv_parallel_matches_sequential_reader() {
+    let schema = Schema::new(vec![
+        Field::new("id", DataType::Int64),
+        Field::new("note", DataType::Utf8),
+        Field::new("score", DataType::Float64),
+    ]);
+    // Every third note is quoted and spans lines, so naive byte splits would land mid-record.
+    let mut text = String::from("id,note,score\n");
+    for i in 0..5000 {
+        if i % 3 == 0 {
+            text.push_str(&format!("{i},\"line one\nline \"\"two\"\", {i}\",{i}.5\n"));
+        } else {
+            text.push_str(&format!("{i},plain {i},\n"));
+        }
+    }
+    let path = tmp_file("parallel");
+    std::fs::write(&path, &text).unwrap();
+
+    let options = IngestionOptions::default();
+    let sequential = |path: &std::path::Path| {
+        let mut rdr = csv::Reader::from_path(path).unwrap();
+        ingest_csv_from_reader_with_options(&mut rdr, &schema, &options)
+    };
+    // Pin the thread count so the file is split into several chunks on any machine.
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(4)
+        .build()
+        .unwrap();
+    let parallel = pool
+        .install(|| ingest_csv_parallel(&path, &schema, &options))
+        .unwrap();
+    assert_eq!(parallel.row_count(), 5000);
+    assert_eq!(parallel, sequential(&path).unwrap());
+
+    // A bad cell deep in the file reports the same row number as the sequential reader.
+    std::fs::write(
+        &path,
+        text.replacen("4001,plain 4001,", "4001,plain 4001,oops", 1),
+    )
+    .unwrap();
+    let seq_err = sequential(&path).unwrap_err();
+    let par_err = pool
+        .install(|| ingest_csv_parallel(&path, &schema, &options))
+        .unwrap_err();
+    let _ = std::fs::remove_file(&path);
+    match (&seq_err, &par_err) {
+        (
+            rust_data_processing::IngestionError::ParseError { row: a, .. },
+            rust_data_processing::IngestionError::ParseError { row: b, .. },
+        ) => {
+            assert_eq!(a, b);
+            assert_eq!(*b, 4003);
+        }
+        other => panic!("expected two ParseErrors, got {other:?}"),
+    }
+}
+
+#[test]
+fn ingest_csv_parallel_reports_file_positions_and_progress() {
+    use std::sync::{Arc, Mutex};
+
+    use rust_data_processing::ingestion::{IngestionContext, IngestionObserver};
+
+    #[derive(Default)]
+    struct ProgressLog(Mutex<Vec<usize>>);
+
+    impl IngestionObserver for ProgressLog {
+        fn on_progress(&self, _ctx: &IngestionContext, rows_so_far: usize) {
+            self.0.lock().unwrap().push(rows_so_far);
+        }
+    }
+
+    let schema = Schema::new(vec![
+        Field::new("id", DataType::Int64),
+        Field::new("note", DataType::Utf8),
+    ]);
+    let mut bytes = b"id,note\n".to_vec();
+    for i in 0..4000 {
+        if i % 4 == 0 {
+            bytes.extend_from_slice(format!("{i},\"two\nlines\"\n").as_bytes());
+        } else {
+            bytes.extend_from_slice(format!("{i},plain\n\n").as_bytes());
+        }
+    }
+    let path = tmp_file("parallel-positions");
+    std::fs::write(&path, &bytes).unwrap();
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(4)
+        .build()
+        .unwrap();
+
+    // Progress arrives per chunk with running totals; every multiple is reported once.
+    let log = Arc::new(ProgressLog::default());
+    let options = IngestionOptions {
+        observer: Some(log.clone()),
+        progress_every: Some(500),
+        ..Default::default()
+    };
+    let ds = pool
+        .install(|| ingest_csv_parallel(&path, &schema, &options))
+        .unwrap();
+    assert_eq!(ds.row_count(), 4000);
+    let mut reported = log.0.lock().unwrap().clone();
+    reported.sort_unstable();
+    assert_eq!(reported, (1..=8).map(|n| n * 500).collect::<Vec<_>>());
+
+    // Invalid UTF-8 in a late chunk reports the same record/line/byte as the sequential reader.
+    let at = bytes.windows(10).position(|w| w == b"3901,plain").unwrap() + 5;
+    bytes[at] = 0xff;
+    std::fs::write(&path, &bytes).unwrap();
+    let options = IngestionOptions::default();
+    let mut rdr = csv::Reader::from_path(&path).unwrap();
+    let seq_err = ingest_csv_from_reader_with_options(&mut rdr, &schema, &options).unwrap_err();
+    let par_err = pool
+        .install(|| ingest_csv_parallel(&path, &schema, &options))
+        .unwrap_err();
+    let _ = std::fs::remove_file(&path);
+    assert!(
+        matches!(par_err, rust_data_processing::IngestionError::Csv(_)),
+        "{par_err:?}"
+    );
+    assert_eq!(par_err.to_string(), seq_err.to_string());
+}
+
+#[test]
+fn ingest_csv_since_reads_only_appended_records() {
+    use std::io::Write;
+
+    let schema = people_schema();
+    let path = tmp_file("since");
+    std::fs::write(&path, "id,name,score,active\n1,Ada,1.5,true\n").unwrap();
+
+    let (ds, offset) = ingest_csv_since(&path, &schema, 0).unwrap();
+    assert_eq!(ds.row_count(), 1);
+    assert_eq!(ds.rows[0][1], Value::Utf8("Ada".to_string()));
+
+    // Nothing new yet.
+    let (ds, same) = ingest_csv_since(&path, &schema, offset).unwrap();
+    assert!(ds.is_empty());
+    assert_eq!(same, offset);
+
+    // A half-written record is left for the next poll.
+    let mut f = std::fs::OpenOptions::new()
+        .append(true)
+        .open(&path)
+        .unwrap();
+    write!(f, "2,Grace,2.5,false\n3,Li").unwrap();
+    let (ds, offset) = ingest_csv_since(&path, &schema, offset).unwrap();
+    assert_eq!(ds.row_count(), 1);
+    assert_eq!(ds.rows[0][0], Value::Int64(2));
+
+    writeln!(f, "nus,3.5,true").unwrap();
+    let (ds, offset) = ingest_csv_since(&path, &schema, offset).unwrap();
+    let len = std::fs::metadata(&path).unwrap().len();
+    let _ = std::fs::remove_file(&path);
+    assert_eq!(ds.row_count(), 1);
+    assert_eq!(ds.rows[0][1], Value::Utf8("Linus".to_string()));
+    assert_eq!(offset, len);
+}
+
+#[test]
+fn ingest_csv_since_with_options_honours_options_and_reports_file_rows() {
+    use std::io::Write;
+
+    let schema = people_schema();
+    let path = tmp_file("since-options");
+    std::fs::write(&path, "id,name,score,active\n1, Ada ,1.5,ja\n").unwrap();
+    let options = IngestionOptions {
+        trim_strings: false,
+        bool_true_tokens: vec!["ja".to_string()],
+        bool_false_tokens: vec!["nein".to_string()],
+        ..Default::default()
+    };
+
+    let (ds, offset) = ingest_csv_since_with_options(&path, &schema, 0, &options).unwrap();
+    assert_eq!(ds.rows[0][1], Value::Utf8(" Ada ".to_string()));
+    assert_eq!(ds.rows[0][3], Value::Bool(true));
+
+    // The first appended record is reported as row 2, as if it followed the header.
+    let mut f = std::fs::OpenOptions::new()
+        .append(true)
+        .open(&path)
+        .unwrap();
+    writeln!(f, "2,Grace,oops,nein").unwrap();
+    let err = ingest_csv_since_with_options(&path, &schema, offset, &options).unwrap_err();
+    let _ = std::fs::remove_file(&path);
+    match err {
+        rust_data_processing::IngestionError::ParseError { row, column, .. } => {
+            assert_eq!(row, 2);
+            assert_eq!(column, "score");
+        }
+        other => panic!("expected ParseError, got {other:?}"),
+    }
+}
+
+#[test]
+fn ingest_csv_skip_blank_lines_drops_all_empty_records() {
+    let schema = Schema::new(vec![
+        Field::new("id", DataType::Int64),
+        Field::new("name", DataType::Utf8),
+    ]);
+    let text = "id,name\n1,Ada\n\n,\n2,Grace\n,\n\n3,Linus\n";
+    let path = tmp_file("blank-lines");
+    std::fs::write(&path, text).unwrap();
+
+    // Default: skipped on both the Polars path and the record reader.
+    let ds = ingest_csv_from_path(&path, &schema).unwrap();
+    assert_eq!(
+        ds.column_i64("id").unwrap(),
+        vec![Some(1), Some(2), Some(3)]
+    );
+    let strict = IngestionOptions {
+        strict_row_width: true,
+        ..Default::default()
+    };
+    let ds = ingest_csv_from_path_with_options(&path, &schema, &strict).unwrap();
+    assert_eq!(ds.row_count(), 3);
+
+    // Opting out keeps the `,` records as all-null rows.
+    let keep = IngestionOptions {
+        skip_blank_lines: false,
+        ..Default::default()
+    };
+    let mut rdr = csv::Reader::from_reader(text.as_bytes());
+    let ds = ingest_csv_from_reader_with_options(&mut rdr, &schema, &keep).unwrap();
+    assert_eq!(
+        ds.column_i64("id").unwrap(),
+        vec![Some(1), None, Some(2), None, Some(3)]
+    );
+
+    // Skipped records do not advance error row numbers.
+    std::fs::write(&path, text.replace("3,Linus", "x,Linus")).unwrap();
+    let err = ingest_csv_from_path_with_options(&path, &schema, &strict).unwrap_err();
+    let _ = std::fs::remove_file(&path);
+    match err {
+        rust_data_processing::IngestionError::ParseError { row, .. } => assert_eq!(row, 4),
+        other => panic!("expected ParseError, got {other:?}"),
+    }
+}
+
+#[test]
+fn ingest_csv_allow_empty_controls_header_only_files() {
+    let path = tmp_file("empty");
+    std::fs::write(&path, "id,name,score,active\n").unwrap();
+    let schema = people_schema();
+
+    // Default: an empty DataSet, as before.
+    let ds = ingest_csv_from_path(&path, &schema).unwrap();
+    assert!(ds.is_empty());
+    assert_eq!(ds.schema, schema);
+
+    let strict = IngestionOptions::builder().allow_empty(false).build();
+    let err = ingest_csv_from_path_with_options(&path, &schema, &strict).unwrap_err();
+    let _ = std::fs::remove_file(&path);
+    assert!(matches!(
+        err,
+        rust_data_processing::IngestionError::EmptyInput
+    ));
+
+    // Same on the record reader; a file with rows is unaffected.
+    let mut rdr = csv::Reader::from_reader("id,name,score,active\n".as_bytes());
+    assert!(matches!(
+        ingest_csv_from_reader_with_options(&mut rdr, &schema, &strict),
+        Err(rust_data_processing::IngestionError::EmptyInput)
+    ));
+    let mut rdr = csv::Reader::from_reader("id,name,score,active\n1,Ada,1.0,true\n".as_bytes());
+    assert_eq!(
+        ingest_csv_from_reader_with_options(&mut rdr, &schema, &strict)
+            .unwrap()
+            .row_count(),
+        1
+    );
+}
+
+#[test]
+fn ingest_csv_strict_row_width_rejects_short_rows() {
+    let schema = people_schema();
+    let input = "id,name,score,active\n1,Ada,98.5,true\n2,Grace\n";
+    let flexible_reader = || {
+        csv::ReaderBuilder::new()
+            .flexible(true)
+            .from_reader(input.as_bytes())
+    };
+
+    // Lenient (default): missing trailing fields read as nulls.
+    let ds = ingest_csv_from_reader(&mut flexible_reader(), &schema).unwrap();
+    assert_eq!(
+        ds.rows[1],
+        vec![
+            Value::Int64(2),
+            Value::Utf8("Grace".to_string()),
+            Value::Null,
+            Value::Null
+        ]
+    );
+
+    let path = tmp_file("short-row");
+    std::fs::write(&path, input).unwrap();
+    let lenient = ingest_csv_from_path_with_options(&path, &schema, &IngestionOptions::default());
+    assert_eq!(lenient.unwrap(), ds);
+
+    let opts = IngestionOptions {
+        strict_row_width: true,
+        ..Default::default()
+    };
+    let strict = ingest_csv_from_path_with_options(&path, &schema, &opts);
+    std::fs::remove_file(&path).ok();
+    for err in [
+        strict.unwrap_err(),
+        ingest_csv_from_reader_with_options(&mut flexible_reader(), &schema, &opts).unwrap_err(),
+    ] {
+        match err {
+            rust_data_processing::IngestionError::ParseError {
+                row,
+                column,
+                message,
+                ..
+            } => {
+                assert_eq!(row, 3);
+                assert_eq!(column, "score");
+                assert!(message.contains("2 fields but the header has 4"));
+            }
+            other => panic!("expected ParseError, got {other:?}"),
+        }
+    }
+}
+
+fn write_latin1_fixture(tag: &str) -> PathBuf {
+    let path = tmp_file(&format!("latin1-{tag}"));
+    // "José", "Müller", and byte 0x80 (`€` in Windows-1252, a C1 control in Latin-1).
+    std::fs::write(&path, b"id,name\n1,Jos\xe9\n2,M\xfcller\n3,\x80\n").unwrap();
+    path
+}
+
+#[cfg(feature = "encoding")]
+#[test]
+fn ingest_csv_decodes_latin1_and_windows1252() {
+    let schema = Schema::new(vec![
+        Field::new("id", DataType::Int64),
+        Field::new("name", DataType::Utf8),
+    ]);
+    let path = write_latin1_fixture("decode");
+
+    let latin1 = IngestionOptions {
+        encoding: CsvEncoding::Latin1,
+        ..Default::default()
+    };
+    let ds = ingest_csv_from_path_with_options(&path, &schema, &latin1).unwrap();
+    assert_eq!(
+        ds.column_str("name").unwrap(),
+        vec![Some("José"), Some("Müller"), Some("\u{80}")]
+    );
+
+    let windows = IngestionOptions {
+        encoding: CsvEncoding::Windows1252,
+        ..Default::default()
+    };
+    let ds = ingest_csv_from_path_with_options(&path, &schema, &windows).unwrap();
+    assert_eq!(ds.rows[2][1], Value::Utf8("€".to_string()));
+
+    // Default UTF-8 rejects the same bytes.
+    assert!(
+        ingest_csv_from_path_with_options(&path, &schema, &IngestionOptions::default()).is_err()
+    );
+    let _ = std::fs::remove_file(&path);
+}
+
+#[cfg(not(feature = "encoding"))]
+#[test]
+fn ingest_csv_non_utf8_encoding_requires_feature() {
+    let schema = Schema::new(vec![Field::new("name", DataType::Utf8)]);
+    let path = write_latin1_fixture("nofeature");
+    let opts = IngestionOptions {
+        encoding: CsvEncoding::Latin1,
+        ..Default::default()
+    };
+    let err = ingest_csv_from_path_with_options(&path, &schema, &opts).unwrap_err();
+    assert!(err.to_string().contains("feature 'encoding'"));
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn ingest_csv_numeric_locale_normalizes_separators() {
+    let schema = Schema::new(vec![
+        Field::new("count", DataType::Int64),
+        Field::new("amount", DataType::Float64),
+    ]);
+
+    let european = IngestionOptions {
+        numeric_locale: NumericLocale {
+            decimal_sep: ',',
+            thousands_sep: Some('.'),
+        },
+        ..Default::default()
+    };
+    let input = "count;amount\n1.000;1.234,56\n7;0,5\n";
+    let mut rdr = csv::ReaderBuilder::new()
+        .delimiter(b';')
+        .from_reader(input.as_bytes());
+    let ds = ingest_csv_from_reader_with_options(&mut rdr, &schema, &european).unwrap();
+    assert_eq!(
+        ds.rows[0],
+        vec![Value::Int64(1000), Value::Float64(1234.56)]
+    );
+    assert_eq!(ds.rows[1], vec![Value::Int64(7), Value::Float64(0.5)]);
+
+    let thousands_comma = IngestionOptions {
+        numeric_locale: NumericLocale {
+            decimal_sep: '.',
+            thousands_sep: Some(','),
+        },
+        ..Default::default()
+    };
+    let mut rdr = csv::Reader::from_reader("count,amount\n\"1,000\",\"2,500.25\"\n".as_bytes());
+    let ds = ingest_csv_from_reader_with_options(&mut rdr, &schema, &thousands_comma).unwrap();
+    assert_eq!(
+        ds.rows[0],
+        vec![Value::Int64(1000), Value::Float64(2500.25)]
+    );
+
+    let ambiguous = IngestionOptions {
+        numeric_locale: NumericLocale {
+            decimal_sep: ',',
+            thousands_sep: Some(','),
+        },
+        ..Default::default()
+    };
+    let mut rdr = csv::Reader::from_reader("count,amount\n1,2\n".as_bytes());
+    let err = ingest_csv_from_reader_with_options(&mut rdr, &schema, &ambiguous).unwrap_err();
+    assert!(err.to_string().contains("numeric_locale is ambiguous"));
+}
+
+#[test]
+fn ingest_csv_float_policy_controls_non_finite_values() {
+    let schema = Schema::new(vec![
+        Field::new("id", DataType::Int64),
+        Field::new("score", DataType::Float64),
+    ]);
+    let input = "id,score\n1,1.5\n2,NaN\n3,inf\n4,-inf\n";
+
+    let mut rdr = csv::Reader::from_reader(input.as_bytes());
+    let ds = ingest_csv_from_reader(&mut rdr, &schema).unwrap();
+    assert!(matches!(ds.rows[1][1], Value::Float64(v) if v.is_nan()));
+    assert_eq!(ds.rows[2][1], Value::Float64(f64::INFINITY));
+
+    let opts = IngestionOptions {
+        float_policy: FloatPolicy::NullifyNonFinite,
+        ..Default::default()
+    };
+    let mut rdr = csv::Reader::from_reader(input.as_bytes());
+    let ds = ingest_csv_from_reader_with_options(&mut rdr, &schema, &opts).unwrap();
+    assert_eq!(ds.rows[0][1], Value::Float64(1.5));
+    assert_eq!(ds.rows[1][1], Value::Null);
+    assert_eq!(ds.rows[2][1], Value::Null);
+    assert_eq!(ds.rows[3][1], Value::Null);
+
+    let opts = IngestionOptions {
+        float_policy: FloatPolicy::RejectNonFinite,
+        ..Default::default()
+    };
+    let mut rdr = csv::Reader::from_reader(input.as_bytes());
+    let err = ingest_csv_from_reader_with_options(&mut rdr, &schema, &opts).unwrap_err();
+    match err {
+        rust_data_processing::IngestionError::ParseError {
+            row, column, raw, ..
+        } => {
+            assert_eq!(row, 3);
+            assert_eq!(column, "score");
+            assert_eq!(raw, "NaN");
+        }
+        other => panic!("expected ParseError, got {other:?}"),
+    }
+}
+
+/// Accepts `^[A-Z][a-z]+$`-style names.
+fn capitalized_word(s: &str) -> Result<(), String> {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_uppercase() && chars.all(|c| c.is_ascii_lowercase()) => Ok(()),
+        _ => Err(format!("'{s}' is not a capitalized word")),
+    }
+}
+
+#[test]
+fn ingest_csv_pre_transform_strips_unit_before_int_parsing() {
+    let path = tmp_file("pre-transform");
+    std::fs::write(&path, "id,weight\n1,12kg\n2, 7kg \n3,\n").unwrap();
+    let schema = Schema::new(vec![
+        Field::new("id", DataType::Int64),
+        Field::new("weight", DataType::Int64)
+            .with_pre_transform(|s| s.trim().trim_end_matches("kg").to_string()),
+    ]);
+
+    let ds = ingest_csv_from_path(&path, &schema).unwrap();
+    let _ = std::fs::remove_file(&path);
+    assert_eq!(
+        ds.column_i64("weight").unwrap(),
+        vec![Some(12), Some(7), None]
+    );
+
+    // Without the transform the unit makes the cell unparseable.
+    let plain = Schema::new(vec![Field::new("weight", DataType::Int64)]);
+    let mut rdr = csv::Reader::from_reader("weight\n12kg\n".as_bytes());
+    assert!(ingest_csv_from_reader(&mut rdr, &plain).is_err());
+}
+
+#[test]
+fn ingest_csv_applies_utf8_field_validator() {
+    let schema = Schema::new(vec![
+        Field::new("id", DataType::Int64),
+        Field::new("name", DataType::Utf8).with_validator(capitalized_word),
+    ]);
+
+    // Polars path and record-reader path both run the validator.
+    let ds = ingest_csv_from_path("tests/fixtures/people.csv", &schema).unwrap();
+    assert_eq!(ds.rows[1][1], Value::Utf8("Grace".to_string()));
+
+    let mut rdr = csv::Reader::from_reader("id,name\n1,Ada\n2,ada99\n".as_bytes());
+    let err = ingest_csv_from_reader(&mut rdr, &schema).unwrap_err();
+    match err {
+        rust_data_processing::IngestionError::ParseError {
+            row,
+            column,
+            raw,
+            message,
+        } => {
+            assert_eq!(row, 3);
+            assert_eq!(column, "name");
+            assert_eq!(raw, "ada99");
+            assert_eq!(message, "'ada99' is not a capitalized word");
+        }
+        other => panic!("expected ParseError, got {other:?}"),
+    }
+}
+
+#[test]
+fn ingest_csv_enforces_int64_field_range() {
+    let schema = Schema::new(vec![
+        Field::new("id", DataType::Int64),
+        Field::new("month", DataType::Int64).with_int_range(1..=12),
+    ]);
+
+    let mut rdr = csv::Reader::from_reader("id,month\n1,1\n2,\n3,12\n".as_bytes());
+    let ds = ingest_csv_from_reader(&mut rdr, &schema).unwrap();
+    assert_eq!(
+        ds.column_i64("month").unwrap(),
+        vec![Some(1), None, Some(12)]
+    );
+
+    for (input, bad_row, bad) in [
+        ("id,month\n1,5\n2,0\n", 3, "0"),
+        ("id,month\n1,13\n", 2, "13"),
+    ] {
+        let mut rdr = csv::Reader::from_reader(input.as_bytes());
+        match ingest_csv_from_reader(&mut rdr, &schema).unwrap_err() {
+            rust_data_processing::IngestionError::ParseError {
+                row, raw, message, ..
+            } => {
+                assert_eq!(row, bad_row);
+                assert_eq!(raw, bad);
+                assert!(message.contains("outside the allowed range 1..=12"));
+            }
+            other => panic!("expected ParseError, got {other:?}"),
+        }
+    }
+}
+
+#[test]
+fn ingest_csv_enum_rejects_values_outside_allowed_set() {
+    let status = DataType::Enum(vec!["active".to_string(), "inactive".to_string()]);
+    let schema = Schema::new(vec![
+        Field::new("id", DataType::Int64),
+        Field::new("status", status),
+    ]);
+
+    let mut rdr = csv::Reader::from_reader("id,status\n1,active\n2,\n".as_bytes());
+    let ds = ingest_csv_from_reader(&mut rdr, &schema).unwrap();
+    assert_eq!(ds.rows[0][1], Value::Utf8("active".to_string()));
+    assert_eq!(ds.rows[1][1], Value::Null);
+
+    let input = "id,status\n1,active\n2,Inactive\n";
+    let mut rdr = csv::Reader::from_reader(input.as_bytes());
+    let err = ingest_csv_from_reader(&mut rdr, &schema).unwrap_err();
+    match err {
+        rust_data_processing::IngestionError::ParseError {
+            row,
+            column,
+            raw,
+            message,
+        } => {
+            assert_eq!(row, 3);
+            assert_eq!(column, "status");
+            assert_eq!(raw, "Inactive");
+            assert!(message.contains("'Inactive'"), "{message}");
+        }
+        other => panic!("expected ParseError, got {other:?}"),
+    }
+
+    // Case-insensitive matching stores the schema's spelling.
+    let opts = IngestionOptions {
+        case_insensitive_enums: true,
+        ..Default::default()
+    };
+    let mut rdr = csv::Reader::from_reader(input.as_bytes());
+    let ds = ingest_csv_from_reader_with_options(&mut rdr, &schema, &opts).unwrap();
+    assert_eq!(ds.rows[1][1], Value::Utf8("inactive".to_string()));
+
+    // The Polars-backed path applies the same check.
+    let schema = Schema::new(vec![Field::new(
+        "name",
+        DataType::Enum(vec!["Ada".to_string()]),
+    )]);
+    let err = ingest_csv_from_path("tests/fixtures/people.csv", &schema).unwrap_err();
+    assert!(err.to_string().contains("Grace"), "{err}");
+}
@@ -599,7 +599,12 @@ fn deep_parquet_apache_fixture_ingests_supported_columns() {
             DataType::Int64 => polars::datatypes::DataType::Int64,
             DataType::Float64 => polars::datatypes::DataType::Float64,
             DataType::Bool => polars::datatypes::DataType::Boolean,
-            DataType::Utf8 => polars::datatypes::DataType::String,
+            DataType::Utf8 | DataType::Enum(_) => polars::datatypes::DataType::String,
+            DataType::Bytes => polars::datatypes::DataType::Binary,
+            DataType::Timestamp => polars::datatypes::DataType::Datetime(
+                polars::datatypes::TimeUnit::Microseconds,
+                None,
+            ),
         };
         casted_cols.push(s.cast(&target).unwrap());
     }
@@ -635,12 +640,15 @@ fn deep_parquet_apache_fixture_ingests_supported_columns() {
                 assert_reduce_parity(&ds, &field.name, ReduceOp::L2Norm);
                 assert_reduce_parity(&ds, &field.name, ReduceOp::Variance(VarianceKind::Sample));
             }
-            DataType::Utf8 => {
+            DataType::Utf8 | DataType::Enum(_) => {
                 assert_reduce_parity(&ds, &field.name, ReduceOp::CountDistinctNonNull);
             }
             DataType::Bool => {
                 assert_reduce_parity(&ds, &field.name, ReduceOp::CountDistinctNonNull);
             }
+            DataType::Bytes | DataType::Timestamp => {
+                unreachable!("binary and timestamp columns are not selected above")
+            }
         }
     }
 
@@ -1,10 +1,16 @@
 #![cfg(feature = "excel_test_writer")]
 
 use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
 use std::time::{SystemTime, UNIX_EPOCH};
 
+use rust_data_processing::IngestionError;
 use rust_data_processing::ingestion::excel::{
-    ingest_excel_from_path, ingest_excel_workbook_from_path,
+    ingest_excel_from_path, ingest_excel_from_path_with_options, ingest_excel_workbook_from_path,
+};
+use rust_data_processing::ingestion::{
+    ExcelSheetSelection, IngestionContext, IngestionObserver, IngestionOptions, IngestionSeverity,
+    ingest_from_path,
 };
 use rust_data_processing::types::{DataType, Field, Schema, Value};
 
@@ -167,3 +173,73 @@ fn ingest_excel_multi_tab_selected_sheet_only() {
 
     let _ = std::fs::remove_file(&path);
 }
+
+#[test]
+fn ingest_excel_case_insensitive_headers_is_opt_in() {
+    let schema = Schema::new(vec![
+        Field::new("ID", DataType::Int64),
+        Field::new("NAME", DataType::Utf8),
+    ]);
+    let path = tmp_file("case-insensitive");
+    write_people_xlsx(&path, true, false);
+
+    let err = ingest_excel_from_path(&path, None, &schema).unwrap_err();
+    assert!(err.to_string().contains("missing required column 'ID'"));
+
+    let opts = IngestionOptions {
+        case_insensitive_headers: true,
+        ..Default::default()
+    };
+    let ds = ingest_excel_from_path_with_options(&path, None, &schema, &opts).unwrap();
+    assert_eq!(ds.row_count(), 2);
+    assert_eq!(ds.rows[1][1], Value::Utf8("Grace".to_string()));
+
+    let _ = std::fs::remove_file(&path);
+}
+
+#[derive(Default)]
+struct SheetRecorder {
+    failed_sheets: Mutex<Vec<Option<String>>>,
+}
+
+impl IngestionObserver for SheetRecorder {
+    fn on_failure(
+        &self,
+        ctx: &IngestionContext,
+        _severity: IngestionSeverity,
+        _error: &IngestionError,
+    ) {
+        self.failed_sheets.lock().unwrap().push(ctx.sheet.clone());
+    }
+}
+
+#[test]
+fn observer_context_names_the_failing_sheet() {
+    use rust_xlsxwriter::Workbook;
+
+    let path = tmp_file("bad-sheet");
+    let mut wb = Workbook::new();
+    for (name, id) in [("Good", "1"), ("Broken", "oops")] {
+        let ws = wb.add_worksheet();
+        ws.set_name(name).unwrap();
+        ws.write_string(0, 0, "id").unwrap();
+        ws.write_string(1, 0, id).unwrap();
+    }
+    wb.save(&path).unwrap();
+
+    let rec = Arc::new(SheetRecorder::default());
+    let opts = IngestionOptions {
+        excel_sheet_selection: ExcelSheetSelection::AllSheets,
+        observer: Some(rec.clone()),
+        ..Default::default()
+    };
+    let schema = Schema::new(vec![Field::new("id", DataType::Int64)]);
+    let err = ingest_from_path(&path, &schema, &opts).unwrap_err();
+    assert!(matches!(err, IngestionError::ParseError { .. }));
+    assert_eq!(
+        *rec.failed_sheets.lock().unwrap(),
+        vec![Some("Broken".to_string())]
+    );
+
+    let _ = std::fs::remove_file(&path);
+}
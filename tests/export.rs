@@ -0,0 +1,228 @@
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rust_data_processing::export::{
+    write_csv, write_csv_to_path, write_json, write_ndjson, write_parquet,
+};
+use rust_data_processing::ingestion::csv::{ingest_csv_from_path, ingest_csv_from_reader};
+use rust_data_processing::ingestion::json::{ingest_json_from_path, ingest_json_from_str};
+use rust_data_processing::ingestion::parquet::ingest_parquet_from_path;
+use rust_data_processing::types::{DataSet, DataType, Field, Schema, Value};
+
+fn tmp_file(name: &str, ext: &str) -> PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    std::env::temp_dir().join(format!("rust-data-processing-export-{name}-{nanos}.{ext}"))
+}
+
+fn people_schema() -> Schema {
+    Schema::new(vec![
+        Field::new("id", DataType::Int64),
+        Field::new("name", DataType::Utf8),
+        Field::new("score", DataType::Float64),
+        Field::new("active", DataType::Bool),
+    ])
+}
+
+fn people_dataset() -> DataSet {
+    DataSet::new(
+        people_schema(),
+        vec![
+            vec![
+                Value::Int64(1),
+                Value::Utf8("Ada".to_string()),
+                Value::Float64(98.5),
+                Value::Bool(true),
+            ],
+            vec![
+                Value::Int64(2),
+                Value::Utf8("Lovelace, \"Countess\"\nof Lovelace".to_string()),
+                Value::Null,
+                Value::Bool(false),
+            ],
+            vec![
+                Value::Int64(3),
+                Value::Null,
+                Value::Float64(-0.25),
+                Value::Null,
+            ],
+        ],
+    )
+}
+
+#[test]
+fn write_csv_renders_header_nulls_and_quotes() {
+    let ds = people_dataset();
+    let mut buf: Vec<u8> = Vec::new();
+    write_csv(&ds, &mut buf).unwrap();
+
+    let text = String::from_utf8(buf).unwrap();
+    assert!(text.starts_with("id,name,score,active\n1,Ada,98.5,true\n"));
+    assert!(text.contains("\"Lovelace, \"\"Countess\"\"\nof Lovelace\""));
+    assert!(text.ends_with("3,,-0.25,\n"));
+}
+
+#[test]
+fn csv_export_round_trips_through_reader_ingestion() {
+    let ds = people_dataset();
+    let mut buf: Vec<u8> = Vec::new();
+    write_csv(&ds, &mut buf).unwrap();
+
+    let mut rdr = csv::ReaderBuilder::new()
+        .has_headers(true)
+        .from_reader(buf.as_slice());
+    let back = ingest_csv_from_reader(&mut rdr, &people_schema()).unwrap();
+    assert_eq!(back, ds);
+}
+
+#[test]
+fn csv_export_round_trips_through_path_ingestion() {
+    let schema = people_schema();
+    let original = ingest_csv_from_path("tests/fixtures/people.csv", &schema).unwrap();
+
+    let path = tmp_file("people", "csv");
+    write_csv_to_path(&original, &path).unwrap();
+    let back = ingest_csv_from_path(&path, &schema).unwrap();
+    let _ = std::fs::remove_file(&path);
+
+    assert_eq!(back, original);
+}
+
+#[test]
+fn csv_export_round_trips_non_utf8_bytes_as_base64() {
+    let schema = Schema::new(vec![
+        Field::new("id", DataType::Int64),
+        Field::new("blob", DataType::Bytes),
+    ]);
+    let ds = DataSet::new(
+        schema.clone(),
+        vec![
+            vec![Value::Int64(1), Value::Bytes(vec![0xff, 0x00, 0xfe, b','])],
+            vec![Value::Int64(2), Value::Null],
+        ],
+    );
+
+    let path = tmp_file("blob", "csv");
+    write_csv_to_path(&ds, &path).unwrap();
+    let text = std::fs::read_to_string(&path).unwrap();
+    let back = ingest_csv_from_path(&path, &schema).unwrap();
+    let _ = std::fs::remove_file(&path);
+
+    assert_eq!(text, "id,blob\n1,/wD+LA==\n2,\n");
+    assert_eq!(back, ds);
+}
+
+fn nested_schema() -> Schema {
+    Schema::new(vec![
+        Field::new("id", DataType::Int64),
+        Field::new("user.name", DataType::Utf8),
+        Field::new("user.score", DataType::Float64),
+        Field::new("active", DataType::Bool),
+    ])
+}
+
+fn nested_dataset() -> DataSet {
+    DataSet::new(
+        nested_schema(),
+        vec![
+            vec![
+                Value::Int64(1),
+                Value::Utf8("Ada".to_string()),
+                Value::Float64(98.5),
+                Value::Bool(true),
+            ],
+            vec![
+                Value::Int64(2),
+                Value::Utf8("Grace".to_string()),
+                Value::Null,
+                Value::Bool(false),
+            ],
+        ],
+    )
+}
+
+#[test]
+fn write_json_nests_dotted_fields_and_writes_null() {
+    let ds = nested_dataset();
+    let mut buf: Vec<u8> = Vec::new();
+    write_json(&ds, &mut buf, false).unwrap();
+
+    let v: serde_json::Value = serde_json::from_slice(&buf).unwrap();
+    assert_eq!(
+        v,
+        serde_json::json!([
+            {"id": 1, "user": {"name": "Ada", "score": 98.5}, "active": true},
+            {"id": 2, "user": {"name": "Grace", "score": null}, "active": false},
+        ])
+    );
+
+    let mut pretty: Vec<u8> = Vec::new();
+    write_json(&ds, &mut pretty, true).unwrap();
+    assert!(String::from_utf8(pretty).unwrap().contains("\n  {"));
+    assert_eq!(
+        ingest_json_from_str(std::str::from_utf8(&buf).unwrap(), &nested_schema()).unwrap(),
+        ds
+    );
+}
+
+#[test]
+fn write_json_rejects_scalar_and_nested_fields_with_the_same_prefix() {
+    for names in [["a", "a.b"], ["a.b", "a"]] {
+        let schema = Schema::new(vec![
+            Field::new(names[0], DataType::Int64),
+            Field::new(names[1], DataType::Int64),
+        ]);
+        let ds = DataSet::new(schema, vec![vec![Value::Int64(1), Value::Int64(2)]]);
+
+        let mut buf: Vec<u8> = Vec::new();
+        let err = write_json(&ds, &mut buf, false).unwrap_err().to_string();
+        assert!(err.contains("conflicts with"), "{names:?}: {err}");
+        assert!(write_ndjson(&ds, &mut buf).is_err(), "{names:?}");
+    }
+}
+
+#[test]
+fn ndjson_export_round_trips_through_path_ingestion() {
+    let ds = nested_dataset();
+    let path = tmp_file("nested", "ndjson");
+    let mut file = std::fs::File::create(&path).unwrap();
+    write_ndjson(&ds, &mut file).unwrap();
+    drop(file);
+
+    let text = std::fs::read_to_string(&path).unwrap();
+    assert_eq!(text.lines().count(), 2);
+
+    let back = ingest_json_from_path(&path, &nested_schema()).unwrap();
+    let _ = std::fs::remove_file(&path);
+    assert_eq!(back, ds);
+}
+
+#[test]
+fn parquet_export_round_trips_with_nulls() {
+    let ds = people_dataset();
+    let path = tmp_file("people", "parquet");
+    write_parquet(&ds, &path).unwrap();
+
+    let back = ingest_parquet_from_path(&path, &people_schema()).unwrap();
+    let _ = std::fs::remove_file(&path);
+    assert_eq!(back, ds);
+}
+
+#[test]
+fn parquet_export_rejects_values_that_do_not_match_schema() {
+    let ds = DataSet::new(
+        people_schema(),
+        vec![vec![
+            Value::Utf8("oops".to_string()),
+            Value::Null,
+            Value::Null,
+            Value::Null,
+        ]],
+    );
+    let path = tmp_file("bad", "parquet");
+    let err = write_parquet(&ds, &path).unwrap_err();
+    let _ = std::fs::remove_file(&path);
+    assert!(err.to_string().contains("column 'id'"));
+}
@@ -1,7 +1,10 @@
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use rust_data_processing::ingestion::{
-    IngestionFormat, IngestionObserver, IngestionOptions, IngestionSeverity, ingest_from_path,
+    CompositeObserver, CountingObserver, IngestionContext, IngestionCounts, IngestionFormat,
+    IngestionObserver, IngestionOptions, IngestionSeverity, NullObserver, RateLimitObserver,
+    infer_schema_from_path, ingest_from_path,
 };
 use rust_data_processing::types::{DataType, Field, Schema};
 
@@ -9,6 +12,8 @@ use rust_data_processing::types::{DataType, Field, Schema};
 struct RecordingObserver {
     failures: Mutex<Vec<IngestionSeverity>>,
     alerts: Mutex<Vec<IngestionSeverity>>,
+    progress: Mutex<Vec<usize>>,
+    schemas: Mutex<Vec<Schema>>,
 }
 
 impl IngestionObserver for RecordingObserver {
@@ -29,6 +34,18 @@ impl IngestionObserver for RecordingObserver {
     ) {
         self.alerts.lock().unwrap().push(severity);
     }
+
+    fn on_progress(
+        &self,
+        _ctx: &rust_data_processing::ingestion::IngestionContext,
+        rows_so_far: usize,
+    ) {
+        self.progress.lock().unwrap().push(rows_so_far);
+    }
+
+    fn on_schema_inferred(&self, _ctx: &IngestionContext, schema: &Schema) {
+        self.schemas.lock().unwrap().push(schema.clone());
+    }
 }
 
 fn schema_id_only() -> Schema {
@@ -81,3 +98,144 @@ fn observer_receives_failure_without_alert_for_non_critical_error() {
     assert_eq!(failures, vec![IngestionSeverity::Error]);
     assert!(obs.alerts.lock().unwrap().is_empty());
 }
+
+struct AlertVeto;
+
+impl IngestionObserver for AlertVeto {
+    fn should_alert(&self, _ctx: &IngestionContext, _severity: IngestionSeverity) -> bool {
+        false
+    }
+}
+
+#[test]
+fn composite_observer_skips_alert_fan_out_when_a_child_vetoes() {
+    let missing = "tests/fixtures/does_not_exist.csv";
+    for veto in [false, true] {
+        let rec = Arc::new(RecordingObserver::default());
+        let mut children: Vec<Arc<dyn IngestionObserver>> = vec![rec.clone()];
+        if veto {
+            children.push(Arc::new(AlertVeto));
+        }
+        let opts = IngestionOptions {
+            observer: Some(Arc::new(CompositeObserver::new(children))),
+            alert_at_or_above: IngestionSeverity::Error,
+            ..Default::default()
+        };
+        ingest_from_path(missing, &schema_id_only(), &opts).unwrap_err();
+
+        assert_eq!(rec.failures.lock().unwrap().len(), 1);
+        assert_eq!(rec.alerts.lock().unwrap().is_empty(), veto);
+    }
+}
+
+#[test]
+fn rate_limit_observer_forwards_at_most_max_per_window() {
+    let rec = Arc::new(RecordingObserver::default());
+    let limited = RateLimitObserver::new(rec.clone(), 5, Duration::from_secs(60));
+    let ctx = IngestionContext {
+        path: "jobs/failing.csv".into(),
+        format: IngestionFormat::Csv,
+        sheet: None,
+    };
+    let err = rust_data_processing::IngestionError::SchemaMismatch {
+        message: "boom".to_string(),
+    };
+    for _ in 0..100 {
+        limited.on_failure(&ctx, IngestionSeverity::Error, &err);
+    }
+    assert_eq!(rec.failures.lock().unwrap().len(), 5);
+    assert_eq!(limited.dropped_count(), 95);
+
+    // Once the window has passed, events flow again.
+    let limited = RateLimitObserver::new(rec.clone(), 1, Duration::from_millis(20));
+    limited.on_progress(&ctx, 1);
+    limited.on_progress(&ctx, 2);
+    std::thread::sleep(Duration::from_millis(30));
+    limited.on_progress(&ctx, 3);
+    assert_eq!(*rec.progress.lock().unwrap(), vec![1, 3]);
+    assert_eq!(limited.dropped_count(), 1);
+}
+
+#[test]
+fn counting_observer_tallies_successes_failures_and_rows() {
+    let obs = Arc::new(CountingObserver::new());
+    let opts = IngestionOptions {
+        observer: Some(obs.clone()),
+        // Alerts on every failure must not double-count it.
+        alert_at_or_above: IngestionSeverity::Info,
+        ..Default::default()
+    };
+
+    let ds = ingest_from_path("tests/fixtures/people.csv", &schema_id_only(), &opts).unwrap();
+    ingest_from_path("tests/fixtures/people.csv", &schema_missing_col(), &opts).unwrap_err();
+    assert_eq!(
+        obs.snapshot(),
+        IngestionCounts {
+            success_count: 1,
+            failure_count: 1,
+            total_rows: ds.row_count(),
+        }
+    );
+
+    let opts = IngestionOptions {
+        observer: Some(Arc::new(NullObserver)),
+        ..Default::default()
+    };
+    ingest_from_path("tests/fixtures/people.csv", &schema_id_only(), &opts).unwrap();
+}
+
+#[test]
+fn observer_receives_progress_every_n_rows() {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    let path = std::env::temp_dir().join(format!("rust-data-processing-progress-{nanos}.csv"));
+    let body: String = (1..=10).map(|i| format!("{i}\n")).collect();
+    std::fs::write(&path, format!("id\n{body}")).unwrap();
+
+    // Polars fast path and the record-level reader both report progress.
+    for case_insensitive_headers in [false, true] {
+        let obs = Arc::new(RecordingObserver::default());
+        let opts = IngestionOptions {
+            observer: Some(obs.clone()),
+            progress_every: Some(3),
+            case_insensitive_headers,
+            ..Default::default()
+        };
+        let ds = ingest_from_path(&path, &schema_id_only(), &opts).unwrap();
+        assert_eq!(ds.row_count(), 10);
+        assert_eq!(*obs.progress.lock().unwrap(), vec![3, 6, 9]);
+    }
+
+    let obs = Arc::new(RecordingObserver::default());
+    let opts = IngestionOptions {
+        observer: Some(obs.clone()),
+        ..Default::default()
+    };
+    ingest_from_path(&path, &schema_id_only(), &opts).unwrap();
+    assert!(obs.progress.lock().unwrap().is_empty());
+
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn observer_receives_inferred_schema() {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    let path = std::env::temp_dir().join(format!("rust-data-processing-inferred-{nanos}.csv"));
+    std::fs::write(&path, "id,name\n1,a\n2,b\n").unwrap();
+
+    let obs = Arc::new(RecordingObserver::default());
+    let opts = IngestionOptions {
+        observer: Some(obs.clone()),
+        ..Default::default()
+    };
+    let schema = infer_schema_from_path(&path, &opts).unwrap();
+    let _ = std::fs::remove_file(&path);
+
+    assert_eq!(*obs.schemas.lock().unwrap(), vec![schema]);
+    assert!(obs.failures.lock().unwrap().is_empty());
+}
@@ -1,4 +1,7 @@
-use rust_data_processing::ingestion::json::{ingest_json_from_path, ingest_json_from_str};
+use rust_data_processing::ingestion::json::{
+    flatten_to_schema, fold_json_array, infer_schema, ingest_json_array_streaming,
+    ingest_json_from_path, ingest_json_from_str,
+};
 use rust_data_processing::types::{DataType, Field, Schema, Value};
 
 fn people_schema_nested() -> Schema {
@@ -33,6 +36,58 @@ fn ingest_json_ndjson_happy_path() {
     assert_eq!(ds.rows[0][1], Value::Utf8("Ada".to_string()));
 }
 
+#[test]
+fn fold_json_array_streams_a_large_array() {
+    let path = std::env::temp_dir().join(format!(
+        "rust-data-processing-json-stream-{}.json",
+        std::process::id()
+    ));
+    let items: Vec<String> = (0..20_000)
+        .map(|i| {
+            format!(
+                r#"{{"id": {i}, "user": {{"name": "u{i}"}}, "ok": {}}}"#,
+                i % 2 == 0
+            )
+        })
+        .collect();
+    std::fs::write(&path, format!("[\n{}\n]\n", items.join(",\n"))).unwrap();
+    let schema = Schema::new(vec![
+        Field::new("id", DataType::Int64),
+        Field::new("user.name", DataType::Utf8),
+        Field::new("ok", DataType::Bool),
+    ]);
+
+    let (count, ok) = fold_json_array(&path, &schema, (0usize, 0usize), |(n, ok), row| {
+        (n + 1, ok + usize::from(row[2] == Value::Bool(true)))
+    })
+    .unwrap();
+    assert_eq!((count, ok), (20_000, 10_000));
+
+    let ds = ingest_json_array_streaming(&path, &schema).unwrap();
+    assert_eq!(ds.row_count(), 20_000);
+    assert_eq!(ds.rows[19_999][1], Value::Utf8("u19999".to_string()));
+
+    // Conversion errors keep their 1-based row; NDJSON is not an array.
+    std::fs::write(
+        &path,
+        r#"[{"id": 1, "user": {"name": "a"}, "ok": true}, {"id": "x"}]"#,
+    )
+    .unwrap();
+    match ingest_json_array_streaming(&path, &schema) {
+        Err(rust_data_processing::IngestionError::ParseError { row, column, .. }) => {
+            assert_eq!((row, column.as_str()), (2, "id"));
+        }
+        other => panic!("expected ParseError, got {other:?}"),
+    }
+    std::fs::write(&path, "{\"id\": 1}\n{\"id\": 2}\n").unwrap();
+    let err = ingest_json_array_streaming(&path, &schema).unwrap_err();
+    let _ = std::fs::remove_file(&path);
+    assert!(matches!(
+        err,
+        rust_data_processing::IngestionError::SchemaMismatch { .. }
+    ));
+}
+
 #[test]
 fn ingest_json_errors_on_missing_field() {
     let schema = people_schema_nested();
@@ -52,3 +107,153 @@ fn ingest_json_errors_on_type_mismatch() {
     assert!(msg.contains("failed to parse value"));
     assert!(msg.contains("column 'id'"));
 }
+
+#[test]
+fn infer_schema_flattens_nested_objects_in_first_appearance_order() {
+    let schema = infer_schema("tests/fixtures/infer_sample.ndjson", 100).unwrap();
+    assert_eq!(
+        schema,
+        Schema::new(vec![
+            Field::new("id", DataType::Int64),
+            Field::new("user.name", DataType::Utf8),
+            Field::new("user.age", DataType::Int64),
+            // int + float widens to Float64
+            Field::new("score", DataType::Float64),
+            // string + number conflicts fall back to Utf8
+            Field::new("tag", DataType::Utf8),
+            Field::new("active", DataType::Bool),
+            Field::new("extra", DataType::Float64),
+        ])
+    );
+}
+
+#[test]
+fn infer_schema_respects_sample_rows_and_json_arrays() {
+    let schema = infer_schema("tests/fixtures/infer_sample.ndjson", 1).unwrap();
+    assert_eq!(schema.fields.len(), 5);
+    assert_eq!(schema.fields[3], Field::new("score", DataType::Int64));
+
+    let schema = infer_schema("tests/fixtures/people.json", 10).unwrap();
+    assert_eq!(
+        schema,
+        Schema::new(vec![
+            Field::new("id", DataType::Int64),
+            Field::new("user.name", DataType::Utf8),
+            Field::new("score", DataType::Float64),
+            Field::new("active", DataType::Bool),
+        ])
+    );
+    let ds = ingest_json_from_path("tests/fixtures/people.json", &schema).unwrap();
+    assert_eq!(ds.row_count(), 2);
+}
+
+#[test]
+fn infer_schema_treats_null_as_neutral_for_nested_objects() {
+    let path = std::env::temp_dir().join(format!(
+        "rust-data-processing-infer-null-parent-{}.ndjson",
+        std::process::id()
+    ));
+    std::fs::write(
+        &path,
+        "{\"id\": 1, \"user\": null, \"meta\": {\"tag\": \"x\"}}\n\
+         {\"id\": 2, \"user\": {\"name\": \"Ada\"}, \"meta\": null}\n",
+    )
+    .unwrap();
+
+    let schema = infer_schema(&path, 10).unwrap();
+    let _ = std::fs::remove_file(&path);
+    assert_eq!(
+        schema,
+        Schema::new(vec![
+            Field::new("id", DataType::Int64),
+            Field::new("meta.tag", DataType::Utf8),
+            Field::new("user.name", DataType::Utf8),
+        ])
+    );
+}
+
+#[test]
+fn flatten_to_schema_expands_two_nesting_levels_and_skips_arrays() {
+    let path = std::env::temp_dir().join(format!(
+        "rust-data-processing-flatten-{}.json",
+        std::process::id()
+    ));
+    std::fs::write(
+        &path,
+        r#"[
+            {"id": 1, "user": {"name": "Ada", "address": {"city": "London", "zip": 1}}, "tags": ["a"]},
+            {"id": 2, "user": {"name": "Grace", "address": {"city": "NYC", "zip": 2}}, "tags": []}
+        ]"#,
+    )
+    .unwrap();
+
+    let (schema, ds) = flatten_to_schema(&path, 10).unwrap();
+    let _ = std::fs::remove_file(&path);
+    assert_eq!(
+        schema,
+        Schema::new(vec![
+            Field::new("id", DataType::Int64),
+            Field::new("user.name", DataType::Utf8),
+            Field::new("user.address.city", DataType::Utf8),
+            Field::new("user.address.zip", DataType::Int64),
+        ])
+    );
+    assert_eq!(ds.schema, schema);
+    assert_eq!(
+        ds.rows[1],
+        vec![
+            Value::Int64(2),
+            Value::Utf8("Grace".to_string()),
+            Value::Utf8("NYC".to_string()),
+            Value::Int64(2),
+        ]
+    );
+}
+
+#[test]
+fn ingest_json_decodes_base64_bytes_fields() {
+    let schema = Schema::new(vec![
+        Field::new("id", DataType::Int64),
+        Field::new("blob", DataType::Bytes),
+    ]);
+    let ds = ingest_json_from_str(
+        r#"[{"id": 1, "blob": "AQID/w=="}, {"id": 2, "blob": null}]"#,
+        &schema,
+    )
+    .unwrap();
+    assert_eq!(ds.rows[0][1], Value::Bytes(vec![1, 2, 3, 255]));
+    assert_eq!(ds.rows[1][1], Value::Null);
+
+    let err = ingest_json_from_str(r#"[{"id": 1, "blob": "not base64!"}]"#, &schema).unwrap_err();
+    match err {
+        rust_data_processing::IngestionError::ParseError { row, column, .. } => {
+            assert_eq!(row, 1);
+            assert_eq!(column, "blob");
+        }
+        other => panic!("expected ParseError, got {other:?}"),
+    }
+}
+
+#[test]
+fn ingest_json_from_path_decodes_base64_bytes_fields() {
+    let path = std::env::temp_dir().join(format!(
+        "rust-data-processing-bytes-{}.ndjson",
+        std::process::id()
+    ));
+    std::fs::write(
+        &path,
+        "{\"id\": 1, \"blob\": \"aGk=\"}\n{\"id\": 2, \"blob\": \"%%\"}\n",
+    )
+    .unwrap();
+    let schema = Schema::new(vec![
+        Field::new("id", DataType::Int64),
+        Field::new("blob", DataType::Bytes),
+    ]);
+    let err = ingest_json_from_path(&path, &schema).unwrap_err();
+    assert!(err.to_string().contains("invalid base64"), "{err}");
+
+    std::fs::write(&path, "{\"id\": 1, \"blob\": \"aGk=\"}\n").unwrap();
+    let ds = ingest_json_from_path(&path, &schema).unwrap();
+    let _ = std::fs::remove_file(&path);
+    assert_eq!(ds.rows[0][1], Value::Bytes(b"hi".to_vec()));
+}
@@ -4,12 +4,15 @@ use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use parquet::column::writer::ColumnWriter;
-use parquet::data_type::ByteArray;
+use parquet::data_type::{ByteArray, Int96};
 use parquet::file::properties::WriterProperties;
 use parquet::file::writer::SerializedFileWriter;
 use parquet::schema::parser::parse_message_type;
 
-use rust_data_processing::ingestion::parquet::ingest_parquet_from_path;
+use rust_data_processing::ingestion::parquet::{
+    ingest_parquet_filtered, ingest_parquet_from_path, ingest_parquet_row_groups,
+    parquet_row_group_count,
+};
 use rust_data_processing::types::{DataType, Field, Schema, Value};
 
 fn tmp_file(name: &str) -> PathBuf {
@@ -126,6 +129,26 @@ fn ingest_parquet_happy_path() {
     let _ = std::fs::remove_file(&path);
 }
 
+#[cfg(feature = "mmap")]
+#[test]
+fn ingest_parquet_mmap_matches_standard_path() {
+    use rust_data_processing::ingestion::parquet::ingest_parquet_mmap;
+
+    let schema = people_schema();
+    let path = tmp_file("people-mmap");
+    write_people_parquet(&path, true, false);
+
+    let mapped = ingest_parquet_mmap(&path, &schema).unwrap();
+    let standard = ingest_parquet_from_path(&path, &schema).unwrap();
+    assert_eq!(mapped, standard);
+    assert_eq!(mapped.row_count(), 2);
+
+    let missing = Schema::new(vec![Field::new("nope", DataType::Int64)]);
+    assert!(ingest_parquet_mmap(&path, &missing).is_err());
+
+    let _ = std::fs::remove_file(&path);
+}
+
 #[test]
 fn ingest_parquet_errors_on_missing_required_column() {
     let schema = people_schema();
@@ -150,6 +173,173 @@ fn ingest_parquet_errors_on_type_mismatch() {
     let _ = std::fs::remove_file(&path);
 }
 
+#[test]
+fn ingest_parquet_binary_column_as_bytes() {
+    let path = tmp_file("binary");
+    let schema_str = r#"
+        message schema {
+          REQUIRED INT64 id;
+          OPTIONAL BINARY payload;
+        }
+    "#;
+    let schema = Arc::new(parse_message_type(schema_str).unwrap());
+    let props = Arc::new(WriterProperties::builder().build());
+    let file = File::create(&path).unwrap();
+    let mut writer = SerializedFileWriter::new(file, schema, props).unwrap();
+    let mut rg = writer.next_row_group().unwrap();
+    while let Some(mut col) = rg.next_column().unwrap() {
+        match col.untyped() {
+            ColumnWriter::Int64ColumnWriter(w) => {
+                w.write_batch(&[1_i64, 2_i64], None, None).unwrap();
+            }
+            ColumnWriter::ByteArrayColumnWriter(w) => {
+                let blob = ByteArray::from(vec![0xff_u8, 0x00, 0x7f]);
+                w.write_batch(&[blob], Some(&[1, 0]), None).unwrap();
+            }
+            _ => unreachable!(),
+        }
+        col.close().unwrap();
+    }
+    rg.close().unwrap();
+    writer.close().unwrap();
+
+    let schema = Schema::new(vec![
+        Field::new("id", DataType::Int64),
+        Field::new("payload", DataType::Bytes),
+    ]);
+    let ds = ingest_parquet_from_path(&path, &schema).unwrap();
+    let _ = std::fs::remove_file(&path);
+
+    assert_eq!(ds.rows[0][1], Value::Bytes(vec![0xff, 0x00, 0x7f]));
+    assert_eq!(ds.rows[1][1], Value::Null);
+}
+
+#[test]
+fn ingest_parquet_nested_group_leaf_by_dotted_name() {
+    let path = tmp_file("nested");
+    let schema_str = r#"
+        message schema {
+          REQUIRED INT64 id;
+          OPTIONAL GROUP address {
+            REQUIRED BINARY city (UTF8);
+            REQUIRED INT64 zip;
+          }
+        }
+    "#;
+    let schema = Arc::new(parse_message_type(schema_str).unwrap());
+    let props = Arc::new(WriterProperties::builder().build());
+    let file = File::create(&path).unwrap();
+    let mut writer = SerializedFileWriter::new(file, schema, props).unwrap();
+    let mut rg = writer.next_row_group().unwrap();
+    while let Some(mut col) = rg.next_column().unwrap() {
+        match col.untyped() {
+            ColumnWriter::Int64ColumnWriter(w) if w.get_descriptor().name() == "id" => {
+                w.write_batch(&[1_i64, 2_i64], None, None).unwrap();
+            }
+            ColumnWriter::ByteArrayColumnWriter(w) => {
+                let cities = [ByteArray::from("Austin")];
+                w.write_batch(&cities, Some(&[1, 0]), None).unwrap();
+            }
+            ColumnWriter::Int64ColumnWriter(w) => {
+                w.write_batch(&[78701_i64], Some(&[1, 0]), None).unwrap();
+            }
+            _ => unreachable!(),
+        }
+        col.close().unwrap();
+    }
+    rg.close().unwrap();
+    writer.close().unwrap();
+
+    let schema = Schema::new(vec![
+        Field::new("id", DataType::Int64),
+        Field::new("address.city", DataType::Utf8),
+    ]);
+    let ds = ingest_parquet_from_path(&path, &schema).unwrap();
+    assert_eq!(
+        ds.rows[0],
+        vec![Value::Int64(1), Value::Utf8("Austin".to_string())]
+    );
+    assert_eq!(ds.rows[1], vec![Value::Int64(2), Value::Null]);
+
+    // The record-based readers resolve the same leaf path.
+    let grouped = ingest_parquet_row_groups(&path, &schema, &[0]).unwrap();
+    assert_eq!(grouped.rows, ds.rows);
+
+    let missing = Schema::new(vec![Field::new("address.street", DataType::Utf8)]);
+    assert!(ingest_parquet_row_groups(&path, &missing, &[0]).is_err());
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn ingest_parquet_timestamps_as_timestamp_values() {
+    let path = tmp_file("timestamps");
+    let schema_str = r#"
+        message schema {
+          REQUIRED INT96 legacy;
+          REQUIRED INT64 ts_ms (TIMESTAMP_MILLIS);
+          OPTIONAL INT64 ts_us (TIMESTAMP_MICROS);
+          OPTIONAL INT96 legacy_opt;
+        }
+    "#;
+    let schema = Arc::new(parse_message_type(schema_str).unwrap());
+    let props = Arc::new(WriterProperties::builder().build());
+    let file = File::create(&path).unwrap();
+    let mut writer = SerializedFileWriter::new(file, schema, props).unwrap();
+    let mut rg = writer.next_row_group().unwrap();
+    // 2021-01-01T00:00:00.5Z: Julian day 2_459_216, half a second into the day.
+    let expected = 1_609_459_200_500_000_i64;
+    // INT96 keeps sub-millisecond digits: 500_123_456 ns is 500_123 us (nanoseconds truncated).
+    let int96 = Int96::from(vec![500_123_456, 0, 2_459_216]);
+    while let Some(mut col) = rg.next_column().unwrap() {
+        match col.untyped() {
+            ColumnWriter::Int96ColumnWriter(w) if w.get_descriptor().name() == "legacy" => {
+                w.write_batch(&[int96], None, None).unwrap();
+            }
+            ColumnWriter::Int96ColumnWriter(w) => {
+                w.write_batch(&[int96], Some(&[1]), None).unwrap();
+            }
+            ColumnWriter::Int64ColumnWriter(w) if w.get_descriptor().name() == "ts_ms" => {
+                w.write_batch(&[expected / 1_000], None, None).unwrap();
+            }
+            ColumnWriter::Int64ColumnWriter(w) => {
+                w.write_batch(&[expected], Some(&[1]), None).unwrap();
+            }
+            _ => unreachable!(),
+        }
+        col.close().unwrap();
+    }
+    rg.close().unwrap();
+    writer.close().unwrap();
+
+    let schema = Schema::new(vec![
+        Field::new("legacy", DataType::Timestamp),
+        Field::new("ts_ms", DataType::Timestamp),
+        Field::new("ts_us", DataType::Timestamp),
+        Field::new("legacy_opt", DataType::Timestamp),
+    ]);
+    let ds = ingest_parquet_from_path(&path, &schema).unwrap();
+    let exact = Value::Timestamp(expected + 123);
+    assert_eq!(
+        ds.rows[0],
+        vec![
+            exact.clone(),
+            Value::Timestamp(expected),
+            Value::Timestamp(expected),
+            exact.clone(),
+        ]
+    );
+    assert_eq!(exact.to_string(), "2021-01-01T00:00:00.500123Z");
+
+    // The record-based readers agree, including the sub-millisecond INT96 digits.
+    let grouped = ingest_parquet_row_groups(&path, &schema, &[0]).unwrap();
+    #[cfg(feature = "mmap")]
+    let mapped = rust_data_processing::ingestion::parquet::ingest_parquet_mmap(&path, &schema);
+    let _ = std::fs::remove_file(&path);
+    assert_eq!(grouped.rows, ds.rows);
+    #[cfg(feature = "mmap")]
+    assert_eq!(mapped.unwrap().rows, ds.rows);
+}
+
 #[test]
 #[ignore]
 fn parquet_perf_smoke_test() {
@@ -216,3 +406,81 @@ fn parquet_perf_smoke_test() {
 
     let _ = std::fs::remove_file(&path);
 }
+
+#[test]
+fn ingest_parquet_row_groups_reads_only_requested_groups() {
+    let path = tmp_file("row-groups");
+    let message =
+        parse_message_type("message schema { REQUIRED INT64 id; OPTIONAL BINARY name (UTF8); }")
+            .unwrap();
+    let props = Arc::new(WriterProperties::builder().build());
+    let file = File::create(&path).unwrap();
+    let mut writer = SerializedFileWriter::new(file, Arc::new(message), props).unwrap();
+    // Three row groups of two rows each: ids 0..6, the name of id 3 is null.
+    for group in 0..3_i64 {
+        let mut rg = writer.next_row_group().unwrap();
+        while let Some(mut col) = rg.next_column().unwrap() {
+            match col.untyped() {
+                ColumnWriter::Int64ColumnWriter(w) => {
+                    w.write_batch(&[group * 2, group * 2 + 1], None, None)
+                        .unwrap();
+                }
+                ColumnWriter::ByteArrayColumnWriter(w) => {
+                    let names = [
+                        ByteArray::from(format!("n{}", group * 2).as_str()),
+                        ByteArray::from(format!("n{}", group * 2 + 1).as_str()),
+                    ];
+                    let (values, def) = if group == 1 {
+                        (&names[..1], [1_i16, 0])
+                    } else {
+                        (&names[..], [1_i16, 1])
+                    };
+                    w.write_batch(values, Some(&def), None).unwrap();
+                }
+                _ => panic!("unexpected column writer in test"),
+            }
+            col.close().unwrap();
+        }
+        rg.close().unwrap();
+    }
+    writer.close().unwrap();
+
+    assert_eq!(parquet_row_group_count(&path).unwrap(), 3);
+
+    // Schema order differs from file order; groups are read in the order requested.
+    let schema = Schema::new(vec![
+        Field::new("name", DataType::Utf8),
+        Field::new("id", DataType::Int64),
+    ]);
+    let ds = ingest_parquet_row_groups(&path, &schema, &[2, 1]).unwrap();
+    assert_eq!(
+        ds.column_i64("id").unwrap(),
+        vec![Some(4), Some(5), Some(2), Some(3)]
+    );
+    assert_eq!(
+        ds.column_str("name").unwrap(),
+        vec![Some("n4"), Some("n5"), Some("n2"), None]
+    );
+
+    let err = ingest_parquet_row_groups(&path, &schema, &[0, 3]).unwrap_err();
+    assert!(err.to_string().contains("row group 3 out of range"));
+
+    // Filtering spans every row group and sees nulls.
+    let even = ingest_parquet_filtered(
+        &path,
+        &schema,
+        "id",
+        |v| matches!(v, Value::Int64(n) if n % 2 == 0),
+    )
+    .unwrap();
+    assert_eq!(even.row_count(), 3);
+    assert_eq!(
+        even.column_i64("id").unwrap(),
+        vec![Some(0), Some(2), Some(4)]
+    );
+    let unnamed = ingest_parquet_filtered(&path, &schema, "name", |v| *v == Value::Null).unwrap();
+    assert_eq!(unnamed.column_i64("id").unwrap(), vec![Some(3)]);
+    assert!(ingest_parquet_filtered(&path, &schema, "missing", |_| true).is_err());
+
+    let _ = std::fs::remove_file(&path);
+}
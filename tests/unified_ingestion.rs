@@ -11,7 +11,10 @@ use parquet::schema::parser::parse_message_type;
 
 #[cfg(feature = "excel_test_writer")]
 use rust_data_processing::ingestion::ExcelSheetSelection;
-use rust_data_processing::ingestion::{IngestionFormat, IngestionOptions, ingest_from_path};
+use rust_data_processing::ingestion::{
+    IngestionFormat, IngestionOptions, fold_rows, ingest_from_path, ingest_from_path_detailed,
+    validate, validate_with_sample_rows,
+};
 use rust_data_processing::types::{DataType, Field, Schema, Value};
 
 fn tmp_file(ext: &str) -> PathBuf {
@@ -103,6 +106,208 @@ fn unified_ingest_csv_explicit_format() {
     assert_eq!(ds.row_count(), 2);
 }
 
+#[test]
+fn unified_ingest_detailed_reports_resolved_format() {
+    let schema = people_schema();
+    let (ds, fmt) = ingest_from_path_detailed(
+        "tests/fixtures/people.csv",
+        &schema,
+        &IngestionOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(fmt, IngestionFormat::Csv);
+    assert_eq!(ds.row_count(), 2);
+
+    // No extension: the forced format is what gets reported.
+    let path = tmp_file("csv").with_extension("");
+    std::fs::copy("tests/fixtures/people.csv", &path).unwrap();
+    let opts = IngestionOptions {
+        format: Some(IngestionFormat::Csv),
+        ..Default::default()
+    };
+    let (ds, fmt) = ingest_from_path_detailed(&path, &schema, &opts).unwrap();
+    assert_eq!(fmt, IngestionFormat::Csv);
+    assert_eq!(ds.row_count(), 2);
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn max_rows_fails_instead_of_truncating() {
+    let parquet_path = tmp_file("parquet");
+    write_people_parquet(&parquet_path);
+    let cases = [
+        ("tests/fixtures/people.csv".into(), people_schema()),
+        (
+            "tests/fixtures/people.json".into(),
+            people_schema_json_nested(),
+        ),
+        (parquet_path.clone(), people_schema()),
+    ];
+
+    for (path, schema) in &cases {
+        let capped = IngestionOptions {
+            max_rows: Some(1),
+            ..Default::default()
+        };
+        match ingest_from_path(path, schema, &capped) {
+            Err(rust_data_processing::IngestionError::RowLimitExceeded { limit }) => {
+                assert_eq!(limit, 1)
+            }
+            other => panic!(
+                "{}: expected RowLimitExceeded, got {other:?}",
+                path.display()
+            ),
+        }
+
+        let at_cap = IngestionOptions {
+            max_rows: Some(2),
+            ..Default::default()
+        };
+        assert_eq!(
+            ingest_from_path(path, schema, &at_cap).unwrap().row_count(),
+            2
+        );
+    }
+    let _ = std::fs::remove_file(&parquet_path);
+}
+
+#[test]
+fn round_floats_rounds_float64_cells_at_ingest() {
+    let schema = Schema::new(vec![
+        Field::new("id", DataType::Int64),
+        Field::new("x", DataType::Float64),
+    ]);
+    let csv_path = tmp_file("csv");
+    std::fs::write(&csv_path, "id,x\n1,1.23456\n2,\n").unwrap();
+    let json_path = tmp_file("json");
+    std::fs::write(
+        &json_path,
+        r#"[{"id": 1, "x": 1.23456}, {"id": 2, "x": null}]"#,
+    )
+    .unwrap();
+
+    // Polars fast path, record-level CSV reader, and JSON.
+    for (path, case_insensitive_headers) in
+        [(&csv_path, false), (&csv_path, true), (&json_path, false)]
+    {
+        let opts = IngestionOptions {
+            round_floats: Some(2),
+            case_insensitive_headers,
+            ..Default::default()
+        };
+        let ds = ingest_from_path(path, &schema, &opts).unwrap();
+        assert_eq!(ds.column_f64("x").unwrap(), vec![Some(1.23), None]);
+    }
+    let ds = ingest_from_path(&csv_path, &schema, &IngestionOptions::default()).unwrap();
+    assert_eq!(ds.rows[0][1], Value::Float64(1.23456));
+
+    let parquet_path = tmp_file("parquet");
+    write_people_parquet(&parquet_path);
+    let opts = IngestionOptions {
+        round_floats: Some(1),
+        ..Default::default()
+    };
+    let ds = ingest_from_path(&parquet_path, &people_schema(), &opts).unwrap();
+    assert_eq!(
+        ds.column_f64("score").unwrap(),
+        vec![Some(98.5), Some(87.3)]
+    );
+
+    for path in [&csv_path, &json_path, &parquet_path] {
+        let _ = std::fs::remove_file(path);
+    }
+}
+
+#[test]
+fn fold_rows_sums_a_streamed_csv() {
+    let path = tmp_file("csv");
+    let mut body = String::from("id,amount\n");
+    for i in 1..=1000 {
+        body.push_str(&format!(
+            "{i},{}\n",
+            if i % 10 == 0 {
+                String::new()
+            } else {
+                i.to_string()
+            }
+        ));
+    }
+    std::fs::write(&path, body).unwrap();
+    let schema = Schema::new(vec![
+        Field::new("id", DataType::Int64),
+        Field::new("amount", DataType::Int64),
+    ]);
+
+    let (rows, sum) = fold_rows(
+        &path,
+        &schema,
+        &IngestionOptions::default(),
+        (0, 0),
+        |(n, s), row| match row[1] {
+            Value::Int64(v) => (n + 1, s + v),
+            _ => (n + 1, s),
+        },
+    )
+    .unwrap();
+    assert_eq!(rows, 1000);
+    assert_eq!(sum, 500_500 - 50_500);
+
+    // The first bad row aborts the fold.
+    std::fs::write(&path, "id,amount\n1,2\n2,oops\n").unwrap();
+    let err = fold_rows(&path, &schema, &IngestionOptions::default(), 0, |n, _| {
+        n + 1
+    })
+    .unwrap_err();
+    assert!(err.to_string().contains("row 3"));
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn validate_accepts_a_clean_file() {
+    let opts = IngestionOptions::default();
+    let report = validate("tests/fixtures/people.csv", &people_schema(), &opts).unwrap();
+    assert!(report.ok);
+    assert_eq!(report.checked_rows, 2);
+    assert!(report.issues.is_empty());
+
+    let path = tmp_file("parquet");
+    write_people_parquet(&path);
+    let report = validate(&path, &people_schema(), &opts).unwrap();
+    assert!(report.ok, "{:?}", report.issues);
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn validate_reports_missing_columns_and_every_bad_cell_in_the_sample() {
+    let opts = IngestionOptions::default();
+    let mut schema = people_schema();
+    schema.fields.push(Field::new("email", DataType::Utf8));
+    let report = validate("tests/fixtures/people.csv", &schema, &opts).unwrap();
+    assert!(!report.ok);
+    assert_eq!(report.checked_rows, 0);
+    assert_eq!(report.issues.len(), 1);
+    assert!(report.issues[0].to_string().contains("'email'"));
+
+    let path = tmp_file("csv");
+    std::fs::write(
+        &path,
+        "id,name,score,active\n1,a,x,true\ntwo,b,2.0,maybe\n3,c,3.0,false\n4,d,oops,true\n",
+    )
+    .unwrap();
+    let report = validate(&path, &people_schema(), &opts).unwrap();
+    assert!(!report.ok);
+    assert_eq!(report.checked_rows, 4);
+    assert_eq!(report.issues.len(), 4);
+
+    // Only the sampled rows are checked.
+    let report = validate_with_sample_rows(&path, &people_schema(), &opts, 1).unwrap();
+    assert_eq!(report.checked_rows, 1);
+    assert_eq!(report.issues.len(), 1);
+    let _ = std::fs::remove_file(&path);
+
+    assert!(validate("tests/fixtures/missing.csv", &people_schema(), &opts).is_err());
+}
+
 #[test]
 fn unified_ingest_json_explicit_format_errors_with_flat_schema() {
     let schema = people_schema();
@@ -189,6 +394,29 @@ fn unified_ingest_switch_formats_same_schema() {
     let _ = std::fs::remove_file(&path);
 }
 
+#[test]
+fn unified_ingest_csv_case_insensitive_headers() {
+    let schema = Schema::new(vec![
+        Field::new("ID", DataType::Int64),
+        Field::new("Name", DataType::Utf8),
+    ]);
+    let opts = IngestionOptions {
+        case_insensitive_headers: true,
+        ..Default::default()
+    };
+    let ds = ingest_from_path("tests/fixtures/people.csv", &schema, &opts).unwrap();
+    assert_eq!(ds.row_count(), 2);
+    assert_eq!(ds.rows[1][1], Value::Utf8("Grace".to_string()));
+
+    let err = ingest_from_path(
+        "tests/fixtures/people.csv",
+        &schema,
+        &IngestionOptions::default(),
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("ID"));
+}
+
 #[cfg(feature = "excel_test_writer")]
 #[test]
 fn unified_ingest_excel_all_sheets_explicit_format() {